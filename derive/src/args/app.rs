@@ -21,6 +21,26 @@ fn impl_create_schema(app: &App) -> TokenStream {
                 let registry = registry.register::<Self>();
                 registry.create_schema()
             }
+            pub fn try_create_schema() -> std::result::Result<#crate_name::dynamic::SchemaBuilder, #crate_name::RegistryError> {
+                let registry = #crate_name::internal::Registry::new();
+                let registry = registry.register::<Self>();
+                registry.try_create_schema()
+            }
+            pub fn create_schema_for(filter: &#crate_name::TagFilter) -> #crate_name::dynamic::SchemaBuilder {
+                let registry = #crate_name::internal::Registry::new();
+                let registry = registry.register::<Self>();
+                registry.create_schema_for(filter)
+            }
+            pub fn try_create_schema_for(filter: &#crate_name::TagFilter) -> std::result::Result<#crate_name::dynamic::SchemaBuilder, #crate_name::RegistryError> {
+                let registry = #crate_name::internal::Registry::new();
+                let registry = registry.register::<Self>();
+                registry.try_create_schema_for(filter)
+            }
+            pub fn sdl() -> String {
+                let registry = #crate_name::internal::Registry::new();
+                let registry = registry.register::<Self>();
+                registry.create_sdl()
+            }
         }
     }
 }
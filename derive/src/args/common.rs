@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
 pub use args::*;
+pub use federation::*;
 pub use fields::*;
 pub use generics::*;
 pub use interfaces::*;
+pub use node::*;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -16,15 +18,20 @@ use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::impl_block::BaseFnArg;
+use crate::utils::rename_rule::calc_arg_name;
 use crate::utils::rename_rule::calc_enum_item_name;
 use crate::utils::rename_rule::calc_input_field_name;
 use crate::utils::rename_rule::calc_type_name;
+use crate::utils::tags::get_tags_slice;
 use crate::utils::type_utils::get_owned_type;
+use crate::utils::visible::VisibleAttr;
 
 mod args;
+mod federation;
 mod fields;
 mod generics;
 mod interfaces;
+mod node;
 
 pub trait ArgImplementor: CommonArg {
     fn get_self_arg_definition(&self) -> darling::Result<TokenStream>;
@@ -128,6 +135,11 @@ pub fn impl_resolve_owned(obj: &impl CommonObject) -> darling::Result<TokenStrea
                 Ok(Some(#crate_name::FieldValue::owned_any(self)))
             }
         }
+        impl #impl_generics #crate_name::internal::ResolveShared<#lifetime> for #object_ident #ty_generics #where_clause {
+            fn resolve_shared(shared: #crate_name::internal::Shared<Self>, _ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<#lifetime>>> {
+                Ok(Some(#crate_name::FieldValue::owned_any(shared)))
+            }
+        }
     })
 }
 
@@ -157,6 +169,11 @@ pub fn impl_resolve_owned_by_value(obj: &impl CommonObject) -> darling::Result<T
                 Ok(Some(#crate_name::FieldValue::value(&self)))
             }
         }
+        impl<'__dynamic_graphql_lifetime> #crate_name::internal::ResolveShared<'__dynamic_graphql_lifetime> for #object_ident {
+            fn resolve_shared(shared: #crate_name::internal::Shared<Self>, ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<'__dynamic_graphql_lifetime>>> {
+                #crate_name::internal::resolve_shared_value(&shared, ctx)
+            }
+        }
     })
 }
 
@@ -203,6 +220,14 @@ pub fn object_description(doc: Option<&str>) -> darling::Result<TokenStream> {
     }
 }
 
+/// attach the `directive` attributes of the type
+pub fn object_directives(obj: &impl CommonObject) -> TokenStream {
+    let directives = obj.get_directives();
+    quote! {
+        #( let object = object.directive(#directives); )*
+    }
+}
+
 pub fn get_type_name(obj: &impl CommonObject) -> darling::Result<String> {
     let name = obj.get_name();
     let object_ident = obj.get_ident();
@@ -265,6 +290,248 @@ where
         .collect())
 }
 
+/// register the complexity of the fields in the registry, `type_name` is the name of the object
+/// or interface that owns the fields
+pub fn get_complexity_register_code<O, F>(
+    object: &O,
+    type_name: TokenStream,
+) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: CommonField,
+{
+    object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .filter_map(|field| {
+            let complexity = field.get_complexity()?;
+            Some(get_field_name(field).map(|field_name| {
+                quote! {
+                    let registry = registry.set_field_complexity(#type_name.as_ref(), #field_name, #complexity);
+                }
+            }))
+        })
+        .collect()
+}
+
+/// hide the type in the requests where it is not visible, `type_name` is the name of the type
+pub fn get_type_visible_register_code(
+    object: &impl CommonObject,
+    type_name: TokenStream,
+) -> TokenStream {
+    let Some(visible) = object.get_visible().and_then(VisibleAttr::get_visible_fn) else {
+        return quote!();
+    };
+    quote! {
+        let registry = registry.set_type_visible(#type_name.as_ref(), #visible);
+    }
+}
+
+/// hide the fields, enum items or input fields in the requests where they are not visible,
+/// `get_name` is the graphql name of the field
+pub fn get_fields_visible_register_code<O, F>(
+    object: &O,
+    type_name: TokenStream,
+    get_name: impl Fn(&F) -> darling::Result<String>,
+) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: CommonField,
+{
+    object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .filter_map(|field| {
+            let visible = field.get_visible()?.get_visible_fn()?;
+            Some(get_name(field).map(|field_name| {
+                quote! {
+                    let registry = registry.set_field_visible(#type_name.as_ref(), #field_name, #visible);
+                }
+            }))
+        })
+        .collect()
+}
+
+/// hide the arguments of the fields in the requests where they are not visible
+pub fn get_args_visible_register_code<O, F, A>(
+    object: &O,
+    type_name: TokenStream,
+) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: CommonField + GetArgs<A>,
+    A: CommonArg,
+{
+    let mut code = Vec::new();
+    for field in object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+    {
+        let field_name = get_field_name(field)?;
+        for arg in field.get_args()? {
+            if is_arg_injected(arg) {
+                continue;
+            }
+            let BaseFnArg::Typed(typed) = arg.get_arg() else {
+                continue;
+            };
+            let Some(visible) = arg.get_visible().and_then(VisibleAttr::get_visible_fn) else {
+                continue;
+            };
+            let arg_name = calc_arg_name(
+                arg.get_name(),
+                &typed.ident.to_string(),
+                arg.get_arg_rename_rule(),
+            );
+            code.push(quote! {
+                let registry = registry.set_argument_visible(#type_name.as_ref(), #field_name, #arg_name, #visible);
+            });
+        }
+    }
+    Ok(quote!(#(#code)*))
+}
+
+/// declare the loaders of the `#[graphql(loader)]` arguments
+pub fn get_loaders_declare_code<O, F, A>(object: &O) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: CommonField + GetArgs<A>,
+    A: CommonArg,
+{
+    let crate_name = get_crate_name();
+    let mut code = Vec::new();
+    for field in object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+    {
+        for arg in field.get_args()? {
+            if !is_arg_loader(arg) {
+                continue;
+            }
+            let BaseFnArg::Typed(typed) = arg.get_arg() else {
+                continue;
+            };
+            let ty = get_owned_type(&typed.ty);
+            code.push(quote! {
+                let registry = <#ty as #crate_name::internal::LoaderArg>::declare_loader(registry);
+            });
+        }
+    }
+    Ok(quote!(#(#code)*))
+}
+
+/// tag the type, `type_name` is the name of the type
+pub fn get_type_tags_register_code(
+    object: &impl CommonObject,
+    type_name: TokenStream,
+) -> TokenStream {
+    let Some(tags) = get_tags_slice(object.get_tags()) else {
+        return quote!();
+    };
+    quote! {
+        let registry = registry.set_type_tags(#type_name.as_ref(), #tags);
+    }
+}
+
+/// add the field to the object, the tagged fields are added by the registry when the schema is
+/// created
+pub fn get_object_field_usage_code(
+    field: &impl CommonField,
+    type_name: TokenStream,
+) -> darling::Result<TokenStream> {
+    let Some(tags) = get_tags_slice(field.get_tags()) else {
+        return Ok(quote!(let object = object.field(field);));
+    };
+    let field_name = get_field_name(field)?;
+    Ok(quote! {
+        let registry = registry.add_tagged_field(#type_name.as_ref(), #field_name, #tags, field);
+    })
+}
+
+/// add the field to the interface, the tagged fields are added by the registry when the schema
+/// is created
+pub fn get_interface_field_usage_code(
+    field: &impl CommonField,
+    type_name: TokenStream,
+) -> darling::Result<TokenStream> {
+    let Some(tags) = get_tags_slice(field.get_tags()) else {
+        return Ok(quote!(let object = object.field(field);));
+    };
+    let field_name = get_field_name(field)?;
+    Ok(quote! {
+        let registry = registry.add_tagged_interface_field(#type_name.as_ref(), #field_name, #tags, field);
+    })
+}
+
+/// declare the types used by the fields and their arguments, so the types only used by removed
+/// fields are removed with them
+pub fn get_fields_types_declare_code<O, F, A>(
+    object: &O,
+    type_name: TokenStream,
+) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: CommonField + GetArgs<A>,
+    A: CommonArg,
+{
+    let crate_name = get_crate_name();
+    let mut code = Vec::new();
+    for field in object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+    {
+        let field_name = get_field_name(field)?;
+        let field_type = get_field_type(field)?;
+        let args = field
+            .get_args()?
+            .iter()
+            .filter(|arg| !is_arg_injected(*arg))
+            .filter_map(|arg| match arg.get_arg() {
+                BaseFnArg::Typed(typed) => Some(get_owned_type(&typed.ty)),
+                BaseFnArg::Receiver(_) => None,
+            })
+            .map(|arg_type| {
+                quote!(<#arg_type as #crate_name::internal::GetInputTypeRef>::get_input_type_ref().type_name())
+            });
+        code.push(quote! {
+            let registry = registry.declare_field_types(#type_name.as_ref(), #field_name, &[
+                <#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref().type_name(),
+                #(#args,)*
+            ]);
+        });
+    }
+    Ok(quote!(#(#code)*))
+}
+
+/// declare the types used by the input fields
+pub fn get_input_fields_types_declare_code<O, F>(
+    object: &O,
+    type_name: TokenStream,
+) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: CommonField,
+{
+    let mut code = Vec::new();
+    for field in object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+    {
+        let field_name = get_input_field_name(field)?;
+        let type_ref = get_input_type_ref_code(field)?;
+        code.push(quote! {
+            let registry = registry.declare_field_types(#type_name.as_ref(), #field_name, &[#type_ref.type_name()]);
+        });
+    }
+    Ok(quote!(#(#code)*))
+}
+
 pub fn get_nested_type_register_code<O, F, A>(object: &O) -> darling::Result<TokenStream>
 where
     O: GetFields<F>,
@@ -288,7 +555,7 @@ where
             };
             args.iter().for_each(|arg| {
                 if let BaseFnArg::Typed(ty) = arg.get_arg() {
-                    if is_arg_ctx(arg) {
+                    if is_arg_injected(arg) {
                         return;
                     }
                     types.insert(&ty.ty);
@@ -3,12 +3,15 @@ use quote::quote;
 
 use crate::utils::common::CommonArg;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
+use crate::utils::error::IntoTokenStream;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::TypedArg;
 use crate::utils::rename_rule::calc_arg_name;
 use crate::utils::type_utils::get_owned_type;
 use crate::utils::type_utils::get_value_type;
 use crate::utils::type_utils::is_type_ref;
+use crate::utils::validators::get_validators_code;
 
 pub fn get_arg_ident(arg: &impl CommonArg) -> syn::Ident {
     syn::Ident::new(&format!("arg{}", arg.get_index()), arg.get_arg().span())
@@ -19,6 +22,16 @@ pub fn is_arg_ctx(arg: &impl CommonArg) -> bool {
         || matches!(arg.get_arg(), BaseFnArg::Typed(TypedArg{ref ident, ..}) if ident == "ctx" || ident == "_ctx")
 }
 
+/// the argument is a `DataLoader` from the context, `#[graphql(loader)]`
+pub fn is_arg_loader(arg: &impl CommonArg) -> bool {
+    arg.is_marked_as_loader()
+}
+
+/// the argument is not a graphql argument
+pub fn is_arg_injected(arg: &impl CommonArg) -> bool {
+    is_arg_ctx(arg) || is_arg_loader(arg)
+}
+
 pub fn get_self_arg_usage(arg: &impl CommonArg) -> darling::Result<TokenStream> {
     let arg_ident = get_arg_ident(arg);
     Ok(quote!(#arg_ident,))
@@ -29,9 +42,9 @@ pub fn get_typed_arg_usage(arg: &impl CommonArg) -> darling::Result<TokenStream>
     let BaseFnArg::Typed(typed) = arg.get_arg() else {
         unreachable!("Expected typed argument");
     };
-    let is_ctx = is_arg_ctx(arg);
+    let is_injected = is_arg_injected(arg);
     let is_owned = !is_type_ref(&typed.ty);
-    if is_ctx || is_owned {
+    if is_injected || is_owned {
         Ok(quote!(#arg_ident,))
     } else {
         Ok(quote!(&#arg_ident,))
@@ -39,7 +52,7 @@ pub fn get_typed_arg_usage(arg: &impl CommonArg) -> darling::Result<TokenStream>
 }
 
 pub fn get_argument_definition(arg: &impl CommonArg) -> TokenStream {
-    if is_arg_ctx(arg) {
+    if is_arg_injected(arg) {
         return quote!();
     }
     let BaseFnArg::Typed(typed) = arg.get_arg() else {
@@ -52,13 +65,39 @@ pub fn get_argument_definition(arg: &impl CommonArg) -> TokenStream {
         arg.get_arg_rename_rule(),
     );
     let arg_type = get_owned_type(&typed.ty);
+    let default_value =
+        get_default_value_code(&quote!(arg), arg_type, arg.get_default_value()).into_token_stream();
+    let directives = arg.get_directives();
 
     quote! {
         let arg = #crate_name::dynamic::InputValue::new(#arg_name, <#arg_type as #crate_name::internal::GetInputTypeRef>::get_input_type_ref());
+        #default_value
+        #( let arg = arg.directive(#directives); )*
         let field = field.argument(arg);
     }
 }
 
+/// set the default value of the argument or input field `input_value`, a null default value is
+/// not set because it's the value of a missing nullable input
+pub fn get_default_value_code(
+    input_value: &TokenStream,
+    ty: &syn::Type,
+    default_value: darling::Result<Option<DefaultValue>>,
+) -> darling::Result<Option<TokenStream>> {
+    let crate_name = get_crate_name();
+    Ok(default_value?.map(|default_value| {
+        quote! {
+            let #input_value = {
+                let value: #ty = #default_value;
+                match #crate_name::internal::ToValue::to_value(&value) {
+                    #crate_name::Value::Null => #input_value,
+                    value => #input_value.default_value(value),
+                }
+            };
+        }
+    }))
+}
+
 pub fn get_argument_definitions(args: &[impl CommonArg]) -> darling::Result<TokenStream> {
     Ok(args.iter().map(get_argument_definition).collect())
 }
@@ -73,19 +112,35 @@ pub fn get_typed_arg_definition(arg: &impl CommonArg) -> darling::Result<TokenSt
         Ok(quote! {
             let #arg_ident = &ctx;
         })
+    } else if is_arg_loader(arg) {
+        Ok(quote! {
+            let #arg_ident = &#crate_name::internal::get_loader(ctx.ctx)?;
+        })
     } else {
         let arg_name = calc_arg_name(
             arg.get_name(),
             &typed.ident.to_string(),
             arg.get_arg_rename_rule(),
         );
-        let value_type = get_value_type(&typed.ty);
-        match value_type {
+        let value_type = get_value_type(&typed.ty).map(|ty| quote!(: #ty));
+        let validators = get_validators_code(
+            arg.get_validators(),
+            &typed.ty,
+            &arg_ident,
+            &arg_name,
+            quote!(into_arg_error),
+        );
+        match arg.get_default_value()? {
             None => Ok(quote! {
-                let #arg_ident = #crate_name::internal::FromValue::from_value(ctx.args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
+                let #arg_ident #value_type = #crate_name::internal::FromValue::from_value(ctx.args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
+                #validators
             }),
-            Some(ty) => Ok(quote! {
-                let #arg_ident: #ty = #crate_name::internal::FromValue::from_value(ctx.args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
+            Some(default_value) => Ok(quote! {
+                let #arg_ident #value_type = match ctx.args.get(#arg_name) {
+                    Some(value) => #crate_name::internal::FromValue::from_value(Ok(value)).map_err(|e| e.into_arg_error(#arg_name))?,
+                    None => #default_value,
+                };
+                #validators
             }),
         }
     }
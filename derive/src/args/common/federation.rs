@@ -0,0 +1,86 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::args::common::get_args_definition;
+use crate::args::common::get_field_type;
+use crate::args::common::get_guard_code;
+use crate::args::common::is_arg_injected;
+use crate::args::common::ArgImplementor;
+use crate::args::common::FieldImplementor;
+use crate::utils::common::CommonArg;
+use crate::utils::common::GetArgs;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::impl_block::BaseFnArg;
+use crate::utils::rename_rule::calc_arg_name;
+use crate::utils::type_utils::get_owned_type;
+use crate::utils::type_utils::is_type_named;
+
+/// register the methods marked with `#[graphql(entity)]` as entity resolvers of their return type
+pub fn get_entity_register_code<O, F, A>(object: &O) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: FieldImplementor + GetArgs<A>,
+    A: ArgImplementor,
+{
+    object
+        .get_fields()?
+        .iter()
+        .filter(|field| field.is_entity())
+        .map(get_entity_resolver_code)
+        .collect()
+}
+
+fn get_entity_resolver_code<F, A>(method: &F) -> darling::Result<TokenStream>
+where
+    F: FieldImplementor + GetArgs<A>,
+    A: ArgImplementor,
+{
+    let crate_name = get_crate_name();
+    let field_type = get_field_type(method)?;
+    let keys = get_entity_keys(method.get_args()?)?;
+    let graphql_args_definition = get_args_definition(method)?;
+    let guard = get_guard_code(method);
+    let execute = method.get_execute_code()?;
+    let resolve = method.get_resolve_code()?;
+
+    Ok(quote! {
+        let registry = registry.register::<#field_type>();
+        let registry = registry.register_entity_resolver(
+            <#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref().type_name(),
+            &[#(#keys),*],
+            |ctx| {
+                Box::pin(async move {
+                    #guard
+                    #graphql_args_definition
+                    #execute
+                    #resolve
+                })
+            },
+        );
+    })
+}
+
+/// the required arguments of the entity resolver, the representation must contain all of them
+fn get_entity_keys(args: &[impl CommonArg]) -> darling::Result<Vec<String>> {
+    let mut keys = Vec::new();
+    for arg in args {
+        let BaseFnArg::Typed(typed) = arg.get_arg() else {
+            continue;
+        };
+        let ty = get_owned_type(&typed.ty);
+        if is_arg_injected(arg)
+            || is_type_named(ty, "Option")
+            || is_type_named(ty, "MaybeUndefined")
+            || arg.get_default_value()?.is_some()
+        {
+            continue;
+        }
+        keys.push(calc_arg_name(
+            arg.get_name(),
+            &typed.ident.to_string(),
+            arg.get_arg_rename_rule(),
+        ));
+    }
+    Ok(keys)
+}
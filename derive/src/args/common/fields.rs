@@ -69,6 +69,14 @@ pub fn field_description(field: &impl CommonField) -> darling::Result<TokenStrea
     }
 }
 
+/// attach the `directive` attributes of the field, enum item or input field
+pub fn field_directives(field: &impl CommonField) -> TokenStream {
+    let directives = field.get_directives();
+    quote! {
+        #( let field = field.directive(#directives); )*
+    }
+}
+
 pub fn get_field_name(field: &impl CommonField) -> darling::Result<String> {
     Ok(calc_field_name(
         field.get_name(),
@@ -87,11 +95,13 @@ where
     let field_name = get_field_name(method)?;
     let field_type = get_field_type(method)?;
     let graphql_args_definition = get_args_definition(method)?;
+    let guard = get_guard_code(method);
     let execute = method.get_execute_code()?;
     let resolve = method.get_resolve_code()?;
     Ok(quote! {
         let field = #crate_name::dynamic::Field::new(#field_name, <#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref(), |ctx| {
             #crate_name::dynamic::FieldFuture::new(async move {
+                #guard
                 #graphql_args_definition
                 #execute
                 #resolve
@@ -100,6 +110,16 @@ where
     })
 }
 
+pub fn get_guard_code<F: CommonField>(field: &F) -> TokenStream {
+    let crate_name = get_crate_name();
+    match field.get_guard() {
+        Some(guard) => quote! {
+            #crate_name::internal::Guard::check(&(#guard), ctx.ctx).await?;
+        },
+        None => quote!(),
+    }
+}
+
 pub fn build_field<F, A>(method: &F) -> darling::Result<TokenStream>
 where
     F: FieldImplementor + GetArgs<A>,
@@ -109,6 +129,8 @@ where
     let argument_definitions = method.get_field_argument_definition()?;
     let description = method.get_field_description_code()?;
     let deprecation = method.get_field_deprecation_code()?;
+    let directives = field_directives(method);
+    let federation = method.get_federation();
     let field_usage = method.get_field_usage_code()?;
 
     Ok(quote! {
@@ -116,6 +138,8 @@ where
         #argument_definitions
         #description
         #deprecation
+        #directives
+        #federation
         #field_usage
     })
 }
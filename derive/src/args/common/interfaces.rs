@@ -10,6 +10,20 @@ use crate::utils::interface_attr::InterfaceImplAttr;
 pub fn get_register_interface_code(
     obj: &impl CommonInterfaceAttrs,
 ) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let marks: Vec<_> = obj
+        .get_marks()
+        .iter()
+        .map(|mark| {
+            let path = &mark.path;
+            quote! {
+                let registry = registry.declare_implement(
+                    <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                    <dyn #path as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
+                );
+            }
+        })
+        .collect();
     let mut paths = Vec::new();
     obj.get_marks().iter().for_each(|mark| {
         paths.push(mark.path.clone());
@@ -27,6 +41,7 @@ pub fn get_register_interface_code(
         .collect();
     Ok(quote! {
         #(#codes)*
+        #(#marks)*
     })
 }
 
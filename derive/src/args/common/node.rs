@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::utils::common::CommonObject;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::node_attr::NodeAttr;
+
+pub fn impl_fetch_node(
+    object: &impl CommonObject,
+    node: Option<&NodeAttr>,
+) -> darling::Result<TokenStream> {
+    let Some(node) = node else {
+        return Ok(quote!());
+    };
+    let crate_name = get_crate_name();
+    let object_ident = object.get_ident();
+    let (impl_generics, ty_generics, where_clause) = object.get_generics()?.split_for_impl();
+    let fetch = &node.fetch;
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::FetchNode for #object_ident #ty_generics #where_clause {
+            fn fetch_node(
+                ctx: &#crate_name::Context<'_>,
+                id: String,
+            ) -> impl ::std::future::Future<Output = #crate_name::Result<Option<Self>>> + Send {
+                #fetch(ctx, id)
+            }
+        }
+    })
+}
+
+pub fn get_register_node_code(node: Option<&NodeAttr>) -> TokenStream {
+    let crate_name = get_crate_name();
+    match node {
+        Some(_) => quote! {
+            let registry = #crate_name::internal::register_node::<Self>(registry);
+        },
+        None => quote!(),
+    }
+}
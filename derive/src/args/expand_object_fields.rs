@@ -15,16 +15,22 @@ use crate::utils::common::CommonMethod;
 use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
+use crate::utils::complexity::ComplexityAttr;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::federation::FieldFederation;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::BaseItemImpl;
 use crate::utils::impl_block::BaseMethod;
 use crate::utils::macros::*;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
 use crate::utils::type_utils::get_type_path;
 use crate::utils::type_utils::remove_path_generics;
+use crate::utils::validators::Validators;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
@@ -39,6 +45,25 @@ pub struct ExpandObjectFieldsArgAttrs {
 
     #[darling(default)]
     pub ctx: bool,
+
+    #[darling(default)]
+    pub loader: bool,
+
+    #[darling(default)]
+    pub default: Option<DefaultValue>,
+
+    #[darling(default)]
+    pub default_with: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub validator: Option<Validators>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 impl Attributes for ExpandObjectFieldsArgAttrs {
@@ -71,6 +96,29 @@ pub struct ExpandObjectFieldsMethodAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub guard: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub complexity: Option<ComplexityAttr>,
+
+    #[darling(default)]
+    pub entity: bool,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
+
+    #[darling(flatten)]
+    pub federation: FieldFederation,
 }
 
 impl Attributes for ExpandObjectFieldsMethodAttrs {
@@ -184,7 +232,7 @@ impl CommonField for ExpandObjectFieldsMethod {
     }
 
     fn get_skip(&self) -> bool {
-        self.attrs.skip
+        self.attrs.skip || self.attrs.entity
     }
 
     fn get_doc(&self) -> darling::Result<Option<String>> {
@@ -193,6 +241,32 @@ impl CommonField for ExpandObjectFieldsMethod {
     fn get_deprecation(&self) -> darling::Result<Deprecation> {
         Ok(self.attrs.deprecation.clone())
     }
+
+    fn get_guard(&self) -> Option<&syn::Expr> {
+        self.attrs.guard.as_ref()
+    }
+
+    fn get_complexity(&self) -> Option<&ComplexityAttr> {
+        self.attrs.complexity.as_ref()
+    }
+
+    fn get_federation(&self) -> Option<&FieldFederation> {
+        Some(&self.attrs.federation)
+    }
+
+    fn is_entity(&self) -> bool {
+        self.attrs.entity
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
@@ -224,6 +298,28 @@ impl CommonArg for ExpandObjectFieldsArg {
     fn is_marked_as_ctx(&self) -> bool {
         self.attrs.ctx
     }
+
+    fn is_marked_as_loader(&self) -> bool {
+        self.attrs.loader
+    }
+
+    fn get_default_value(&self) -> darling::Result<Option<DefaultValue>> {
+        DefaultValue::from_attrs(
+            self.attrs.default.as_ref(),
+            self.attrs.default_with.as_ref(),
+        )
+    }
+
+    fn get_validators(&self) -> Option<&Validators> {
+        self.attrs.validator.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl CommonMethod for ExpandObjectFieldsMethod {
@@ -249,7 +345,7 @@ impl ArgImplementor for ExpandObjectFieldsArg {
         let crate_name = get_crate_name();
         let arg_ident = common::get_arg_ident(self);
         Ok(quote! {
-            let parent = ctx.parent_value.try_downcast_ref::<<Self as #crate_name::internal::ParentType>::Type>()?.into();
+            let parent = #crate_name::internal::downcast_parent::<<Self as #crate_name::internal::ParentType>::Type>(ctx.parent_value)?.into();
             let #arg_ident = &parent;
         })
     }
@@ -296,6 +392,13 @@ impl FieldImplementor for ExpandObjectFieldsMethod {
     }
 
     fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
+        if !self.attrs.tags.is_empty() {
+            let crate_name = get_crate_name();
+            return common::get_object_field_usage_code(
+                self,
+                quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+            );
+        }
         let field_var_ident = get_field_var_ident(self.index, &self.ident);
 
         Ok(quote! {
@@ -342,10 +445,31 @@ fn use_fields_code(expand: &ExpandObjectFields) -> darling::Result<TokenStream>
         .iter()
         .filter(|method| !method.get_skip())
         .enumerate()
+        // the tagged fields are added by the registry
+        .filter(|(_, method)| method.attrs.tags.is_empty())
         .map(|(index, method)| use_field_code(index, method).into_token_stream())
         .collect())
 }
 
+/// declare the added fields, the same field can't be added by different expansions
+fn declare_fields_code(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    expand
+        .get_fields()?
+        .iter()
+        .filter(|method| !method.get_skip())
+        .map(|method| {
+            let field_name = common::get_field_name(method)?;
+            Ok(quote! {
+                let registry = registry.declare_expanded_field(
+                    <<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                    #field_name,
+                );
+            })
+        })
+        .collect()
+}
+
 fn impl_register(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let (impl_generics, _, where_clause) = expand.generics.split_for_impl();
@@ -355,8 +479,34 @@ fn impl_register(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
 
     let define_fields = common::get_define_fields_code(expand).into_token_stream();
 
+    let register_complexity = common::get_complexity_register_code(
+        expand,
+        quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+    )
+    .into_token_stream();
+
+    let register_fields_visible = common::get_fields_visible_register_code(
+        expand,
+        quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+        common::get_field_name,
+    )?;
+
+    let register_args_visible = common::get_args_visible_register_code(
+        expand,
+        quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+    let declare_loaders = common::get_loaders_declare_code(expand)?;
+
+    let declare_fields_types = common::get_fields_types_declare_code(
+        expand,
+        quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+
     let use_fields = use_fields_code(expand).into_token_stream();
 
+    let declare_fields = declare_fields_code(expand).into_token_stream();
+
+    let register_entities = common::get_entity_register_code(expand)?;
     let register_fns = common::call_register_fns();
     Ok(quote! {
         impl #impl_generics #crate_name::internal::Register for #ty #where_clause {
@@ -367,6 +517,21 @@ fn impl_register(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
                 #register_fns
 
                 #define_fields
+
+                #register_complexity
+
+                #register_fields_visible
+
+                #register_args_visible
+
+                #declare_loaders
+
+                #declare_fields_types
+
+                #register_entities
+
+                #declare_fields
+
                 registry.update_object(
                     <<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name().as_ref(),
                     <Self as #crate_name::internal::ExpandObject>::get_expand_object_name().as_ref(),
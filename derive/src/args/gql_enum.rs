@@ -22,6 +22,8 @@ use crate::utils::macros::*;
 use crate::utils::path_attr::PathAttr;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
@@ -35,6 +37,13 @@ pub struct EnumVariantAttributes {
 
     #[darling(default)]
     deprecation: Deprecation,
+
+    #[darling(default)]
+    visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    directives: Vec<syn::Expr>,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -66,6 +75,17 @@ pub struct EnumAttributes {
     #[darling(default, multiple)]
     #[darling(rename = "register")]
     pub registers: Vec<RegisterAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 from_derive_input!(
@@ -110,6 +130,16 @@ impl CommonObject for Enum {
     fn get_fields_rename_rule(&self) -> Option<&RenameRule> {
         self.attrs.rename_items.as_ref()
     }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl CommonField for EnumVariant {
@@ -137,6 +167,13 @@ impl CommonField for EnumVariant {
         Ok(self.attrs.deprecation.clone())
     }
 
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
+
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_items.as_ref()
     }
@@ -216,6 +253,11 @@ where
                 }
             }
         }
+        impl #crate_name::internal::ToValue for #enum_ident {
+            fn to_value(&self) -> #crate_name::Value {
+                self.into()
+            }
+        }
     })
 }
 
@@ -345,11 +387,13 @@ fn register_item(variant: &impl CommonField) -> darling::Result<TokenStream> {
     let name = get_enum_item_name(variant)?;
     let description = common::field_description(variant)?;
     let deprecated = field_deprecation_code(variant)?;
+    let directives = common::field_directives(variant);
     // todo rename field to item
     Ok(quote! {
         let field = #crate_name::dynamic::EnumItem::new(#name);
         #description
         #deprecated
+        #directives
         let object = object.item(field);
     })
 }
@@ -371,6 +415,20 @@ fn impl_register(enm: &Enum) -> darling::Result<TokenStream> {
     let enum_ident = enm.get_ident();
     let items = register_items(enm)?;
     let description = common::object_description(enm.get_doc()?.as_deref())?;
+    let directives = common::object_directives(enm);
+    let register_visible = common::get_type_visible_register_code(
+        enm,
+        quote!(<#enum_ident as #crate_name::internal::Enum>::get_enum_type_name()),
+    );
+    let register_tags = common::get_type_tags_register_code(
+        enm,
+        quote!(<#enum_ident as #crate_name::internal::Enum>::get_enum_type_name()),
+    );
+    let register_items_visible = common::get_fields_visible_register_code(
+        enm,
+        quote!(<#enum_ident as #crate_name::internal::Enum>::get_enum_type_name()),
+        get_enum_item_name,
+    )?;
     let register_union = common::register_object_code();
     let register_attr = &enm.attrs.registers;
     // todo rename object to enm
@@ -380,7 +438,11 @@ fn impl_register(enm: &Enum) -> darling::Result<TokenStream> {
                 #( #register_attr )*
                 let object = #crate_name::dynamic::Enum::new(<#enum_ident as #crate_name::internal::Enum>::get_enum_type_name().as_ref());
                 #description
+                #directives
                 #items
+                #register_visible
+                #register_tags
+                #register_items_visible
                 #register_union
             }
         }
@@ -0,0 +1,190 @@
+use darling::util::Override;
+use darling::FromAttributes;
+use proc_macro2::Ident;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+use syn::Generics;
+use syn::LitStr;
+
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseEnum;
+use crate::utils::derive_types::BaseField;
+use crate::utils::derive_types::BaseVariant;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameTarget;
+use crate::utils::with_attributes::WithAttributes;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorFieldAttrs {
+    /// add the field to the extensions, optionally with a different name
+    #[darling(default)]
+    pub extension: Option<Override<String>>,
+}
+
+from_field!(
+    GraphQLErrorField,
+    WithAttributes<GraphQLErrorFieldAttrs, BaseField>,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorVariantAttrs {
+    /// the message of the error, `{name}` and `{0}` are replaced with the fields of the variant
+    pub message: String,
+
+    #[darling(default)]
+    pub code: Option<String>,
+}
+
+from_variant!(
+    GraphQLErrorVariant,
+    WithAttributes<GraphQLErrorVariantAttrs, BaseVariant<GraphQLErrorField>>,
+);
+
+from_derive_input!(GraphQLError, BaseEnum<GraphQLErrorVariant, Generics>);
+
+/// the binding of the tuple field `index` in the match arm
+fn tuple_binding(index: usize) -> Ident {
+    Ident::new(&format!("__field_{}", index), Span::call_site())
+}
+
+/// rewrite the positional arguments of the message, `{0}` to `{__field_0}`, so all the fields
+/// are captured by name
+fn rewrite_positional_args(message: &str) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        result.push(c);
+        if c != '{' {
+            continue;
+        }
+        match chars.peek() {
+            Some('{') => {
+                result.push('{');
+                chars.next();
+            }
+            Some(next) if next.is_ascii_digit() => result.push_str("__field_"),
+            _ => {}
+        }
+    }
+    result
+}
+
+fn get_variant_bindings(variant: &GraphQLErrorVariant) -> Vec<Ident> {
+    variant
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => Ident::new(&ident.to_string(), Span::call_site()),
+            None => tuple_binding(index),
+        })
+        .collect()
+}
+
+fn get_variant_pattern(
+    ident: &Ident,
+    variant: &GraphQLErrorVariant,
+    bindings: &[Ident],
+) -> TokenStream {
+    let variant_ident = &variant.ident;
+    match variant.fields.style {
+        darling::ast::Style::Struct => quote!(#ident::#variant_ident { #(#bindings),* }),
+        darling::ast::Style::Tuple => quote!(#ident::#variant_ident ( #(#bindings),* )),
+        darling::ast::Style::Unit => quote!(#ident::#variant_ident),
+    }
+}
+
+fn get_message(variant: &GraphQLErrorVariant) -> TokenStream {
+    let message = rewrite_positional_args(&variant.attrs.message);
+    let message = LitStr::new(&message, Span::call_site());
+    quote!(format!(#message))
+}
+
+fn get_extensions(
+    variant: &GraphQLErrorVariant,
+    bindings: &[Ident],
+) -> darling::Result<Vec<TokenStream>> {
+    let crate_name = get_crate_name();
+    variant
+        .fields
+        .iter()
+        .zip(bindings)
+        .filter_map(|(field, binding)| {
+            let extension = field.attrs.extension.as_ref()?;
+            let name = match (extension, &field.ident) {
+                (Override::Explicit(name), _) => name.clone(),
+                (Override::Inherit, Some(ident)) => RenameTarget::Field.rename(ident.to_string()),
+                (Override::Inherit, None) => {
+                    return Some(Err(darling::Error::custom(
+                        "the extension of a tuple field must have a name, like `extension = \"name\"`",
+                    )
+                    .with_span(&field.ty)));
+                }
+            };
+            Some(Ok(quote! {
+                __extensions.set(#name, #crate_name::internal::ToValue::to_value(#binding));
+            }))
+        })
+        .collect()
+}
+
+fn impl_match_arm(ident: &Ident, variant: &GraphQLErrorVariant) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let bindings = get_variant_bindings(variant);
+    let pattern = get_variant_pattern(ident, variant, &bindings);
+    let message = get_message(variant);
+    let code = variant
+        .attrs
+        .code
+        .clone()
+        .unwrap_or_else(|| RenameTarget::EnumItem.rename(variant.ident.to_string()));
+    let extensions = get_extensions(variant, &bindings)?;
+
+    Ok(quote! {
+        #pattern => {
+            #crate_name::ErrorExtensions::extend_with(
+                #crate_name::Error::new(#message),
+                |_, __extensions| {
+                    __extensions.set("code", #code);
+                    #(#extensions)*
+                },
+            )
+        }
+    })
+}
+
+fn impl_error_extensions(error: &GraphQLError) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &error.ident;
+    let (impl_generics, ty_generics, where_clause) = error.generics.split_for_impl();
+    let arms = error
+        .data
+        .iter()
+        .map(|variant| impl_match_arm(ident, variant))
+        .collect::<darling::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::ErrorExtensions for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn extend(&self) -> #crate_name::Error {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+impl ToTokens for GraphQLError {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_error_extensions = impl_error_extensions(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_error_extensions
+        });
+    }
+}
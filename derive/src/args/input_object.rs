@@ -12,12 +12,17 @@ use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::common::EMPTY_ARGS;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::derive_types::BaseStruct;
 use crate::utils::derive_types::NamedField;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
+use crate::utils::validators::get_validators_code;
+use crate::utils::validators::Validators;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
@@ -31,6 +36,22 @@ pub struct InputObjectFieldAttrs {
 
     #[darling(default)]
     pub name: Option<String>,
+
+    #[darling(default)]
+    pub default: Option<DefaultValue>,
+
+    #[darling(default)]
+    pub default_with: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub validator: Option<Validators>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -62,6 +83,17 @@ pub struct InputObjectAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "register")]
     pub registers: Vec<RegisterAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 from_derive_input!(
@@ -105,6 +137,16 @@ impl CommonObject for InputObject {
     fn get_fields_rename_rule(&self) -> Option<&RenameRule> {
         self.attrs.rename_fields.as_ref()
     }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl CommonField for InputObjectField {
@@ -131,6 +173,24 @@ impl CommonField for InputObjectField {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
+
+    fn get_default_value(&self) -> darling::Result<Option<DefaultValue>> {
+        DefaultValue::from_attrs(
+            self.attrs.default.as_ref(),
+            self.attrs.default_with.as_ref(),
+        )
+    }
+
+    fn get_validators(&self) -> Option<&Validators> {
+        self.attrs.validator.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl GetFields<InputObjectField> for InputObject {
@@ -148,9 +208,17 @@ impl GetArgs<()> for InputObjectField {
 fn get_define_field(field: &impl CommonField) -> darling::Result<TokenStream> {
     let description = common::field_description(field)?;
     let get_new_input_value_code = common::get_new_input_value_code(field)?;
+    let default_value = common::get_default_value_code(
+        &quote!(field),
+        field.get_type()?,
+        field.get_default_value(),
+    )?;
+    let directives = common::field_directives(field);
     Ok(quote! {
         #get_new_input_value_code
         #description
+        #default_value
+        #directives
         let object = object.field(field);
     })
 }
@@ -176,6 +244,24 @@ fn impl_register(object: &InputObject) -> darling::Result<TokenStream> {
     let define_object = common::impl_define_input_object();
     let define_fields = get_define_fields(object)?;
     let description = common::object_description(object.get_doc()?.as_deref())?;
+    let directives = common::object_directives(object);
+    let register_visible = common::get_type_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+    );
+    let register_tags = common::get_type_tags_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+    );
+    let declare_fields_types = common::get_input_fields_types_declare_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+    )?;
+    let register_fields_visible = common::get_fields_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+        common::get_input_field_name,
+    )?;
     let register_object_code = common::register_object_code();
 
     let register_attr = &object.attrs.registers;
@@ -191,9 +277,18 @@ fn impl_register(object: &InputObject) -> darling::Result<TokenStream> {
                 #define_object
 
                 #description
+                #directives
 
                 #define_fields
 
+                #register_visible
+
+                #register_tags
+
+                #declare_fields_types
+
+                #register_fields_visible
+
                 #register_object_code
             }
         }
@@ -214,9 +309,26 @@ fn get_field_value(index: usize, field: &InputObjectField) -> darling::Result<To
             let #item = Default::default();
         });
     }
-    Ok(quote! {
-        let #item = #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?;
-    })
+    let validators = get_validators_code(
+        field.get_validators(),
+        field.get_type()?,
+        &item,
+        &field_name,
+        quote!(into_field_error),
+    );
+    match field.get_default_value()? {
+        None => Ok(quote! {
+            let #item = #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?;
+            #validators
+        }),
+        Some(default_value) => Ok(quote! {
+            let #item = match __object.get(#field_name) {
+                Some(value) => #crate_name::internal::FromValue::from_value(Ok(value)).map_err(|e| e.into_field_error(#field_name))?,
+                None => #default_value,
+            };
+            #validators
+        }),
+    }
 }
 
 fn get_fields_value(object: &InputObject) -> TokenStream {
@@ -273,15 +385,41 @@ fn impl_from_value(object: &InputObject) -> darling::Result<TokenStream> {
     ))
 }
 
+fn impl_to_value(object: &InputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = object.get_ident();
+    let fields = object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| {
+            let field_ident = field.get_ident()?;
+            let field_name = common::get_input_field_name(field)?;
+            Ok(quote! {
+                (#field_name, #crate_name::internal::ToValue::to_value(&self.#field_ident)),
+            })
+        })
+        .collect::<darling::Result<Vec<_>>>()?;
+    Ok(quote!(
+        impl #crate_name::internal::ToValue for #ident {
+            fn to_value(&self) -> #crate_name::Value {
+                #crate_name::internal::object_value([#(#fields)*])
+            }
+        }
+    ))
+}
+
 impl ToTokens for InputObject {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let impl_object = common::impl_input_object(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_to_value = impl_to_value(self).into_token_stream();
         tokens.extend(quote! {
             #impl_object
             #impl_register
             #impl_from_value
+            #impl_to_value
         });
     }
 }
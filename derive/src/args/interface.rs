@@ -18,15 +18,21 @@ use crate::utils::common::CommonField;
 use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
+use crate::utils::complexity::ComplexityAttr;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::BaseItemTrait;
 use crate::utils::impl_block::BaseMethod;
 use crate::utils::impl_block::FromItemTrait;
+use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::macros::*;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
+use crate::utils::validators::Validators;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::SetContext;
@@ -65,6 +71,25 @@ pub struct InterfaceMethodArgAttrs {
 
     #[darling(default)]
     pub ctx: bool,
+
+    #[darling(default)]
+    pub loader: bool,
+
+    #[darling(default)]
+    pub default: Option<DefaultValue>,
+
+    #[darling(default)]
+    pub default_with: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub validator: Option<Validators>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 impl Attributes for InterfaceMethodArgAttrs {
@@ -97,6 +122,23 @@ pub struct InterfaceMethodAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub guard: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub complexity: Option<ComplexityAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 impl Attributes for InterfaceMethodAttrs {
@@ -155,6 +197,28 @@ impl CommonArg for InterfaceMethodArg {
     fn is_marked_as_ctx(&self) -> bool {
         self.attrs.ctx
     }
+
+    fn is_marked_as_loader(&self) -> bool {
+        self.attrs.loader
+    }
+
+    fn get_default_value(&self) -> darling::Result<Option<DefaultValue>> {
+        DefaultValue::from_attrs(
+            self.attrs.default.as_ref(),
+            self.attrs.default_with.as_ref(),
+        )
+    }
+
+    fn get_validators(&self) -> Option<&Validators> {
+        self.attrs.validator.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl GetArgs<InterfaceMethodArg> for InterfaceMethod {
@@ -186,6 +250,22 @@ pub struct InterfaceAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "auto_register")]
     pub auto_registers: Vec<RegisterAttr>,
+
+    /// parent interfaces, `#[graphql(implements(Node))]`
+    #[darling(default, multiple)]
+    #[darling(rename = "implements")]
+    pub impls: Vec<InterfaceImplAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 impl Attributes for InterfaceAttrs {
@@ -240,6 +320,16 @@ impl CommonObject for Interface {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.doc.clone())
     }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl GetFields<InterfaceMethod> for Interface {
@@ -275,6 +365,24 @@ impl CommonField for InterfaceMethod {
         Ok(self.attrs.deprecation.clone())
     }
 
+    fn get_guard(&self) -> Option<&syn::Expr> {
+        self.attrs.guard.as_ref()
+    }
+
+    fn get_complexity(&self) -> Option<&ComplexityAttr> {
+        self.attrs.complexity.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
+
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
@@ -19,8 +19,9 @@ use crate::utils::crate_name::get_crate_name;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
 
-struct OthersMethod<'a>(&'a InterfaceMethod);
+struct OthersMethod<'a>(&'a InterfaceMethod, &'a Ident);
 
 impl GetArgs<InterfaceMethodArg> for OthersMethod<'_> {
     fn get_args(&self) -> darling::Result<&Vec<InterfaceMethodArg>> {
@@ -59,6 +60,10 @@ impl<'a> CommonField for OthersMethod<'a> {
         self.0.get_deprecation()
     }
 
+    fn get_guard(&self) -> Option<&syn::Expr> {
+        self.0.get_guard()
+    }
+
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.0.get_field_rename_rule()
     }
@@ -66,6 +71,10 @@ impl<'a> CommonField for OthersMethod<'a> {
     fn get_args_rename_rule(&self) -> Option<&RenameRule> {
         self.0.get_args_rename_rule()
     }
+
+    fn get_tags(&self) -> &[TagsAttr] {
+        self.0.get_tags()
+    }
 }
 
 impl<'a> FieldImplementor for OthersMethod<'a> {
@@ -74,8 +83,7 @@ impl<'a> FieldImplementor for OthersMethod<'a> {
     }
 
     fn get_execute_code(&self) -> darling::Result<TokenStream> {
-        // let type_ident = self.0.ctx.rename_args;
-        execute_code(self)
+        execute_code(self, self.1)
     }
 
     fn get_resolve_code(&self) -> darling::Result<TokenStream> {
@@ -95,6 +103,13 @@ impl<'a> FieldImplementor for OthersMethod<'a> {
     }
 
     fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
+        if !self.get_tags().is_empty() {
+            let crate_name = get_crate_name();
+            return common::get_object_field_usage_code(
+                self,
+                quote!(<T as #crate_name::internal::Object>::get_object_type_name()),
+            );
+        }
         let field_var_ident = get_field_var_ident(self.0.index, &self.0.ident);
 
         Ok(quote! {
@@ -106,9 +121,10 @@ impl<'a> FieldImplementor for OthersMethod<'a> {
 impl ArgImplementor for InterfaceMethodArg {
     fn get_self_arg_definition(&self) -> darling::Result<TokenStream> {
         let arg_ident = common::get_arg_ident(self);
+        let crate_name = get_crate_name();
 
         Ok(quote! {
-            let parent = ctx.parent_value.try_downcast_ref::<T>()?;
+            let parent = #crate_name::internal::downcast_parent::<T>(ctx.parent_value)?;
             let #arg_ident = parent;
         })
     }
@@ -126,7 +142,7 @@ impl ArgImplementor for InterfaceMethodArg {
     }
 }
 
-fn execute_code<F, A>(method: &F) -> darling::Result<TokenStream>
+fn execute_code<F, A>(method: &F, interface_ident: &Ident) -> darling::Result<TokenStream>
 where
     F: CommonMethod + GetArgs<A>,
     A: CommonArg + ArgImplementor,
@@ -137,11 +153,11 @@ where
 
     if method.is_async() {
         Ok(quote! {
-            let value = T::#field_ident(#args).await;
+            let value = <T as #interface_ident>::#field_ident(#args).await;
         })
     } else {
         Ok(quote! {
-            let value = T::#field_ident(#args);
+            let value = <T as #interface_ident>::#field_ident(#args);
         })
     }
 }
@@ -151,7 +167,7 @@ fn define_fields_code(input: &Interface) -> darling::Result<TokenStream> {
         .get_fields()?
         .iter()
         .filter(|method| !method.get_skip())
-        .map(|method| common::build_field(&OthersMethod(method)).into_token_stream())
+        .map(|method| common::build_field(&OthersMethod(method, &input.ident)).into_token_stream())
         .collect())
 }
 
@@ -172,6 +188,8 @@ fn use_fields_code(input: &Interface) -> darling::Result<TokenStream> {
         .get_fields()?
         .iter()
         .filter(|method| !method.get_skip())
+        // the tagged fields are added by the registry
+        .filter(|method| method.attrs.tags.is_empty())
         .map(|method| use_field_code(method).into_token_stream())
         .collect())
 }
@@ -184,6 +202,36 @@ pub fn impl_others_register(input: &Interface) -> darling::Result<TokenStream> {
 
     let use_fields = use_fields_code(input).into_token_stream();
 
+    let register_complexity = common::get_complexity_register_code(
+        input,
+        quote!(<T as #crate_name::internal::Object>::get_object_type_name()),
+    )
+    .into_token_stream();
+
+    let register_fields_visible = common::get_fields_visible_register_code(
+        input,
+        quote!(<T as #crate_name::internal::Object>::get_object_type_name()),
+        common::get_field_name,
+    )
+    .into_token_stream();
+
+    let register_args_visible = common::get_args_visible_register_code(
+        input,
+        quote!(<T as #crate_name::internal::Object>::get_object_type_name()),
+    )
+    .into_token_stream();
+
+    let declare_fields_types = common::get_fields_types_declare_code(
+        input,
+        quote!(<T as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+    let declare_loaders = common::get_loaders_declare_code(input)?;
+
+    // objects implementing the interface implement its parents too, the parent fields are
+    // declared by the interface
+    let parents = input.attrs.impls.iter().map(|parent| &parent.path);
+    let declare_parents = parents.clone();
+
     let mut auto_registers = input.attrs.auto_registers.clone();
     auto_registers.iter_mut().for_each(|register| {
         // add <T> to last segment
@@ -203,12 +251,28 @@ pub fn impl_others_register(input: &Interface) -> darling::Result<TokenStream> {
             {
                 #( #auto_registers )*
                 #define_fields
+                #register_complexity
+                #register_fields_visible
+                #register_args_visible
+                #declare_loaders
+                #declare_fields_types
+                let registry = registry.declare_implement(
+                    <T as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                    <dyn #ident as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
+                );
+                #(
+                    let registry = registry.declare_implement(
+                        <T as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                        <dyn #declare_parents as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
+                    );
+                )*
                 registry.update_object(
                     <T as #crate_name::internal::Object>::get_object_type_name().as_ref(),
                     <dyn #ident as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
                     |object| {
                         #use_fields
                         let object = object.implement(<dyn #ident as #crate_name::internal::Interface>::get_interface_type_name().as_ref());
+                        #( let object = object.implement(<dyn #parents as #crate_name::internal::Interface>::get_interface_type_name().as_ref()); )*
                         object
                     },
                 )
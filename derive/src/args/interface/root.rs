@@ -47,9 +47,11 @@ impl FieldImplementor for InterfaceMethod {
     }
 
     fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
-        Ok(quote! {
-            let object = object.field(field);
-        })
+        let crate_name = get_crate_name();
+        common::get_interface_field_usage_code(
+            self,
+            quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+        )
     }
 }
 
@@ -67,10 +69,19 @@ pub fn impl_interface(input: &Interface) -> darling::Result<TokenStream> {
         }
     });
 
+    // instances of the interface are instances of its parents
+    let marks = input.attrs.impls.iter().map(|parent| {
+        let path = &parent.path;
+        quote! {
+            impl #crate_name::internal::InterfaceMark<dyn #path> for dyn #ident {}
+        }
+    });
+
     Ok(quote! {
         #type_name
         impl #crate_name::internal::OutputTypeName for dyn #ident {}
         impl #crate_name::internal::Interface for dyn #ident {}
+        #(#marks)*
     })
 }
 
@@ -80,10 +91,40 @@ pub fn impl_register(input: &Interface) -> darling::Result<TokenStream> {
     let register_nested_types = common::get_nested_type_register_code(input).into_token_stream();
 
     let description = common::object_description(input.get_doc()?.as_deref())?;
+    let directives = common::object_directives(input);
     let define_fields = common::get_define_fields_code(input)?;
+    let register_complexity = common::get_complexity_register_code(
+        input,
+        quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+    )?;
+    let register_visible = common::get_type_visible_register_code(
+        input,
+        quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+    );
+    let register_fields_visible = common::get_fields_visible_register_code(
+        input,
+        quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+        common::get_field_name,
+    )?;
+    let register_args_visible = common::get_args_visible_register_code(
+        input,
+        quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+    )?;
+    let declare_loaders = common::get_loaders_declare_code(input)?;
+    let register_tags = common::get_type_tags_register_code(
+        input,
+        quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+    );
+    let declare_fields_types = common::get_fields_types_declare_code(
+        input,
+        quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+    )?;
     let register_code = common::register_object_code();
 
     let register_attr = &input.attrs.registers;
+    let parents = input.attrs.impls.iter().map(|parent| &parent.path);
+    let register_parents = parents.clone();
+    let declare_parents = parents.clone();
 
     Ok(quote! {
         impl #crate_name::internal::Register for dyn #ident {
@@ -91,13 +132,30 @@ pub fn impl_register(input: &Interface) -> darling::Result<TokenStream> {
 
                 #( #register_attr )*
 
+                #( let registry = registry.register::<dyn #register_parents>(); )*
+                #(
+                    let registry = registry.declare_implement(
+                        <Self as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
+                        <dyn #declare_parents as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
+                    );
+                )*
+
                 #register_nested_types
 
                 // todo rename to interface
                 let object = #crate_name::dynamic::Interface::new(<Self as #crate_name::internal::Interface>::get_interface_type_name().as_ref());
 
+                #( let object = object.implement(<dyn #parents as #crate_name::internal::Interface>::get_interface_type_name()); )*
                 #description
+                #directives
                 #define_fields
+                #register_complexity
+                #register_visible
+                #register_fields_visible
+                #register_args_visible
+                #declare_loaders
+                #register_tags
+                #declare_fields_types
                 #register_code
             }
         }
@@ -2,15 +2,20 @@ pub use app::App;
 pub use expand_object::ExpandObject;
 pub use expand_object_fields::ExpandObjectFields;
 pub use gql_enum::Enum;
+pub use graphql_error::GraphQLError;
 pub use input_object::InputObject;
 pub use interface::Interface;
 pub use mutation::Mutation;
 pub use mutation_fields::MutationFields;
 pub use mutation_root::MutationRoot;
+pub use one_of_input_object::OneOfInputObject;
 pub use resolved_object::ResolvedObject;
 pub use resolved_object_fields::ResolvedObjectFields;
 pub use scalar::Scalar;
 pub use simple_object::SimpleObject;
+pub use subscription::Subscription;
+pub use subscription_fields::SubscriptionFields;
+pub use subscription_root::SubscriptionRoot;
 pub use union::Union;
 
 mod app;
@@ -18,13 +23,18 @@ mod common;
 mod expand_object;
 mod expand_object_fields;
 mod gql_enum;
+mod graphql_error;
 mod input_object;
 mod interface;
 mod mutation;
 mod mutation_fields;
 mod mutation_root;
+mod one_of_input_object;
 mod resolved_object;
 mod resolved_object_fields;
 mod scalar;
 mod simple_object;
+mod subscription;
+mod subscription_fields;
+mod subscription_root;
 mod union;
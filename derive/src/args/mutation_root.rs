@@ -56,6 +56,11 @@ impl From<MutationRoot> for SimpleObject {
                     registers,
                     marks: vec![],
                     impls: vec![],
+                    node: None,
+                    visible: None,
+                    tags: vec![],
+                    directives: vec![],
+                    federation: Default::default(),
                 },
             },
             inner: BaseStruct {
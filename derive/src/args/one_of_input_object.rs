@@ -0,0 +1,321 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+use syn::Generics;
+use syn::Path;
+
+use crate::args::common;
+use crate::args::input_object::InputObjectAttrs;
+use crate::utils::common::CommonField;
+use crate::utils::common::CommonObject;
+use crate::utils::common::GetArgs;
+use crate::utils::common::GetFields;
+use crate::utils::common::EMPTY_ARGS;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseEnum;
+use crate::utils::derive_types::NewtypeVariant;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
+use crate::utils::type_utils::get_owned_type;
+use crate::utils::visible::VisibleAttr;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::WithContext;
+use crate::utils::with_doc::WithDoc;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct OneOfInputObjectVariantAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct OneOfInputObjectVariantContext {
+    pub rename_fields: Option<RenameRule>,
+}
+
+from_variant!(
+    OneOfInputObjectVariant,
+    WithAttributes<
+        WithDoc<OneOfInputObjectVariantAttrs>,
+        WithContext<OneOfInputObjectVariantContext, NewtypeVariant>,
+    >,
+);
+
+from_derive_input!(
+    OneOfInputObject,
+    WithAttributes<WithDoc<InputObjectAttrs>, BaseEnum<OneOfInputObjectVariant, Generics>>,
+    ctx,
+);
+
+impl MakeContext<OneOfInputObjectVariantContext> for OneOfInputObject {
+    fn make_context(&self) -> OneOfInputObjectVariantContext {
+        OneOfInputObjectVariantContext {
+            rename_fields: self.attrs.rename_fields,
+        }
+    }
+}
+
+impl CommonObject for OneOfInputObject {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn should_impl_type_name(&self) -> bool {
+        !self.attrs.type_name
+    }
+
+    fn get_ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn get_type(&self) -> darling::Result<Path> {
+        Ok(self.ident.clone().into())
+    }
+
+    fn get_generics(&self) -> darling::Result<&Generics> {
+        Ok(&self.generics)
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+    fn get_fields_rename_rule(&self) -> Option<&RenameRule> {
+        self.attrs.rename_fields.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
+}
+
+impl CommonField for OneOfInputObjectVariant {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_ident(&self) -> darling::Result<&syn::Ident> {
+        Ok(&self.ident)
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        Ok(&self.fields.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        false
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_fields.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+}
+
+impl GetFields<OneOfInputObjectVariant> for OneOfInputObject {
+    fn get_fields(&self) -> darling::Result<&Vec<OneOfInputObjectVariant>> {
+        Ok(&self.data)
+    }
+}
+
+impl GetArgs<()> for OneOfInputObjectVariant {
+    fn get_args(&self) -> darling::Result<&Vec<()>> {
+        Ok(&EMPTY_ARGS)
+    }
+}
+
+fn get_define_field(variant: &OneOfInputObjectVariant) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let field_name = common::get_input_field_name(variant)?;
+    let field_type = get_owned_type(variant.get_type()?);
+    let description = common::field_description(variant)?;
+    // all fields of a oneOf input object are nullable
+    Ok(quote! {
+        let field = #crate_name::dynamic::InputValue::new(#field_name, <Option<#field_type> as #crate_name::internal::GetInputTypeRef>::get_input_type_ref());
+        #description
+        let object = object.field(field);
+    })
+}
+
+fn get_define_fields(object: &OneOfInputObject) -> darling::Result<TokenStream> {
+    Ok(object
+        .get_fields()?
+        .iter()
+        .map(|variant| get_define_field(variant).into_token_stream())
+        .collect())
+}
+
+fn impl_register(object: &OneOfInputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
+
+    let define_object = common::impl_define_input_object();
+    let define_fields = get_define_fields(object)?;
+    let description = common::object_description(object.get_doc()?.as_deref())?;
+    let directives = common::object_directives(object);
+    let register_visible = common::get_type_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+    );
+    let register_tags = common::get_type_tags_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+    );
+    let declare_fields_types = common::get_input_fields_types_declare_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+    )?;
+    let register_fields_visible = common::get_fields_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::InputObject>::get_input_object_type_name()),
+        common::get_input_field_name,
+    )?;
+    let register_object_code = common::register_object_code();
+
+    let register_attr = &object.attrs.registers;
+
+    Ok(quote! {
+        impl #crate_name::internal::Register for #ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+
+                #( #register_attr )*
+
+                #register_nested_types
+
+                #define_object
+
+                let object = object.oneof();
+
+                #description
+                #directives
+
+                #define_fields
+
+                #register_visible
+
+                #register_tags
+
+                #declare_fields_types
+
+                #register_fields_visible
+
+                #register_object_code
+            }
+        }
+    })
+}
+
+fn get_variant_match_item(variant: &OneOfInputObjectVariant) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let variant_ident = &variant.ident;
+    let field_name = common::get_input_field_name(variant)?;
+
+    Ok(quote! {
+        #field_name => Ok(Self::#variant_ident(
+            #crate_name::internal::FromValue::from_value(Ok(__field_value)).map_err(|e| e.into_field_error(#field_name))?,
+        )),
+    })
+}
+
+fn impl_from_value(object: &OneOfInputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = object.get_ident();
+    let match_items: TokenStream = object
+        .get_fields()?
+        .iter()
+        .map(|variant| get_variant_match_item(variant).into_token_stream())
+        .collect();
+
+    Ok(quote!(
+        impl #crate_name::internal::FromValue for #ident {
+            fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let __value = __value?;
+                let __object = __value.object()?;
+                let mut __fields = __object.iter().filter(|(_, value)| !value.is_null());
+                let (__field_name, __field_value) = match (__fields.next(), __fields.next()) {
+                    (Some(field), None) => field,
+                    (None, _) => {
+                        return Err(#crate_name::internal::InputValueError::custom(
+                            "Exactly one field must be set, but none was given.",
+                        ));
+                    }
+                    (Some(_), Some(_)) => {
+                        return Err(#crate_name::internal::InputValueError::custom(
+                            "Exactly one field must be set, but more than one was given.",
+                        ));
+                    }
+                };
+                match __field_name.as_str() {
+                    #match_items
+                    name => Err(#crate_name::internal::InputValueError::custom(
+                        format!("Unknown field \"{}\".", name),
+                    )),
+                }
+            }
+        }
+    ))
+}
+
+fn impl_to_value(object: &OneOfInputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = object.get_ident();
+    let match_items = object
+        .get_fields()?
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            let field_name = common::get_input_field_name(variant)?;
+            Ok(quote! {
+                Self::#variant_ident(value) => #crate_name::internal::object_value([
+                    (#field_name, #crate_name::internal::ToValue::to_value(value)),
+                ]),
+            })
+        })
+        .collect::<darling::Result<Vec<_>>>()?;
+    Ok(quote!(
+        impl #crate_name::internal::ToValue for #ident {
+            fn to_value(&self) -> #crate_name::Value {
+                match self {
+                    #(#match_items)*
+                }
+            }
+        }
+    ))
+}
+
+impl ToTokens for OneOfInputObject {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_object = common::impl_input_object(self).into_token_stream();
+        let impl_register = impl_register(self).into_token_stream();
+        let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_to_value = impl_to_value(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_object
+            #impl_register
+            #impl_from_value
+            #impl_to_value
+        });
+    }
+}
@@ -14,15 +14,19 @@ use crate::utils::common::CommonObject;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::derive_types::BaseStruct;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::federation::ObjectFederation;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
 use crate::utils::macros::*;
+use crate::utils::node_attr::NodeAttr;
 use crate::utils::register_attr::RegisterAttr;
+use crate::utils::tags::TagsAttr;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_doc::WithDoc;
 
 #[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
+#[darling(attributes(graphql), and_then = Self::add_node_impl)]
 pub struct ResolvedObjectAttrs {
     #[darling(default)]
     pub root: bool,
@@ -45,6 +49,32 @@ pub struct ResolvedObjectAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "implements")]
     pub impls: Vec<InterfaceImplAttr>,
+
+    #[darling(default)]
+    pub node: Option<NodeAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
+
+    #[darling(flatten)]
+    pub federation: ObjectFederation,
+}
+
+impl ResolvedObjectAttrs {
+    fn add_node_impl(mut self) -> darling::Result<Self> {
+        if let Some(node) = &self.node {
+            self.impls.push(node.get_interface_impl());
+        }
+        Ok(self)
+    }
 }
 
 from_derive_input!(
@@ -76,6 +106,16 @@ impl CommonObject for ResolvedObject {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.doc.clone())
     }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl CommonInterfaceAttrs for ResolvedObject {
@@ -182,11 +222,40 @@ fn impl_registers_fn(object: &ResolvedObject) -> darling::Result<TokenStream> {
     let (impl_generics, ty_generics, where_clause) = object.get_generics()?.split_for_impl();
 
     let register_attr = &object.attrs.registers;
+    let register_node = common::get_register_node_code(object.attrs.node.as_ref());
+    let register_visible = common::get_type_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    );
+    let register_tags = common::get_type_tags_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    );
+    let federation = &object.attrs.federation;
+    let directives = common::object_directives(object);
+    let has_federation = !federation.keys.is_empty() || federation.shareable;
+    let register_federation = (has_federation || !object.attrs.directives.is_empty()).then(|| {
+        quote! {
+            let registry = registry.update_object(
+                <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                |object| {
+                    #federation
+                    #directives
+                    object
+                },
+            );
+        }
+    });
 
     Ok(quote! {
         impl #impl_generics #object_ident #ty_generics #where_clause {
             fn __registers(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
                 #( #register_attr )*
+                #register_node
+                #register_visible
+                #register_tags
+                #register_federation
                 registry
             }
         }
@@ -224,6 +293,8 @@ impl ToTokens for ResolvedObject {
         let impl_register_extras = impl_register_fns_trait(self).into_token_stream();
         let impl_interface_mark = common::impl_interface_mark(self).into_token_stream();
         let impl_registers_fn = impl_registers_fn(self).into_token_stream();
+        let impl_fetch_node =
+            common::impl_fetch_node(self, self.attrs.node.as_ref()).into_token_stream();
 
         tokens.extend(quote! {
             #impl_registers_fn
@@ -235,6 +306,7 @@ impl ToTokens for ResolvedObject {
             #register_interface
             #register_root
             #impl_register_extras
+            #impl_fetch_node
         });
     }
 }
@@ -16,15 +16,21 @@ use crate::utils::common::CommonMethod;
 use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
+use crate::utils::complexity::ComplexityAttr;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::federation::FieldFederation;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::BaseItemImpl;
 use crate::utils::impl_block::BaseMethod;
 use crate::utils::macros::*;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
 use crate::utils::type_utils::get_type_path;
+use crate::utils::validators::Validators;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
@@ -39,6 +45,25 @@ pub struct ResolvedObjectFieldsArgAttrs {
 
     #[darling(default)]
     pub ctx: bool,
+
+    #[darling(default)]
+    pub loader: bool,
+
+    #[darling(default)]
+    pub default: Option<DefaultValue>,
+
+    #[darling(default)]
+    pub default_with: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub validator: Option<Validators>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 impl Attributes for ResolvedObjectFieldsArgAttrs {
@@ -71,6 +96,29 @@ pub struct ResolvedObjectFieldsMethodAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub guard: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub complexity: Option<ComplexityAttr>,
+
+    #[darling(default)]
+    pub entity: bool,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
+
+    #[darling(flatten)]
+    pub federation: FieldFederation,
 }
 
 impl Attributes for ResolvedObjectFieldsMethodAttrs {
@@ -182,7 +230,7 @@ impl CommonField for ResolvedObjectFieldsMethod {
     }
 
     fn get_skip(&self) -> bool {
-        self.attrs.skip
+        self.attrs.skip || self.attrs.entity
     }
 
     fn get_doc(&self) -> darling::Result<Option<String>> {
@@ -191,6 +239,32 @@ impl CommonField for ResolvedObjectFieldsMethod {
     fn get_deprecation(&self) -> darling::Result<Deprecation> {
         Ok(self.attrs.deprecation.clone())
     }
+
+    fn get_guard(&self) -> Option<&syn::Expr> {
+        self.attrs.guard.as_ref()
+    }
+
+    fn get_complexity(&self) -> Option<&ComplexityAttr> {
+        self.attrs.complexity.as_ref()
+    }
+
+    fn get_federation(&self) -> Option<&FieldFederation> {
+        Some(&self.attrs.federation)
+    }
+
+    fn is_entity(&self) -> bool {
+        self.attrs.entity
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
@@ -222,6 +296,28 @@ impl CommonArg for ResolvedObjectFieldsArg {
     fn is_marked_as_ctx(&self) -> bool {
         self.attrs.ctx
     }
+
+    fn is_marked_as_loader(&self) -> bool {
+        self.attrs.loader
+    }
+
+    fn get_default_value(&self) -> darling::Result<Option<DefaultValue>> {
+        DefaultValue::from_attrs(
+            self.attrs.default.as_ref(),
+            self.attrs.default_with.as_ref(),
+        )
+    }
+
+    fn get_validators(&self) -> Option<&Validators> {
+        self.attrs.validator.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl CommonMethod for ResolvedObjectFieldsMethod {
@@ -248,7 +344,7 @@ impl ArgImplementor for ResolvedObjectFieldsArg {
         let arg_ident = common::get_arg_ident(self);
 
         Ok(quote! {
-            let parent = ctx.parent_value.try_downcast_ref::<<Self as #crate_name::internal::ParentType>::Type>()?.into();
+            let parent = #crate_name::internal::downcast_parent::<<Self as #crate_name::internal::ParentType>::Type>(ctx.parent_value)?.into();
             let #arg_ident = parent;
         })
     }
@@ -291,9 +387,11 @@ impl FieldImplementor for ResolvedObjectFieldsMethod {
     }
 
     fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
-        Ok(quote! {
-            let object = object.field(field);
-        })
+        let crate_name = get_crate_name();
+        common::get_object_field_usage_code(
+            self,
+            quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+        )
     }
 }
 
@@ -323,7 +421,26 @@ fn impl_register(object: &ResolvedObjectFields) -> darling::Result<TokenStream>
     let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
     let define_object = common::impl_define_object();
     let define_fields = common::get_define_fields_code(object)?;
+    let register_complexity = common::get_complexity_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+    let register_fields_visible = common::get_fields_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+        common::get_field_name,
+    )?;
+    let register_args_visible = common::get_args_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+    let declare_loaders = common::get_loaders_declare_code(object)?;
+    let declare_fields_types = common::get_fields_types_declare_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
     let register_object_code = common::register_object_code();
+    let register_entities = common::get_entity_register_code(object)?;
     let register_fns = common::call_register_fns();
     let (impl_generics, _, where_clause) = object.get_generics()?.split_for_impl();
 
@@ -337,6 +454,18 @@ fn impl_register(object: &ResolvedObjectFields) -> darling::Result<TokenStream>
 
                 #define_fields
 
+                #register_complexity
+
+                #register_fields_visible
+
+                #register_args_visible
+
+                #declare_loaders
+
+                #declare_fields_types
+
+                #register_entities
+
                 #register_fns
 
                 #register_object_code
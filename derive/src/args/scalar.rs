@@ -17,6 +17,8 @@ use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
 use crate::utils::path_attr::PathAttr;
 use crate::utils::register_attr::RegisterAttr;
+use crate::utils::tags::TagsAttr;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_doc::WithDoc;
 
@@ -39,6 +41,17 @@ pub struct ScalarAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "register")]
     pub registers: Vec<RegisterAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 from_derive_input!(
@@ -70,6 +83,16 @@ impl CommonObject for Scalar {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.doc.clone())
     }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 fn impl_scalar(scalar: &Scalar) -> darling::Result<TokenStream> {
@@ -108,6 +131,12 @@ fn impl_resolved_own(scalar: &Scalar) -> darling::Result<TokenStream> {
                 Ok(Some(#crate_name::FieldValue::value(value)))
             }
         }
+        impl #impl_generics #crate_name::internal::ResolveShared<#lifetime> for #object_ident #ty_generics #where_clause {
+            fn resolve_shared(shared: #crate_name::internal::Shared<Self>, _ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<#lifetime>>> {
+                let value = #crate_name::ScalarValue::to_value(shared.get());
+                Ok(Some(#crate_name::FieldValue::value(value)))
+            }
+        }
     })
 }
 
@@ -128,13 +157,32 @@ pub fn impl_resolve_ref(scalar: &impl CommonObject) -> darling::Result<TokenStre
     })
 }
 
-fn impl_from_value(scalar: &impl CommonObject) -> darling::Result<TokenStream> {
+fn impl_to_value(scalar: &impl CommonObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = scalar.get_ident();
+    Ok(quote!(
+        impl #crate_name::internal::ToValue for #ident {
+            fn to_value(&self) -> #crate_name::Value {
+                #crate_name::ScalarValue::to_value(self)
+            }
+        }
+    ))
+}
+
+fn impl_from_value(scalar: &Scalar) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = scalar.get_ident();
+    let validate = scalar.attrs.validator.as_ref().map(|validator| {
+        let path = &validator.0;
+        quote! {
+            #crate_name::internal::validate_scalar(&value, #path)?;
+        }
+    });
     Ok(quote!(
         impl #crate_name::internal::FromValue for #ident {
             fn from_value(value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
                 let value = value?.as_value().clone();
+                #validate
                 Ok(#crate_name::ScalarValue::from_value(value)?)
             }
         }
@@ -152,12 +200,13 @@ pub fn get_specified_by_url_code(scalar: &Scalar) -> darling::Result<TokenStream
 }
 
 fn get_validator_code(scalar: &Scalar) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
     let validator = &scalar.attrs.validator;
     Ok(match validator {
         Some(validator) => {
             let path = &validator.0;
             quote! {
-                let object = object.validator(#path);
+                let object = #crate_name::internal::register_scalar_validator(object, #path);
             }
         }
         None => quote!(),
@@ -169,8 +218,17 @@ fn impl_register(scalar: &Scalar) -> darling::Result<TokenStream> {
 
     let ident = &scalar.get_ident();
     let description = common::object_description(scalar.get_doc()?.as_deref())?;
+    let directives = common::object_directives(scalar);
     let specified_by_url = get_specified_by_url_code(scalar)?;
     let validator_code = get_validator_code(scalar)?;
+    let register_visible = common::get_type_visible_register_code(
+        scalar,
+        quote!(<Self as #crate_name::internal::Scalar>::get_scalar_type_name()),
+    );
+    let register_tags = common::get_type_tags_register_code(
+        scalar,
+        quote!(<Self as #crate_name::internal::Scalar>::get_scalar_type_name()),
+    );
 
     let (impl_generics, ty_generics, where_clause) = scalar.generics.split_for_impl();
     let register_attr = &scalar.attrs.registers;
@@ -181,7 +239,10 @@ fn impl_register(scalar: &Scalar) -> darling::Result<TokenStream> {
                 let object = #crate_name::dynamic::Scalar::new(<Self as #crate_name::internal::Scalar>::get_scalar_type_name().as_ref());
                 #validator_code
                 #description
+                #directives
                 #specified_by_url
+                #register_visible
+                #register_tags
                 registry.register_type(object)
             }
         }
@@ -193,12 +254,14 @@ impl ToTokens for Scalar {
         let impl_scalar = impl_scalar(self).into_token_stream();
         let impl_resolved_own = impl_resolved_own(self).into_token_stream();
         let impl_resolve_ref = impl_resolve_ref(self).into_token_stream();
+        let impl_to_value = impl_to_value(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         tokens.extend(quote! {
             #impl_scalar
             #impl_resolved_own
             #impl_resolve_ref
+            #impl_to_value
             #impl_from_value
             #impl_register
         })
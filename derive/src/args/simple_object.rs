@@ -15,16 +15,22 @@ use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::common::EMPTY_ARGS;
+use crate::utils::complexity::ComplexityAttr;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::derive_types::BaseStruct;
 use crate::utils::derive_types::NamedField;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::federation::FieldFederation;
+use crate::utils::federation::ObjectFederation;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
 use crate::utils::macros::*;
+use crate::utils::node_attr::NodeAttr;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
@@ -41,6 +47,26 @@ pub struct SimpleObjectFieldAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub guard: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub complexity: Option<ComplexityAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
+
+    #[darling(flatten)]
+    pub federation: FieldFederation,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -57,7 +83,7 @@ from_field!(
 );
 
 #[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
+#[darling(attributes(graphql), and_then = Self::add_node_impl)]
 pub struct SimpleObjectAttrs {
     #[darling(default)]
     pub root: bool,
@@ -86,6 +112,32 @@ pub struct SimpleObjectAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "implements")]
     pub impls: Vec<InterfaceImplAttr>,
+
+    #[darling(default)]
+    pub node: Option<NodeAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
+
+    #[darling(flatten)]
+    pub federation: ObjectFederation,
+}
+
+impl SimpleObjectAttrs {
+    fn add_node_impl(mut self) -> darling::Result<Self> {
+        if let Some(node) = &self.node {
+            self.impls.push(node.get_interface_impl());
+        }
+        Ok(self)
+    }
 }
 
 from_derive_input!(
@@ -139,6 +191,15 @@ impl CommonObject for SimpleObject {
     fn get_fields_rename_rule(&self) -> Option<&RenameRule> {
         self.attrs.rename_fields.as_ref()
     }
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl CommonField for SimpleObjectField {
@@ -164,6 +225,27 @@ impl CommonField for SimpleObjectField {
     fn get_deprecation(&self) -> darling::Result<Deprecation> {
         Ok(self.attrs.deprecation.clone())
     }
+
+    fn get_guard(&self) -> Option<&syn::Expr> {
+        self.attrs.guard.as_ref()
+    }
+
+    fn get_complexity(&self) -> Option<&ComplexityAttr> {
+        self.attrs.complexity.as_ref()
+    }
+
+    fn get_federation(&self) -> Option<&FieldFederation> {
+        Some(&self.attrs.federation)
+    }
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
@@ -176,9 +258,10 @@ impl FieldImplementor for SimpleObjectField {
 
     fn get_execute_code(&self) -> darling::Result<TokenStream> {
         let resolver_ident = get_resolver_ident(self)?;
+        let crate_name = get_crate_name();
 
         Ok(quote! {
-            let parent = ctx.parent_value.try_downcast_ref::<Self>()?;
+            let parent = #crate_name::internal::downcast_parent::<Self>(ctx.parent_value)?;
             let value = Self::#resolver_ident(parent);
         })
     }
@@ -200,9 +283,11 @@ impl FieldImplementor for SimpleObjectField {
     }
 
     fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
-        Ok(quote! {
-            let object = object.field(field);
-        })
+        let crate_name = get_crate_name();
+        common::get_object_field_usage_code(
+            self,
+            quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+        )
     }
 }
 
@@ -291,7 +376,31 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
     let implement = common::get_add_implement_code(object, object.get_impls())?;
 
     let description = common::object_description(object.get_doc()?.as_deref())?;
+    let directives = common::object_directives(object);
+    let federation = &object.attrs.federation;
     let define_fields = common::get_define_fields_code(object)?;
+    let register_complexity = common::get_complexity_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+    let register_visible = common::get_type_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    );
+    let register_fields_visible = common::get_fields_visible_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+        common::get_field_name,
+    )?;
+    let register_tags = common::get_type_tags_register_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    );
+    let declare_fields_types = common::get_fields_types_declare_code(
+        object,
+        quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+    let register_node = common::get_register_node_code(object.attrs.node.as_ref());
     let register_object_code = common::register_object_code();
 
     let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
@@ -316,9 +425,24 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
                 #add_interfaces
 
                 #description
+                #directives
+
+                #federation
 
                 #define_fields
 
+                #register_complexity
+
+                #register_visible
+
+                #register_fields_visible
+
+                #register_tags
+
+                #declare_fields_types
+
+                #register_node
+
                 #register_object_code
             }
         }
@@ -333,6 +457,8 @@ impl ToTokens for SimpleObject {
         let impl_resolvers = impl_resolvers(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         let impl_interface_mark = common::impl_interface_mark(self).into_token_stream();
+        let impl_fetch_node =
+            common::impl_fetch_node(self, self.attrs.node.as_ref()).into_token_stream();
 
         tokens.extend(quote! {
             #impl_object
@@ -341,6 +467,7 @@ impl ToTokens for SimpleObject {
             #impl_resolve_ref
             #impl_resolvers
             #impl_register
+            #impl_fetch_node
         })
     }
 }
@@ -0,0 +1,452 @@
+use darling::FromAttributes;
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+use syn::Generics;
+
+use crate::args::common;
+use crate::args::common::ArgImplementor;
+use crate::args::common::FieldImplementor;
+use crate::utils::attributes::Attributes;
+use crate::utils::common::CommonArg;
+use crate::utils::common::CommonField;
+use crate::utils::common::CommonMethod;
+use crate::utils::common::GetArgs;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
+use crate::utils::deprecation::Deprecation;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::impl_block::BaseFnArg;
+use crate::utils::impl_block::BaseItemImpl;
+use crate::utils::impl_block::BaseMethod;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::type_utils::get_result_ok_type;
+use crate::utils::type_utils::get_stream_item_type;
+use crate::utils::type_utils::get_type_path;
+use crate::utils::type_utils::remove_path_generics;
+use crate::utils::validators::Validators;
+use crate::utils::visible::VisibleAttr;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::WithContext;
+use crate::utils::with_doc::WithDoc;
+use crate::utils::with_index::WithIndex;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct SubscriptionFieldsArgAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub ctx: bool,
+    #[darling(default)]
+    pub default: Option<DefaultValue>,
+
+    #[darling(default)]
+    pub default_with: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub validator: Option<Validators>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+}
+
+impl Attributes for SubscriptionFieldsArgAttrs {
+    const ATTRIBUTES: &'static [&'static str] = &["graphql"];
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct SubscriptionFieldsArgContext {
+    pub rename_args: Option<RenameRule>,
+}
+
+from_fn_arg!(SubscriptionFieldsArg,
+    WithAttributes<
+        SubscriptionFieldsArgAttrs,
+        WithIndex<WithContext<SubscriptionFieldsArgContext, BaseFnArg>>,
+    >,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct SubscriptionFieldsMethodAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub rename_args: Option<RenameRule>,
+
+    #[darling(default)]
+    pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub guard: Option<syn::Expr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+}
+
+impl Attributes for SubscriptionFieldsMethodAttrs {
+    const ATTRIBUTES: &'static [&'static str] = &["graphql"];
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct SubscriptionFieldsMethodContext {
+    pub rename_args: Option<RenameRule>,
+    pub rename_fields: Option<RenameRule>,
+    pub subscription_ty: Option<syn::Type>,
+}
+
+from_impl_item_method!(
+    SubscriptionFieldsMethod,
+    WithAttributes<
+        WithDoc<SubscriptionFieldsMethodAttrs>,
+        WithIndex<WithContext<SubscriptionFieldsMethodContext, BaseMethod<SubscriptionFieldsArg>>>,
+    >,
+    inner = args,
+);
+
+impl MakeContext<SubscriptionFieldsArgContext> for SubscriptionFieldsMethod {
+    fn make_context(&self) -> SubscriptionFieldsArgContext {
+        SubscriptionFieldsArgContext {
+            rename_args: self.attrs.rename_args.or(self.ctx.rename_args),
+        }
+    }
+}
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct SubscriptionFieldsAttrs {
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+
+    #[darling(default)]
+    pub rename_args: Option<RenameRule>,
+}
+
+impl Attributes for SubscriptionFieldsAttrs {
+    const ATTRIBUTES: &'static [&'static str] = &["graphql"];
+}
+
+from_item_impl!(
+    SubscriptionFields,
+    WithAttributes<
+        WithDoc<SubscriptionFieldsAttrs>,
+        BaseItemImpl<SubscriptionFieldsMethod, Generics>,
+    >,
+    ctx,
+);
+
+impl MakeContext<SubscriptionFieldsMethodContext> for SubscriptionFields {
+    fn make_context(&self) -> SubscriptionFieldsMethodContext {
+        SubscriptionFieldsMethodContext {
+            rename_args: self.attrs.rename_args,
+            rename_fields: self.attrs.rename_fields,
+            subscription_ty: Some(self.ty.clone()),
+        }
+    }
+}
+
+impl CommonField for SubscriptionFieldsMethod {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_ident(&self) -> darling::Result<&Ident> {
+        Ok(&self.ident)
+    }
+
+    /// the type of the stream items
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        let output_type = self.output_type.as_ref().ok_or_else(|| {
+            darling::Error::custom("Field must have return type").with_span(&self.ident)
+        })?;
+        get_stream_item_type(output_type).ok_or_else(|| {
+            darling::Error::custom("Subscription field must return `impl Stream<Item = T>`")
+                .with_span(output_type)
+        })
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
+    fn get_guard(&self) -> Option<&syn::Expr> {
+        self.attrs.guard.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_fields.as_ref()
+    }
+    fn get_args_rename_rule(&self) -> Option<&RenameRule> {
+        self.attrs
+            .rename_args
+            .as_ref()
+            .or(self.ctx.rename_args.as_ref())
+    }
+}
+
+impl CommonArg for SubscriptionFieldsArg {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_index(&self) -> usize {
+        self.index
+    }
+
+    fn get_arg(&self) -> &BaseFnArg {
+        self
+    }
+
+    fn get_arg_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_args.as_ref()
+    }
+
+    fn is_marked_as_ctx(&self) -> bool {
+        self.attrs.ctx
+    }
+
+    fn get_default_value(&self) -> darling::Result<Option<DefaultValue>> {
+        DefaultValue::from_attrs(
+            self.attrs.default.as_ref(),
+            self.attrs.default_with.as_ref(),
+        )
+    }
+
+    fn get_validators(&self) -> Option<&Validators> {
+        self.attrs.validator.as_ref()
+    }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+}
+
+impl CommonMethod for SubscriptionFieldsMethod {
+    fn is_async(&self) -> bool {
+        self.asyncness
+    }
+}
+
+impl GetArgs<SubscriptionFieldsArg> for SubscriptionFieldsMethod {
+    fn get_args(&self) -> darling::Result<&Vec<SubscriptionFieldsArg>> {
+        Ok(&self.args)
+    }
+}
+
+impl GetFields<SubscriptionFieldsMethod> for SubscriptionFields {
+    fn get_fields(&self) -> darling::Result<&Vec<SubscriptionFieldsMethod>> {
+        Ok(&self.methods)
+    }
+}
+
+impl ArgImplementor for SubscriptionFieldsArg {
+    fn get_self_arg_definition(&self) -> darling::Result<TokenStream> {
+        Err(
+            darling::Error::custom("Subscription methods must not have a self argument")
+                .with_span(&self.get_arg().span()),
+        )
+    }
+
+    fn get_typed_arg_definition(&self) -> darling::Result<TokenStream> {
+        if common::is_arg_ctx(self) {
+            // the stream may borrow the context, so pass the one that outlives the field future
+            let arg_ident = common::get_arg_ident(self);
+            return Ok(quote! {
+                let #arg_ident = ctx.ctx;
+            });
+        }
+        common::get_typed_arg_definition(self)
+    }
+
+    fn get_self_arg_usage(&self) -> darling::Result<TokenStream> {
+        common::get_self_arg_usage(self)
+    }
+
+    fn get_typed_arg_usage(&self) -> darling::Result<TokenStream> {
+        common::get_typed_arg_usage(self)
+    }
+}
+
+impl FieldImplementor for SubscriptionFieldsMethod {
+    fn define_field(&self) -> darling::Result<TokenStream> {
+        let crate_name = get_crate_name();
+
+        let field_name = common::get_field_name(self)?;
+        let field_type = common::get_field_type(self)?;
+        let guard = common::get_guard_code(self);
+        let graphql_args_definition = common::get_args_definition(self)?;
+        let execute = self.get_execute_code()?;
+        let resolve = self.get_resolve_code()?;
+        Ok(quote! {
+            let field = #crate_name::dynamic::SubscriptionField::new(#field_name, <#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref(), |ctx| {
+                #crate_name::dynamic::SubscriptionFieldFuture::new(async move {
+                    #guard
+                    #graphql_args_definition
+                    #execute
+                    #resolve
+                })
+            });
+        })
+    }
+
+    fn get_execute_code(&self) -> darling::Result<TokenStream> {
+        let ty = self.ctx.subscription_ty.as_ref().unwrap_or_else(|| {
+            unreachable!("SubscriptionFieldsMethodContext::subscription_ty must be set")
+        });
+        let type_path = remove_path_generics(get_type_path(ty)?);
+        let field_ident = &self.ident;
+        let args = common::get_args_usage(self)?;
+
+        if self.is_async() {
+            Ok(quote! {
+                let value = #type_path::#field_ident(#args).await;
+            })
+        } else {
+            Ok(quote! {
+                let value = #type_path::#field_ident(#args);
+            })
+        }
+    }
+
+    fn get_resolve_code(&self) -> darling::Result<TokenStream> {
+        let crate_name = get_crate_name();
+        let unwrap_result = self
+            .output_type
+            .as_ref()
+            .and_then(get_result_ok_type)
+            .map(|_| quote!(let value = value?;));
+
+        Ok(quote! {
+            #unwrap_result
+            Ok(#crate_name::internal::resolve_stream(value, ctx.ctx))
+        })
+    }
+
+    fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
+        common::get_argument_definitions(self.get_args()?)
+    }
+
+    fn get_field_description_code(&self) -> darling::Result<TokenStream> {
+        common::field_description(self)
+    }
+
+    fn get_field_deprecation_code(&self) -> darling::Result<TokenStream> {
+        common::field_deprecation_code(self)
+    }
+
+    fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
+        let field_var_ident = get_field_var_ident(self.index, &self.ident);
+
+        Ok(quote! {
+            let #field_var_ident = field;
+        })
+    }
+}
+
+fn get_field_var_ident(index: usize, ident: &syn::Ident) -> Ident {
+    Ident::new(&format!("__field_{}", index), ident.span())
+}
+
+fn use_fields_code(subscription: &SubscriptionFields) -> darling::Result<TokenStream> {
+    Ok(subscription
+        .get_fields()?
+        .iter()
+        .filter(|method| !method.get_skip())
+        .map(|method| {
+            let field_var_ident = get_field_var_ident(method.index, &method.ident);
+            quote! {
+                let object = object.field(#field_var_ident);
+            }
+        })
+        .collect())
+}
+
+fn impl_register(subscription: &SubscriptionFields) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let (impl_generics, _, where_clause) = subscription.generics.split_for_impl();
+    let ty = get_type_path(&subscription.ty)?;
+
+    let register_nested_types =
+        common::get_nested_type_register_code(subscription).into_token_stream();
+
+    let define_fields = common::get_define_fields_code(subscription).into_token_stream();
+
+    let register_fields_visible = common::get_fields_visible_register_code(
+        subscription,
+        quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+        common::get_field_name,
+    )?;
+
+    let register_args_visible = common::get_args_visible_register_code(
+        subscription,
+        quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+    let declare_loaders = common::get_loaders_declare_code(subscription)?;
+
+    let declare_fields_types = common::get_fields_types_declare_code(
+        subscription,
+        quote!(<<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name()),
+    )?;
+
+    let use_fields = use_fields_code(subscription).into_token_stream();
+
+    let register_fns = common::call_register_fns();
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::Register for #ty #where_clause {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+
+                #register_nested_types
+
+                #register_fns
+
+                #define_fields
+
+                #register_fields_visible
+
+                #register_args_visible
+
+                #declare_loaders
+
+                #declare_fields_types
+
+                registry.update_subscription(
+                    <<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                    <Self as #crate_name::internal::ExpandSubscription>::get_expand_subscription_name().as_ref(),
+                    |object| {
+                        #use_fields
+                        object
+                    },
+                )
+            }
+        }
+    })
+}
+
+impl ToTokens for SubscriptionFields {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_register = impl_register(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_register
+        });
+    }
+}
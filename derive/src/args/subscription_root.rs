@@ -0,0 +1,94 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+
+use crate::args::common;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::UnitStruct;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::register_attr::RegisterAttr;
+use crate::utils::rename_rule::calc_type_name;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_doc::WithDoc;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct SubscriptionRootAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    #[darling(rename = "get_type_name")]
+    pub type_name: bool,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "register")]
+    pub registers: Vec<RegisterAttr>,
+}
+
+from_derive_input!(
+    SubscriptionRoot,
+    WithAttributes<WithDoc<SubscriptionRootAttrs>, UnitStruct>,
+    ctx,
+);
+
+fn impl_object(object: &SubscriptionRoot) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let name = calc_type_name(object.attrs.name.as_deref(), &ident.to_string());
+
+    let type_name = (!object.attrs.type_name).then_some(quote! {
+        impl #crate_name::internal::TypeName for #ident {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                #name.into()
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #crate_name::internal::ParentType for #ident {
+            type Type = #ident;
+        }
+        #type_name
+        impl #crate_name::internal::OutputTypeName for #ident {}
+        impl #crate_name::internal::Object for #ident {}
+    })
+}
+
+fn impl_register(object: &SubscriptionRoot) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let register_attr = &object.attrs.registers;
+    let description = common::object_description(object.attrs.doc.as_deref())?;
+    let register_object_code = common::register_object_code();
+
+    Ok(quote! {
+        impl #crate_name::internal::Register for #ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+
+                #( #register_attr )*
+
+                let registry = registry.set_subscription(<Self as #crate_name::internal::Object>::get_object_type_name().as_ref());
+
+                let object = #crate_name::dynamic::Subscription::new(<Self as #crate_name::internal::Object>::get_object_type_name().as_ref());
+
+                #description
+
+                #register_object_code
+            }
+        }
+    })
+}
+
+impl ToTokens for SubscriptionRoot {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_object = impl_object(self).into_token_stream();
+        let impl_register = impl_register(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_object
+            #impl_register
+        })
+    }
+}
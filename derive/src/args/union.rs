@@ -20,8 +20,10 @@ use crate::utils::derive_types::NewtypeVariant;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
 use crate::utils::register_attr::RegisterAttr;
+use crate::utils::tags::TagsAttr;
 use crate::utils::type_utils::get_owned_type;
 use crate::utils::type_utils::get_type_path;
+use crate::utils::visible::VisibleAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_doc::WithDoc;
 
@@ -40,6 +42,17 @@ pub struct UnionAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "register")]
     pub registers: Vec<RegisterAttr>,
+
+    #[darling(default)]
+    pub visible: Option<VisibleAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "tag")]
+    pub tags: Vec<TagsAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "directive")]
+    pub directives: Vec<syn::Expr>,
 }
 
 from_derive_input!(
@@ -71,6 +84,16 @@ impl CommonObject for Union {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.doc.clone())
     }
+
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        self.attrs.visible.as_ref()
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &self.attrs.tags
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &self.attrs.directives
+    }
 }
 
 impl GetFields<UnionItem> for Union {
@@ -214,6 +237,10 @@ fn define_item(item: &UnionItem) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ty = get_owned_type(&item.fields.ty);
     Ok(quote! {
+        let registry = registry.declare_possible_type(
+            <Self as #crate_name::internal::Union>::get_union_type_name().as_ref(),
+            <#ty as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+        );
         let object = object.possible_type(<#ty as #crate_name::internal::Object>::get_object_type_name().as_ref());
     })
 }
@@ -239,7 +266,16 @@ fn impl_register(union: &Union) -> darling::Result<TokenStream> {
         .get_doc()
         .and_then(|doc| common::object_description(doc.as_deref()))
         .into_token_stream();
+    let directives = common::object_directives(union);
     let define_items = define_items(union).into_token_stream();
+    let register_visible = common::get_type_visible_register_code(
+        union,
+        quote!(<Self as #crate_name::internal::Union>::get_union_type_name()),
+    );
+    let register_tags = common::get_type_tags_register_code(
+        union,
+        quote!(<Self as #crate_name::internal::Union>::get_union_type_name()),
+    );
     let register_union = common::register_object_code().into_token_stream();
     let register_attr = &union.attrs.registers;
     let (impl_generics, ty_generics, where_clause) = union.generics.split_for_impl();
@@ -255,9 +291,14 @@ fn impl_register(union: &Union) -> darling::Result<TokenStream> {
                 #define_union
 
                 #description
+                #directives
 
                 #define_items
 
+                #register_visible
+
+                #register_tags
+
                 #register_union
             }
         }
@@ -58,7 +58,22 @@ pub fn ResolvedObjectFields(
 
 #[proc_macro_derive(InputObject, attributes(graphql))]
 pub fn drive_input_object(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    match args::InputObject::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+    let input = parse_macro_input!(input as DeriveInput);
+    if let syn::Data::Enum(_) = input.data {
+        return match args::OneOfInputObject::from_derive_input(&input) {
+            Ok(object_args) => object_args.into_token_stream().into(),
+            Err(err) => err.write_errors().into(),
+        };
+    }
+    match args::InputObject::from_derive_input(&input) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(GraphQLError, attributes(graphql))]
+pub fn drive_graphql_error(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::GraphQLError::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
         Ok(object_args) => object_args.into_token_stream().into(),
         Err(err) => err.write_errors().into(),
     }
@@ -168,3 +183,38 @@ pub fn MutationFields(
     })
     .into()
 }
+
+#[proc_macro_derive(SubscriptionRoot, attributes(graphql))]
+pub fn drive_subscription_root(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::SubscriptionRoot::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(Subscription, attributes(graphql))]
+pub fn drive_subscription(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::Subscription::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn SubscriptionFields(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut item = parse_macro_input!(item as syn::ItemImpl);
+    let data = args::SubscriptionFields::from_item_impl(&mut item);
+    let extension = match data {
+        Ok(obj) => obj.into_token_stream(),
+        Err(err) => err.write_errors(),
+    };
+    (quote! {
+        #item
+        #extension
+    })
+    .into()
+}
@@ -1,8 +1,14 @@
+use crate::utils::complexity::ComplexityAttr;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
+use crate::utils::federation::FieldFederation;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::tags::TagsAttr;
+use crate::utils::validators::Validators;
+use crate::utils::visible::VisibleAttr;
 
 pub trait CommonObject {
     /// user defined name
@@ -18,6 +24,15 @@ pub trait CommonObject {
     fn get_args_rename_rule(&self) -> Option<&RenameRule> {
         None
     }
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        None
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &[]
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &[]
+    }
 }
 
 pub trait CommonField {
@@ -36,6 +51,34 @@ pub trait CommonField {
     fn get_args_rename_rule(&self) -> Option<&RenameRule> {
         None
     }
+    fn get_default_value(&self) -> darling::Result<Option<DefaultValue>> {
+        Ok(None)
+    }
+    fn get_guard(&self) -> Option<&syn::Expr> {
+        None
+    }
+    fn get_complexity(&self) -> Option<&ComplexityAttr> {
+        None
+    }
+    fn get_validators(&self) -> Option<&Validators> {
+        None
+    }
+    fn get_federation(&self) -> Option<&FieldFederation> {
+        None
+    }
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        None
+    }
+    fn get_tags(&self) -> &[TagsAttr] {
+        &[]
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &[]
+    }
+    /// the method resolves entities of its return type instead of defining a field
+    fn is_entity(&self) -> bool {
+        false
+    }
 }
 
 pub trait CommonMethod: CommonField {
@@ -56,6 +99,21 @@ pub trait CommonArg {
         None
     }
     fn is_marked_as_ctx(&self) -> bool;
+    fn is_marked_as_loader(&self) -> bool {
+        false
+    }
+    fn get_default_value(&self) -> darling::Result<Option<DefaultValue>> {
+        Ok(None)
+    }
+    fn get_validators(&self) -> Option<&Validators> {
+        None
+    }
+    fn get_visible(&self) -> Option<&VisibleAttr> {
+        None
+    }
+    fn get_directives(&self) -> &[syn::Expr] {
+        &[]
+    }
 }
 
 pub trait GetFields<F> {
@@ -0,0 +1,38 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+
+use crate::utils::crate_name::get_crate_name;
+
+/// `#[graphql(complexity = 5)]` or `#[graphql(complexity = "fn_or_closure")]`
+#[derive(Debug, Clone)]
+pub enum ComplexityAttr {
+    Const(syn::LitInt),
+    /// `Fn(child_complexity: usize, args: &FieldArgs) -> usize`
+    Fn(syn::Expr),
+}
+
+impl FromMeta for ComplexityAttr {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        match value {
+            syn::Lit::Int(lit) => Ok(ComplexityAttr::Const(lit.clone())),
+            syn::Lit::Str(lit) => Ok(ComplexityAttr::Fn(lit.parse()?)),
+            _ => Err(darling::Error::unexpected_lit_type(value)),
+        }
+    }
+}
+
+impl ToTokens for ComplexityAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let crate_name = get_crate_name();
+        tokens.extend(match self {
+            ComplexityAttr::Const(lit) => {
+                quote!(#crate_name::internal::Complexity::Const(#lit))
+            }
+            ComplexityAttr::Fn(expr) => {
+                quote!(#crate_name::internal::Complexity::with_fn(#expr))
+            }
+        })
+    }
+}
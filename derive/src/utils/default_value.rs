@@ -0,0 +1,50 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+
+#[derive(Debug, Clone)]
+pub enum DefaultValue {
+    /// `#[graphql(default)]`
+    Default,
+    /// `#[graphql(default = <literal>)]`
+    Value(syn::Lit),
+    /// `#[graphql(default_with = "expr")]`
+    With(syn::Expr),
+}
+
+impl darling::FromMeta for DefaultValue {
+    fn from_word() -> darling::Result<Self> {
+        Ok(DefaultValue::Default)
+    }
+
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        Ok(DefaultValue::Value(value.clone()))
+    }
+}
+
+impl DefaultValue {
+    pub fn from_attrs(
+        default: Option<&DefaultValue>,
+        default_with: Option<&syn::Expr>,
+    ) -> darling::Result<Option<DefaultValue>> {
+        match (default, default_with) {
+            (Some(_), Some(expr)) => Err(darling::Error::custom(
+                "`default` and `default_with` can't be used together",
+            )
+            .with_span(expr)),
+            (Some(default), None) => Ok(Some(default.clone())),
+            (None, Some(expr)) => Ok(Some(DefaultValue::With(expr.clone()))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+impl ToTokens for DefaultValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            DefaultValue::Default => quote!(::std::default::Default::default()),
+            DefaultValue::Value(lit) => quote!(::std::convert::Into::into(#lit)),
+            DefaultValue::With(expr) => quote!(#expr),
+        })
+    }
+}
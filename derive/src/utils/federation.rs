@@ -0,0 +1,61 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+
+/// federation directives of an object: `#[graphql(key = "id", shareable)]`
+#[derive(FromMeta, Debug, Clone, Default)]
+pub struct ObjectFederation {
+    #[darling(default, multiple)]
+    #[darling(rename = "key")]
+    pub keys: Vec<String>,
+
+    #[darling(default)]
+    pub shareable: bool,
+}
+
+impl ToTokens for ObjectFederation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let keys = &self.keys;
+        tokens.extend(quote! {
+            #( let object = object.key(#keys); )*
+        });
+        if self.shareable {
+            tokens.extend(quote!(let object = object.shareable();));
+        }
+    }
+}
+
+/// federation directives of a field:
+/// `#[graphql(shareable, external, requires = "fields", provides = "fields")]`
+#[derive(FromMeta, Debug, Clone, Default)]
+pub struct FieldFederation {
+    #[darling(default)]
+    pub shareable: bool,
+
+    #[darling(default)]
+    pub external: bool,
+
+    #[darling(default)]
+    pub requires: Option<String>,
+
+    #[darling(default)]
+    pub provides: Option<String>,
+}
+
+impl ToTokens for FieldFederation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.shareable {
+            tokens.extend(quote!(let field = field.shareable();));
+        }
+        if self.external {
+            tokens.extend(quote!(let field = field.external();));
+        }
+        if let Some(requires) = &self.requires {
+            tokens.extend(quote!(let field = field.requires(#requires);));
+        }
+        if let Some(provides) = &self.provides {
+            tokens.extend(quote!(let field = field.provides(#provides);));
+        }
+    }
+}
@@ -1,18 +1,25 @@
 pub mod attributes;
 pub mod common;
+pub mod complexity;
 pub mod crate_name;
+pub mod default_value;
 pub mod deprecation;
 pub mod derive_types;
 pub mod docs_utils;
 pub mod error;
+pub mod federation;
 pub mod impl_block;
 pub mod interface_attr;
 pub mod macros;
 pub mod meta_match;
+pub mod node_attr;
 pub mod path_attr;
 pub mod register_attr;
 pub mod rename_rule;
+pub mod tags;
 pub mod type_utils;
+pub mod validators;
+pub mod visible;
 pub mod with_arg;
 pub mod with_attributes;
 pub mod with_context;
@@ -0,0 +1,26 @@
+use darling::FromMeta;
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::interface_attr::InterfaceImplAttr;
+
+/// `#[graphql(node(fetch = "path"))]`
+///
+/// `path` is `async fn(&Context<'_>, String) -> Result<Option<Self>>`
+#[derive(FromMeta, Debug, Clone)]
+pub struct NodeAttr {
+    pub fetch: syn::Path,
+}
+
+impl NodeAttr {
+    /// node objects implement the `Node` interface
+    pub fn get_interface_impl(&self) -> InterfaceImplAttr {
+        let crate_name = get_crate_name();
+        let span: Span = self.fetch.span();
+        InterfaceImplAttr {
+            path: syn::parse_quote!(#crate_name::relay::Node),
+            span,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `#[graphql(tag("admin", "internal"))]` or `#[graphql(tag = "admin")]`
+#[derive(Debug, Clone, Default)]
+pub struct TagsAttr(pub Vec<String>);
+
+impl FromMeta for TagsAttr {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(TagsAttr(vec![value.to_string()]))
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Lit(syn::Lit::Str(tag)) => Ok(tag.value()),
+                item => Err(darling::Error::custom("expected a string literal").with_span(item)),
+            })
+            .collect::<darling::Result<_>>()
+            .map(TagsAttr)
+    }
+}
+
+/// the tags of all the `tag` attributes, as a `&[&str]` expression
+pub fn get_tags_slice(tags: &[TagsAttr]) -> Option<TokenStream> {
+    let tags: Vec<&String> = tags.iter().flat_map(|tags| &tags.0).collect();
+    if tags.is_empty() {
+        return None;
+    }
+    Some(quote!(&[#(#tags),*]))
+}
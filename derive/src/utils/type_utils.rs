@@ -82,3 +82,56 @@ pub fn get_value_type(ty: &syn::Type) -> Option<TokenStream> {
     }
     None
 }
+
+/// get `T` from `Result<T, E>`
+pub fn get_result_ok_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(ref args) = segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+/// get `T` from `impl Stream<Item = T>` or `Result<impl Stream<Item = T>, E>`
+pub fn get_stream_item_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::ImplTrait(impl_trait) = ty else {
+        return get_result_ok_type(ty).and_then(get_stream_item_type);
+    };
+    impl_trait.bounds.iter().find_map(|bound| {
+        let syn::TypeParamBound::Trait(bound) = bound else {
+            return None;
+        };
+        let segment = bound.path.segments.last()?;
+        if segment.ident != "Stream" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(ref args) = segment.arguments else {
+            return None;
+        };
+        args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::AssocType(assoc) if assoc.ident == "Item" => Some(&assoc.ty),
+            _ => None,
+        })
+    })
+}
+
+/// check if the last segment of the type path is `name`, e.g. `Option` for `std::option::Option<T>`
+pub fn is_type_named(ty: &syn::Type, name: &str) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
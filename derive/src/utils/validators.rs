@@ -0,0 +1,100 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::type_utils::get_owned_type;
+use crate::utils::type_utils::is_type_named;
+
+/// `#[graphql(validator(min_length = 1, max_length = 10, min = 0, max = 100, regex = "...", custom = "path"))]`
+#[derive(FromMeta, Default, Debug, Clone)]
+#[darling(and_then = Self::check_regex)]
+pub struct Validators {
+    #[darling(default)]
+    pub min_length: Option<usize>,
+    #[darling(default)]
+    pub max_length: Option<usize>,
+    #[darling(default)]
+    pub min: Option<syn::Expr>,
+    #[darling(default)]
+    pub max: Option<syn::Expr>,
+    #[darling(default)]
+    pub regex: Option<syn::LitStr>,
+    #[darling(default)]
+    pub custom: Option<syn::Path>,
+}
+
+impl Validators {
+    /// an invalid pattern fails the build instead of the first request that uses it
+    fn check_regex(self) -> darling::Result<Self> {
+        if let Some(regex) = &self.regex {
+            if let Err(err) = regex::Regex::new(&regex.value()) {
+                return Err(
+                    darling::Error::custom(format!("invalid regex pattern: {}", err))
+                        .with_span(regex),
+                );
+            }
+        }
+        Ok(self)
+    }
+
+    fn get_checks(&self) -> Vec<TokenStream> {
+        let crate_name = get_crate_name();
+        let mut checks = vec![];
+        if let Some(min_length) = self.min_length {
+            checks.push(quote!(#crate_name::validators::min_length(value, #min_length)));
+        }
+        if let Some(max_length) = self.max_length {
+            checks.push(quote!(#crate_name::validators::max_length(value, #max_length)));
+        }
+        if let Some(min) = &self.min {
+            checks.push(quote!(#crate_name::validators::minimum(value, #min)));
+        }
+        if let Some(max) = &self.max {
+            checks.push(quote!(#crate_name::validators::maximum(value, #max)));
+        }
+        if let Some(regex) = &self.regex {
+            checks.push(quote!({
+                static REGEX: ::std::sync::OnceLock<#crate_name::validators::Regex> = ::std::sync::OnceLock::new();
+                #crate_name::validators::regex(value, REGEX.get_or_init(|| #crate_name::validators::compile_regex(#regex)))
+            }));
+        }
+        if let Some(custom) = &self.custom {
+            checks.push(quote!(#custom(value)));
+        }
+        checks
+    }
+}
+
+/// run the validators on the converted value, `None` values of `Option` and `MaybeUndefined` are skipped
+///
+/// `into_error` is the method of `InputValueError` that names the argument or field,
+/// `into_arg_error` or `into_field_error`
+pub fn get_validators_code(
+    validators: Option<&Validators>,
+    ty: &syn::Type,
+    ident: &syn::Ident,
+    name: &str,
+    into_error: TokenStream,
+) -> TokenStream {
+    let Some(validators) = validators else {
+        return quote!();
+    };
+    let crate_name = get_crate_name();
+    let checks = validators.get_checks();
+    let ty = get_owned_type(ty);
+    let value = if is_type_named(ty, "Option") {
+        quote!(::std::option::Option::as_ref(&#ident))
+    } else if is_type_named(ty, "MaybeUndefined") {
+        quote!(#crate_name::MaybeUndefined::value(&#ident))
+    } else {
+        quote!(::std::option::Option::Some(&#ident))
+    };
+    quote! {
+        if let ::std::option::Option::Some(value) = #value {
+            #(
+                #checks.map_err(|e| #crate_name::internal::InputValueError::<()>::validation(e).#into_error(#name))?;
+            )*
+        }
+    }
+}
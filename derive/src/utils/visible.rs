@@ -0,0 +1,37 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::utils::crate_name::get_crate_name;
+
+/// `#[graphql(visible = false)]` or `#[graphql(visible = "fn_path")]`
+#[derive(Debug, Clone)]
+pub enum VisibleAttr {
+    Visible,
+    Hidden,
+    /// `fn(&VisibleContext) -> bool`
+    Fn(syn::Expr),
+}
+
+impl FromMeta for VisibleAttr {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        match value {
+            syn::Lit::Bool(syn::LitBool { value: true, .. }) => Ok(VisibleAttr::Visible),
+            syn::Lit::Bool(syn::LitBool { value: false, .. }) => Ok(VisibleAttr::Hidden),
+            syn::Lit::Str(lit) => Ok(VisibleAttr::Fn(lit.parse()?)),
+            _ => Err(darling::Error::unexpected_lit_type(value)),
+        }
+    }
+}
+
+impl VisibleAttr {
+    /// the `VisibleFn` of the attribute, `None` when it is always visible
+    pub fn get_visible_fn(&self) -> Option<TokenStream> {
+        let crate_name = get_crate_name();
+        match self {
+            VisibleAttr::Visible => None,
+            VisibleAttr::Hidden => Some(quote!(#crate_name::internal::hidden)),
+            VisibleAttr::Fn(expr) => Some(quote!(#expr)),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod schema_utils;
+mod complexity {
+    mod complexity_tests;
+}
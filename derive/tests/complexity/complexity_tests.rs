@@ -0,0 +1,229 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::Register;
+use dynamic_graphql::internal::Registry;
+use dynamic_graphql::value;
+use dynamic_graphql::App;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::FieldArgs;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Value;
+use dynamic_graphql::Variables;
+
+struct Limits;
+
+impl Register for Limits {
+    fn register(registry: Registry) -> Registry {
+        registry.limit_complexity(10).limit_depth(3)
+    }
+}
+
+fn count_complexity(child_complexity: usize, args: &FieldArgs) -> usize {
+    let count = match args.get("count") {
+        Some(Value::Number(count)) => count.as_u64().unwrap_or(0) as usize,
+        _ => 1,
+    };
+    count * child_complexity
+}
+
+#[Interface]
+trait Named {
+    #[graphql(complexity = 4)]
+    fn name(&self) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Named))]
+struct User {
+    id: String,
+    #[graphql(complexity = 3)]
+    email: String,
+    friends: Vec<User>,
+}
+
+impl Named for User {
+    fn name(&self) -> String {
+        self.id.clone()
+    }
+}
+
+fn user(id: &str) -> User {
+    User {
+        id: id.to_string(),
+        email: format!("{}@example.com", id),
+        friends: vec![],
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn user(&self) -> User {
+        user("1")
+    }
+    #[graphql(complexity = "count_complexity")]
+    fn users(&self, count: i32) -> Vec<User> {
+        (0..count).map(|i| user(&i.to_string())).collect()
+    }
+}
+
+#[derive(ExpandObject)]
+struct QueryExtra<'a>(&'a Query);
+
+#[ExpandObjectFields]
+impl QueryExtra<'_> {
+    #[graphql(complexity = "|child_complexity, _| child_complexity + 8")]
+    fn expensive(&self) -> User {
+        user("2")
+    }
+}
+
+#[derive(App)]
+struct App(Query, QueryExtra<'static>, User, Limits);
+
+async fn execute(request: dynamic_graphql::Request) -> Result<Value, String> {
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema
+        .execute(request.root_value(FieldValue::owned_any(Query)))
+        .await;
+    match res.errors.first() {
+        None => Ok(res.data),
+        Some(error) => Err(error.message.clone()),
+    }
+}
+
+async fn execute_query(query: &str) -> Result<Value, String> {
+    execute(dynamic_graphql::Request::new(query)).await
+}
+
+#[tokio::test]
+async fn test_const_complexity() {
+    // user(1) + email(3)
+    assert_eq!(
+        execute_query("{ user { email } }").await,
+        Ok(value!({ "user": { "email": "1@example.com" } }))
+    );
+    // user(1) + name(4) + id(1) + email(3)
+    assert_eq!(
+        execute_query("{ user { name id email } }").await,
+        Ok(value!({ "user": { "name": "1", "id": "1", "email": "1@example.com" } }))
+    );
+    // user(1) + name(4) + email(3) + user(1) + email(3)
+    assert_eq!(
+        execute_query("{ user { name email } other: user { email } }").await,
+        Err("Query is too complex.".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_fn_complexity() {
+    // 3 * email(3)
+    assert_eq!(
+        execute_query("{ users(count: 3) { email } }")
+            .await
+            .map(|_| ()),
+        Ok(())
+    );
+    // 4 * email(3)
+    assert_eq!(
+        execute_query("{ users(count: 4) { email } }").await,
+        Err("Query is too complex.".to_string())
+    );
+    // 8 + id(1)
+    assert_eq!(
+        execute_query("{ expensive { id } }").await,
+        Ok(value!({ "expensive": { "id": "2" } }))
+    );
+    // 8 + id(1) + email(3)
+    assert_eq!(
+        execute_query("{ expensive { id email } }").await,
+        Err("Query is too complex.".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_complexity_with_variables_and_fragments() {
+    let query = r#"
+        query ($count: Int!) { users(count: $count) { ...UserFields } }
+        fragment UserFields on User { id }
+    "#;
+    let request = dynamic_graphql::Request::new(query)
+        .variables(Variables::from_value(value!({ "count": 10 })));
+    assert!(execute(request).await.is_ok());
+
+    let request = dynamic_graphql::Request::new(query)
+        .variables(Variables::from_value(value!({ "count": 11 })));
+    assert_eq!(
+        execute(request).await,
+        Err("Query is too complex.".to_string())
+    );
+
+    // inline fragments on the interface use the complexity of the interface field
+    let query = "{ user { ... on Named { name } ... on User { email } } }";
+    assert_eq!(
+        execute_query(query).await,
+        Ok(value!({ "user": { "name": "1", "email": "1@example.com" } }))
+    );
+    let query = "{ user { ... on Named { name } ... on User { email id email2: email } } }";
+    assert_eq!(
+        execute_query(query).await,
+        Err("Query is too complex.".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_complexity_of_selected_operation() {
+    // only the selected operation is counted: users(4 * email(3)) is in the other one
+    let query = r#"
+        query A { expensive { id } }
+        query B { users(count: 4) { email } }
+    "#;
+    let request = dynamic_graphql::Request::new(query).operation_name("A");
+    assert_eq!(
+        execute(request).await,
+        Ok(value!({ "expensive": { "id": "2" } }))
+    );
+    let request = dynamic_graphql::Request::new(query).operation_name("B");
+    assert_eq!(
+        execute(request).await,
+        Err("Query is too complex.".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_complexity_with_skip_and_include() {
+    // 8 + id(1), email is not executed
+    let query = r#"
+        query ($skip: Boolean!) {
+            expensive { id email @skip(if: $skip) ... @include(if: false) { name } }
+        }
+    "#;
+    let request = dynamic_graphql::Request::new(query)
+        .variables(Variables::from_value(value!({ "skip": true })));
+    assert_eq!(
+        execute(request).await,
+        Ok(value!({ "expensive": { "id": "2" } }))
+    );
+    let request = dynamic_graphql::Request::new(query)
+        .variables(Variables::from_value(value!({ "skip": false })));
+    assert_eq!(
+        execute(request).await,
+        Err("Query is too complex.".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_limit_depth() {
+    assert!(execute_query("{ user { friends { id } } }").await.is_ok());
+    assert_eq!(
+        execute_query("{ user { friends { friends { id } } } }").await,
+        Err("Query is nested too deep.".to_string())
+    );
+}
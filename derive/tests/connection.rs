@@ -0,0 +1,4 @@
+mod schema_utils;
+mod connection {
+    mod connection_tests;
+}
@@ -0,0 +1,274 @@
+use dynamic_graphql::connection::query;
+use dynamic_graphql::connection::Connection;
+use dynamic_graphql::connection::Edge;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::App;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Result;
+use dynamic_graphql::SimpleObject;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct User {
+    name: String,
+}
+
+struct Friendship {
+    since: i32,
+}
+
+struct Total {
+    total: usize,
+}
+
+type FriendConnection = Connection<User, Friendship, Total>;
+
+#[derive(ExpandObject)]
+struct FriendEdgeFields<'a>(&'a Edge<User, Friendship>);
+
+#[ExpandObjectFields]
+impl FriendEdgeFields<'_> {
+    fn since(&self) -> i32 {
+        self.0.additional_fields.since
+    }
+}
+
+#[derive(ExpandObject)]
+struct FriendConnectionFields<'a>(&'a FriendConnection);
+
+#[ExpandObjectFields]
+impl FriendConnectionFields<'_> {
+    fn total_count(&self) -> usize {
+        self.0.additional_fields.total
+    }
+}
+
+const NAMES: [&str; 5] = ["a", "b", "c", "d", "e"];
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    async fn users(
+        &self,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<FriendConnection> {
+        query(
+            after,
+            before,
+            first,
+            last,
+            |after: Option<usize>, before: Option<usize>, first, last| async move {
+                let mut start = after.map(|after| after + 1).unwrap_or(0);
+                let mut end = before.unwrap_or(NAMES.len()).min(NAMES.len());
+                if let Some(first) = first {
+                    end = (start + first).min(end);
+                }
+                if let Some(last) = last {
+                    start = end.saturating_sub(last).max(start);
+                }
+                let mut connection = Connection::with_additional_fields(
+                    start > 0,
+                    end < NAMES.len(),
+                    Total { total: NAMES.len() },
+                );
+                connection.edges.extend((start..end).map(|index| {
+                    Edge::with_additional_fields(
+                        index,
+                        User {
+                            name: NAMES[index].to_string(),
+                        },
+                        Friendship {
+                            since: 2000 + index as i32,
+                        },
+                    )
+                }));
+                Ok(connection)
+            },
+        )
+        .await
+    }
+}
+
+#[derive(App)]
+struct App(
+    Query,
+    FriendEdgeFields<'static>,
+    FriendConnectionFields<'static>,
+);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    "Information about pagination in a connection"
+    type PageInfo {
+      "When paginating backwards, are there more items?"
+      hasPreviousPage: Boolean!
+      "When paginating forwards, are there more items?"
+      hasNextPage: Boolean!
+      "When paginating backwards, the cursor to continue"
+      startCursor: String
+      "When paginating forwards, the cursor to continue"
+      endCursor: String
+    }
+
+    type Query {
+      users(after: String, before: String, first: Int, last: Int): UserConnection!
+    }
+
+    type User {
+      name: String!
+    }
+
+    "A connection to a list of items"
+    type UserConnection {
+      "Information to aid in pagination"
+      pageInfo: PageInfo!
+      "A list of edges"
+      edges: [UserEdge!]!
+      "A list of nodes"
+      nodes: [User!]!
+      totalCount: Int!
+    }
+
+    "An edge in a connection"
+    type UserEdge {
+      "The item at the end of the edge"
+      node: User!
+      "A cursor for use in pagination"
+      cursor: String!
+      since: Int!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+fn request(query: &str) -> dynamic_graphql::dynamic::DynamicRequest {
+    dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query))
+}
+
+#[tokio::test]
+async fn test_query_first() {
+    let query = r#"
+        query {
+            users(first: 2, after: "0") {
+                totalCount
+                pageInfo {
+                    hasPreviousPage
+                    hasNextPage
+                    startCursor
+                    endCursor
+                }
+                edges {
+                    cursor
+                    since
+                    node {
+                        name
+                    }
+                }
+            }
+        }
+    "#;
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema.execute(request(query)).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "users": {
+                "totalCount": 5,
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": true,
+                    "startCursor": "1",
+                    "endCursor": "2",
+                },
+                "edges": [
+                    { "cursor": "1", "since": 2001, "node": { "name": "b" } },
+                    { "cursor": "2", "since": 2002, "node": { "name": "c" } },
+                ],
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_query_last() {
+    let query = r#"
+        query {
+            users(last: 2) {
+                pageInfo {
+                    hasPreviousPage
+                    hasNextPage
+                }
+                nodes {
+                    name
+                }
+            }
+        }
+    "#;
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema.execute(request(query)).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "users": {
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": false,
+                },
+                "nodes": [{ "name": "d" }, { "name": "e" }],
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_query_invalid_arguments() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let res = schema
+        .execute(request("{ users(first: 1, last: 1) { totalCount } }"))
+        .await;
+    assert_eq!(
+        res.errors[0].message,
+        "The \"first\" and \"last\" parameters cannot exist at the same time"
+    );
+
+    let res = schema
+        .execute(request("{ users(after: \"x\") { totalCount } }"))
+        .await;
+    assert_eq!(res.errors[0].message, "Invalid cursor for \"after\"");
+}
+
+#[tokio::test]
+async fn test_query_negative_count() {
+    let result = query(
+        None,
+        None,
+        Some(-1),
+        None,
+        |_: Option<usize>, _, _, _| async { Ok(Connection::<User>::new(false, false)) },
+    )
+    .await;
+    assert_eq!(
+        result.err().unwrap().message,
+        "The \"first\" parameter must be a non-negative number"
+    );
+}
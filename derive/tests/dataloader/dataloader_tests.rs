@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use dynamic_graphql::dataloader::DataLoader;
+use dynamic_graphql::dataloader::Loader;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::Registry;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Result;
+use dynamic_graphql::SimpleObject;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject, Clone)]
+struct User {
+    name: String,
+}
+
+/// records the keys of each batch
+#[derive(Clone, Default)]
+struct UserLoader {
+    batches: Arc<Mutex<Vec<Vec<u32>>>>,
+}
+
+impl UserLoader {
+    fn batches(&self) -> Vec<Vec<u32>> {
+        self.batches.lock().unwrap().clone()
+    }
+}
+
+impl Loader<u32> for UserLoader {
+    type Value = User;
+
+    async fn load(&self, keys: &[u32]) -> Result<HashMap<u32, User>> {
+        let mut keys = keys.to_vec();
+        keys.sort();
+        self.batches.lock().unwrap().push(keys.clone());
+        Ok(keys
+            .into_iter()
+            .filter(|id| *id != 404)
+            .map(|id| {
+                let user = User {
+                    name: format!("user {}", id),
+                };
+                (id, user)
+            })
+            .collect())
+    }
+}
+
+#[derive(ResolvedObject)]
+struct Post {
+    author_id: u32,
+}
+
+#[ResolvedObjectFields]
+impl Post {
+    async fn author(
+        &self,
+        #[graphql(loader)] users: &DataLoader<UserLoader>,
+    ) -> Result<Option<User>> {
+        users.load_one(self.author_id).await
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn posts(&self) -> Vec<Post> {
+        [1, 2, 1, 404]
+            .into_iter()
+            .map(|author_id| Post { author_id })
+            .collect()
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+fn request(query: &str) -> dynamic_graphql::dynamic::DynamicRequest {
+    dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query))
+}
+
+const QUERY: &str = "{ posts { author { name } } }";
+
+#[tokio::test]
+async fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Post {
+      author: User
+    }
+
+    type Query {
+      posts: [Post!]!
+    }
+
+    type User {
+      name: String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+#[tokio::test]
+async fn test_batch() {
+    let loader = UserLoader::default();
+
+    let mut registry = Registry::new().register::<App>();
+    registry.data.insert(DataLoader::new(loader.clone()));
+    let schema = registry.create_schema().finish().unwrap();
+
+    let res = schema.execute(request(QUERY)).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "posts": [
+                { "author": { "name": "user 1" } },
+                { "author": { "name": "user 2" } },
+                { "author": { "name": "user 1" } },
+                { "author": null },
+            ]
+        })
+    );
+    assert_eq!(loader.batches(), vec![vec![1, 2, 404]]);
+
+    // without cache, the values are loaded again
+    schema.execute(request(QUERY)).await;
+    assert_eq!(loader.batches().len(), 2);
+}
+
+#[tokio::test]
+async fn test_cache() {
+    let loader = UserLoader::default();
+    let schema = App::create_schema().finish().unwrap();
+    let users = DataLoader::with_cache(loader.clone());
+
+    for _ in 0..2 {
+        let req = dynamic_graphql::Request::new(QUERY)
+            .data(users.clone())
+            .root_value(FieldValue::owned_any(Query));
+        let res = schema.execute(req).await;
+        assert!(res.errors.is_empty(), "{:?}", res.errors);
+    }
+    // the missing user is not cached
+    assert_eq!(loader.batches(), vec![vec![1, 2, 404], vec![404]]);
+
+    users.clear::<u32>();
+    assert_eq!(users.load_many([1, 2]).await.unwrap().len(), 2);
+    assert_eq!(loader.batches().len(), 3);
+}
+
+#[tokio::test]
+async fn test_schema_data_cache() {
+    let loader = UserLoader::default();
+
+    let mut registry = Registry::new().register::<App>();
+    registry.data.insert(DataLoader::with_cache(loader.clone()));
+    let schema = registry.create_schema().finish().unwrap();
+
+    // the users are loaded once in the request, the cache is not shared by the requests
+    let query = "{ posts { author { name } } }";
+    for _ in 0..2 {
+        let res = schema.execute(request(query)).await;
+        assert!(res.errors.is_empty(), "{:?}", res.errors);
+    }
+    assert_eq!(loader.batches(), vec![vec![1, 2, 404], vec![1, 2, 404]]);
+}
+
+#[tokio::test]
+async fn test_missing_loader() {
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema.execute(request(QUERY)).await;
+    assert!(res.errors[0].message.starts_with("DataLoader<"));
+    assert!(res.errors[0]
+        .message
+        .ends_with("UserLoader> is not registered"));
+}
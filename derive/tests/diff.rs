@@ -0,0 +1,4 @@
+mod schema_utils;
+mod diff {
+    mod diff_tests;
+}
@@ -0,0 +1,193 @@
+use dynamic_graphql::diff::ChangeKind;
+use dynamic_graphql::diff::ChangeLevel;
+use dynamic_graphql::diff::SchemaChange;
+use dynamic_graphql::diff::SchemaDiff;
+use dynamic_graphql::App;
+use dynamic_graphql::Enum;
+use dynamic_graphql::SimpleObject;
+
+const OLD: &str = r#"
+    type Query {
+      user(id: ID!, deleted: Boolean = false): User
+      users(first: Int!): [User!]!
+    }
+
+    type User {
+      id: ID!
+      name: String
+      email: String!
+    }
+
+    enum Role {
+      ADMIN
+      USER
+      GUEST
+    }
+
+    input UserFilter {
+      name: String
+      role: Role!
+    }
+
+    union SearchResult = User
+"#;
+
+const NEW: &str = r#"
+    type Query {
+      user(id: ID!, deleted: Boolean = true, org: ID!): User
+      users(first: Int): [User!]!
+      me: User
+    }
+
+    type User {
+      id: ID!
+      name: String!
+      email: String
+      role: Role!
+    }
+
+    enum Role {
+      ADMIN
+      USER
+      OWNER
+    }
+
+    input UserFilter {
+      name: String!
+      role: Role
+      email: String
+      org: ID!
+    }
+
+    interface Node {
+      id: ID!
+    }
+
+    scalar SearchResult
+"#;
+
+#[test]
+fn test_from_sdl() {
+    let diff = SchemaDiff::from_sdl(OLD, NEW).unwrap();
+    insta::assert_snapshot!(diff.to_string(), @r###"
+    [safe] Type "Node" was added
+    [safe] Field "Query.me" was added
+    [dangerous] Argument "Query.user.deleted" changed default value from "false" to "true"
+    [breaking] Required argument "Query.user.org" was added
+    [safe] Argument "Query.users.first" changed type from "Int!" to "Int"
+    [breaking] Enum value "Role.GUEST" was removed
+    [dangerous] Enum value "Role.OWNER" was added
+    [breaking] Type "SearchResult" changed from "union" to "scalar"
+    [breaking] Field "User.email" changed type from "String!" to "String"
+    [safe] Field "User.name" changed type from "String" to "String!"
+    [safe] Field "User.role" was added
+    [safe] Input field "UserFilter.email" was added
+    [breaking] Input field "UserFilter.name" changed type from "String" to "String!"
+    [breaking] Required input field "UserFilter.org" was added
+    [safe] Input field "UserFilter.role" changed type from "Role!" to "Role"
+    "###);
+    assert!(diff.is_breaking());
+    assert_eq!(
+        diff.changes_of(ChangeLevel::Dangerous)
+            .map(|change| change.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Query.user.deleted", "Role.OWNER"]
+    );
+}
+
+#[test]
+fn test_same_sdl() {
+    let diff = SchemaDiff::from_sdl(OLD, OLD).unwrap();
+    assert!(diff.is_empty());
+    assert!(!diff.is_breaking());
+}
+
+#[test]
+fn test_invalid_sdl() {
+    assert!(SchemaDiff::from_sdl(OLD, "type Query {").is_err());
+}
+
+mod v1 {
+    use super::*;
+
+    #[derive(Enum)]
+    pub enum Status {
+        Active,
+        Disabled,
+    }
+
+    #[derive(SimpleObject)]
+    pub struct User {
+        pub id: String,
+        pub name: Option<String>,
+        pub status: Status,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    pub struct Query {
+        pub user: Option<User>,
+    }
+
+    #[derive(App)]
+    pub struct App(Query, User, Status);
+}
+
+mod v2 {
+    use super::*;
+
+    #[derive(Enum)]
+    pub enum Status {
+        Active,
+    }
+
+    #[derive(SimpleObject)]
+    pub struct User {
+        pub id: String,
+        pub name: String,
+        pub status: Option<Status>,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    pub struct Query {
+        pub user: Option<User>,
+    }
+
+    #[derive(App)]
+    pub struct App(Query, User, Status);
+}
+
+#[test]
+fn test_between_schemas() {
+    let old = v1::App::create_schema().finish().unwrap();
+    let new = v2::App::create_schema().finish().unwrap();
+    let diff = SchemaDiff::between(&old, &new);
+    assert_eq!(
+        diff.changes,
+        vec![
+            SchemaChange {
+                level: ChangeLevel::Breaking,
+                path: "Status.DISABLED".to_string(),
+                kind: ChangeKind::EnumValueRemoved,
+            },
+            SchemaChange {
+                level: ChangeLevel::Safe,
+                path: "User.name".to_string(),
+                kind: ChangeKind::FieldTypeChanged {
+                    from: "String".to_string(),
+                    to: "String!".to_string(),
+                },
+            },
+            SchemaChange {
+                level: ChangeLevel::Breaking,
+                path: "User.status".to_string(),
+                kind: ChangeKind::FieldTypeChanged {
+                    from: "Status!".to_string(),
+                    to: "Status".to_string(),
+                },
+            },
+        ]
+    );
+    assert!(SchemaDiff::between(&old, &old).is_empty());
+}
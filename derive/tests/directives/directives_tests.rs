@@ -0,0 +1,257 @@
+use dynamic_graphql::dynamic::Directive;
+use dynamic_graphql::dynamic::TypeRef;
+use dynamic_graphql::internal::Register;
+use dynamic_graphql::internal::Registry;
+use dynamic_graphql::value;
+use dynamic_graphql::App;
+use dynamic_graphql::DirectiveDefinition;
+use dynamic_graphql::DirectiveLocation;
+use dynamic_graphql::Enum;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::Interface;
+use dynamic_graphql::Name;
+use dynamic_graphql::RegistryIssue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Scalar;
+use dynamic_graphql::ScalarValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::Value;
+
+use crate::schema_utils::normalize_schema;
+
+fn auth(role: &str) -> Directive {
+    Directive::new("auth").argument("role", Value::Enum(Name::new(role)))
+}
+
+fn cache_control(max_age: i32) -> Directive {
+    Directive::new("cacheControl").argument("maxAge", value!(max_age))
+}
+
+fn tag(name: &str) -> Directive {
+    Directive::new("tag").argument("name", value!(name))
+}
+
+struct Directives;
+
+impl Register for Directives {
+    fn register(registry: Registry) -> Registry {
+        registry
+            .register_directive(
+                DirectiveDefinition::new("auth")
+                    .description("only callers with the role can use the element")
+                    .argument("role", TypeRef::named_nn("Role"))
+                    .location(DirectiveLocation::Object)
+                    .location(DirectiveLocation::FieldDefinition),
+            )
+            .register_directive(
+                DirectiveDefinition::new("cacheControl")
+                    .argument_with_default("maxAge", TypeRef::named(TypeRef::INT), 0)
+                    .location(DirectiveLocation::Object)
+                    .location(DirectiveLocation::FieldDefinition),
+            )
+            .register_directive(
+                DirectiveDefinition::new("tag")
+                    .argument("name", TypeRef::named_nn(TypeRef::STRING))
+                    .repeatable()
+                    .location(DirectiveLocation::Scalar)
+                    .location(DirectiveLocation::ArgumentDefinition)
+                    .location(DirectiveLocation::Interface)
+                    .location(DirectiveLocation::Union)
+                    .location(DirectiveLocation::Enum)
+                    .location(DirectiveLocation::EnumValue)
+                    .location(DirectiveLocation::InputObject)
+                    .location(DirectiveLocation::InputFieldDefinition),
+            )
+    }
+}
+
+#[derive(Enum)]
+#[graphql(directive = tag("roles"))]
+enum Role {
+    Member,
+    #[graphql(directive = tag("staff"))]
+    Admin,
+}
+
+#[derive(Scalar)]
+#[graphql(directive = tag("scalar"))]
+struct Email(String);
+
+impl ScalarValue for Email {
+    fn from_value(value: Value) -> dynamic_graphql::Result<Self> {
+        match value {
+            Value::String(value) => Ok(Email(value)),
+            _ => Err("invalid value".into()),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+
+#[Interface]
+#[graphql(directive = tag("node"))]
+trait Node {
+    #[graphql(directive = cache_control(30))]
+    fn id(&self) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Node))]
+#[graphql(directive = cache_control(60), directive = auth("MEMBER"))]
+struct User {
+    #[graphql(skip)]
+    id: String,
+    #[graphql(directive = auth("ADMIN"))]
+    email: Email,
+    role: Role,
+}
+
+impl Node for User {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+#[derive(ExpandObject)]
+struct UserAudit<'a>(&'a User);
+
+#[ExpandObjectFields]
+impl UserAudit<'_> {
+    #[graphql(directive = auth("ADMIN"))]
+    fn last_login(&self, #[graphql(directive = tag("format"))] format: String) -> String {
+        format
+    }
+}
+
+#[derive(SimpleObject)]
+struct Team {
+    name: String,
+}
+
+#[derive(Union)]
+#[graphql(directive = tag("search"))]
+enum SearchResult {
+    User(User),
+    Team(Team),
+}
+
+#[derive(InputObject)]
+#[graphql(directive = tag("filter"))]
+struct UserFilter {
+    #[graphql(directive = tag("role"))]
+    role: Option<Role>,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root, register(Directives))]
+#[graphql(directive = cache_control(10))]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    #[graphql(directive = auth("ADMIN"))]
+    fn users(&self, #[graphql(directive = tag("filter"))] filter: Option<UserFilter>) -> Vec<User> {
+        let _ = filter;
+        vec![]
+    }
+    fn search(&self) -> Vec<SearchResult> {
+        vec![]
+    }
+}
+
+#[derive(App)]
+struct App(Query, UserAudit<'static>, SearchResult);
+
+#[test]
+fn test_sdl() {
+    let sdl = App::sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    scalar Email @tag(name: "scalar")
+
+    interface Node @tag(name: "node") {
+      id: String! @cacheControl(maxAge: 30)
+    }
+
+    type Query @cacheControl(maxAge: 10) {
+      users(filter: UserFilter @tag(name: "filter")): [User!]! @auth(role: ADMIN)
+      search: [SearchResult!]!
+    }
+
+    enum Role @tag(name: "roles") {
+      MEMBER
+      ADMIN @tag(name: "staff")
+    }
+
+    union SearchResult @tag(name: "search") = User | Team
+
+    type Team {
+      name: String!
+    }
+
+    type User implements Node @cacheControl(maxAge: 60) @auth(role: MEMBER) {
+      email: Email! @auth(role: ADMIN)
+      role: Role!
+      id: String!
+      lastLogin(format: String! @tag(name: "format")): String! @auth(role: ADMIN)
+    }
+
+    input UserFilter @tag(name: "filter") {
+      role: Role @tag(name: "role")
+    }
+
+    schema {
+      query: Query
+    }
+
+    "only callers with the role can use the element"
+    directive @auth(role: Role!) on OBJECT | FIELD_DEFINITION
+
+    directive @cacheControl(maxAge: Int = 0) on OBJECT | FIELD_DEFINITION
+
+    directive @tag(name: String!) repeatable on SCALAR | ARGUMENT_DEFINITION | INTERFACE | UNION | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION
+    "###);
+}
+
+#[test]
+fn test_schema_without_definitions() {
+    let schema = App::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+    assert!(sdl.contains("email: Email! @auth(role: ADMIN)"));
+    assert!(!sdl.contains("directive @auth"));
+}
+
+#[test]
+fn test_duplicate_directive() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        value: String,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let registry = Registry::new()
+        .register::<App>()
+        .register_directive(DirectiveDefinition::new("auth").location(DirectiveLocation::Object))
+        // the same definition can be registered again
+        .register_directive(DirectiveDefinition::new("auth").location(DirectiveLocation::Object))
+        .register_directive(
+            DirectiveDefinition::new("auth").location(DirectiveLocation::FieldDefinition),
+        );
+    let Err(err) = registry.try_create_schema() else {
+        panic!("the directive is defined twice");
+    };
+    assert_eq!(
+        err.issues,
+        vec![RegistryIssue::DuplicateDirective {
+            name: "auth".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use dynamic_graphql::dataloader::DataLoader;
+use dynamic_graphql::dataloader::Loader;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::federation::Federation;
+use dynamic_graphql::internal::Registry;
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Result;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::ID;
+
+#[derive(SimpleObject)]
+#[graphql(key = "id")]
+struct User {
+    id: ID,
+    #[graphql(shareable)]
+    name: String,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(key = "upc", key = "sku", shareable)]
+struct Product {
+    upc: String,
+}
+
+#[ResolvedObjectFields]
+impl Product {
+    fn upc(&self) -> &str {
+        &self.upc
+    }
+
+    #[graphql(external)]
+    fn weight(&self) -> i32 {
+        10
+    }
+
+    #[graphql(requires = "weight")]
+    fn shipping_estimate(&self) -> i32 {
+        20
+    }
+
+    #[graphql(provides = "name")]
+    fn author(&self) -> User {
+        User {
+            id: ID("1".to_string()),
+            name: "author".to_string(),
+        }
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn product(&self) -> Product {
+        Product {
+            upc: "p1".to_string(),
+        }
+    }
+}
+
+#[derive(ExpandObject)]
+struct QueryEntities<'a>(&'a Query);
+
+#[ExpandObjectFields]
+impl QueryEntities<'_> {
+    #[graphql(entity)]
+    fn find_user_by_id(id: ID) -> Option<User> {
+        (id.as_str() != "404").then(|| User {
+            name: format!("user {}", id.as_str()),
+            id,
+        })
+    }
+
+    #[graphql(entity)]
+    async fn find_product_by_upc(_ctx: &Context<'_>, upc: String) -> Option<Product> {
+        Some(Product { upc })
+    }
+
+    #[graphql(entity)]
+    fn find_product_by_sku(sku: String, name: Option<String>) -> Product {
+        Product {
+            upc: format!("{}-{}", sku, name.unwrap_or_default()),
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query, QueryEntities<'static>);
+
+fn request(query: &str) -> dynamic_graphql::dynamic::DynamicRequest {
+    dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query))
+}
+
+#[tokio::test]
+async fn test_service_sdl() {
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema.execute(request("{ _service { sdl } }")).await;
+    let data = res.data.into_json().unwrap();
+    let sdl = data["_service"]["sdl"].as_str().unwrap();
+    // the types, without the built-in directives and the federation link
+    let types = sdl.split("\"\"\"").next().unwrap();
+    insta::assert_snapshot!(types, @r###"
+    type Product @key(fields: "upc") @key(fields: "sku") @shareable {
+    	upc: String!
+    	weight: Int! @external
+    	shippingEstimate: Int! @requires(fields: "weight")
+    	author: User! @provides(fields: "name")
+    }
+
+    type Query {
+    	product: Product!
+    }
+
+    type User @key(fields: "id") {
+    	id: ID!
+    	name: String! @shareable
+    }
+"###);
+}
+
+#[tokio::test]
+async fn test_entities() {
+    let query = r#"
+        query {
+            _entities(representations: [
+                { __typename: "User", id: "1" },
+                { __typename: "Product", upc: "p1" },
+                { __typename: "Product", sku: "s1", name: "box" },
+            ]) {
+                __typename
+                ... on User {
+                    id
+                    name
+                }
+                ... on Product {
+                    upc
+                }
+            }
+        }
+    "#;
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema.execute(request(query)).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "_entities": [
+                { "__typename": "User", "id": "1", "name": "user 1" },
+                { "__typename": "Product", "upc": "p1" },
+                { "__typename": "Product", "upc": "s1-box" },
+            ]
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_entities_not_found() {
+    let query = r#"
+        query {
+            _entities(representations: [
+                { __typename: "User", id: "1" },
+                { __typename: "User", id: "404" },
+                { __typename: "Review", id: "1" },
+                { __typename: "Product", upc: "p1" },
+            ]) {
+                __typename
+            }
+        }
+    "#;
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema.execute(request(query)).await;
+    let data = res.data.into_json().unwrap();
+    // the other entities are resolved, in the order of the representations
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "_entities": [
+                { "__typename": "User" },
+                null,
+                null,
+                { "__typename": "Product" },
+            ]
+        })
+    );
+    // a missing entity is not an error
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        "no entity resolver for \"Review\" matches the representation"
+    );
+    assert_eq!(
+        serde_json::to_value(&res.errors[0].path).unwrap(),
+        serde_json::json!(["_entities", 2])
+    );
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct PlainQuery;
+
+#[ResolvedObjectFields]
+impl PlainQuery {
+    fn value(&self) -> i32 {
+        1
+    }
+}
+
+#[tokio::test]
+async fn test_enable_federation() {
+    #[derive(App)]
+    struct App(PlainQuery, Federation);
+
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new("{ _service { sdl } }")
+                .root_value(FieldValue::owned_any(PlainQuery)),
+        )
+        .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+
+    #[derive(App)]
+    struct PlainApp(PlainQuery);
+
+    let schema = PlainApp::create_schema().finish().unwrap();
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new("{ _service { sdl } }")
+                .root_value(FieldValue::owned_any(PlainQuery)),
+        )
+        .await;
+    assert!(!res.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_entities_batched() {
+    #[derive(SimpleObject)]
+    #[graphql(key = "id")]
+    struct Review {
+        id: u32,
+    }
+
+    /// records the keys of each batch
+    #[derive(Clone, Default)]
+    struct ReviewLoader {
+        batches: Arc<Mutex<Vec<Vec<u32>>>>,
+    }
+
+    impl Loader<u32> for ReviewLoader {
+        type Value = u32;
+
+        async fn load(&self, keys: &[u32]) -> Result<HashMap<u32, u32>> {
+            let mut keys = keys.to_vec();
+            keys.sort();
+            self.batches.lock().unwrap().push(keys.clone());
+            Ok(keys.into_iter().map(|id| (id, id)).collect())
+        }
+    }
+
+    #[derive(ExpandObject)]
+    struct QueryReviews<'a>(&'a PlainQuery);
+
+    #[ExpandObjectFields]
+    impl QueryReviews<'_> {
+        #[graphql(entity)]
+        async fn find_review_by_id(
+            #[graphql(loader)] reviews: &DataLoader<ReviewLoader>,
+            id: u32,
+        ) -> Result<Option<Review>> {
+            let id = reviews.load_one(id).await?;
+            Ok(id.map(|id| Review { id }))
+        }
+    }
+
+    #[derive(App)]
+    struct App(PlainQuery, QueryReviews<'static>);
+
+    let loader = ReviewLoader::default();
+    let mut registry = Registry::new().register::<App>();
+    registry.data.insert(DataLoader::new(loader.clone()));
+    let schema = registry.create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            _entities(representations: [
+                { __typename: "Review", id: 1 },
+                { __typename: "Review", id: 2 },
+                { __typename: "Review", id: 3 },
+            ]) {
+                ... on Review {
+                    id
+                }
+            }
+        }
+    "#;
+    let res = schema
+        .execute(dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(PlainQuery)))
+        .await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({ "_entities": [{ "id": 1 }, { "id": 2 }, { "id": 3 }] })
+    );
+    // one load call serves the representations
+    assert_eq!(*loader.batches.lock().unwrap(), vec![vec![1, 2, 3]]);
+}
@@ -0,0 +1,189 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::App;
+use dynamic_graphql::ErrorExtensions;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::GraphQLError;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Result;
+use dynamic_graphql::ResultExt;
+
+#[derive(GraphQLError, Debug)]
+enum UserError {
+    #[graphql(message = "not logged in")]
+    Unauthenticated,
+    #[graphql(message = "user {id} not found")]
+    NotFound {
+        #[graphql(extension)]
+        id: u32,
+    },
+    #[graphql(message = "{0} is not allowed to {1}", code = "FORBIDDEN")]
+    PermissionDenied(#[graphql(extension = "role")] String, String),
+    #[graphql(message = "try again later")]
+    RateLimited {
+        #[graphql(extension)]
+        retry_after: u32,
+        #[graphql(extension = "limit")]
+        max_requests: u32,
+    },
+}
+
+#[test]
+fn test_extend() {
+    let error = UserError::Unauthenticated.extend();
+    assert_eq!(error.message, "not logged in");
+    assert_eq!(
+        serde_json::to_value(error.extensions).unwrap(),
+        serde_json::json!({ "code": "UNAUTHENTICATED" })
+    );
+
+    let error = UserError::NotFound { id: 1 }.extend();
+    assert_eq!(error.message, "user 1 not found");
+    assert_eq!(
+        serde_json::to_value(error.extensions).unwrap(),
+        serde_json::json!({ "code": "NOT_FOUND", "id": 1 })
+    );
+
+    let error = UserError::PermissionDenied("guest".to_string(), "delete".to_string()).extend();
+    assert_eq!(error.message, "guest is not allowed to delete");
+    assert_eq!(
+        serde_json::to_value(error.extensions).unwrap(),
+        serde_json::json!({ "code": "FORBIDDEN", "role": "guest" })
+    );
+
+    let error = UserError::RateLimited {
+        retry_after: 10,
+        max_requests: 100,
+    }
+    .extend();
+    assert_eq!(error.message, "try again later");
+    assert_eq!(
+        serde_json::to_value(error.extensions).unwrap(),
+        serde_json::json!({ "code": "RATE_LIMITED", "retryAfter": 10, "limit": 100 })
+    );
+}
+
+#[tokio::test]
+async fn test_query() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn user(&self, id: u32) -> Result<Option<String>> {
+            let user: Result<Option<String>, UserError> = Err(UserError::NotFound { id });
+            user.extend()
+        }
+        async fn users(&self) -> Vec<Option<Result<String>>> {
+            vec![
+                Some(Ok("foo".to_string())),
+                Some(Err(UserError::PermissionDenied(
+                    "guest".to_string(),
+                    "read".to_string(),
+                )
+                .extend())),
+            ]
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            user(id: 42)
+            users
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "users": ["foo", null],
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&res.errors).unwrap(),
+        serde_json::json!([
+            {
+                "message": "user 42 not found",
+                "locations": [{ "line": 3, "column": 13 }],
+                "extensions": { "code": "NOT_FOUND", "id": 42 },
+            },
+            {
+                "message": "guest is not allowed to read",
+                "locations": [{ "line": 4, "column": 13 }],
+                "path": ["users", 1],
+                "extensions": { "code": "FORBIDDEN", "role": "guest" },
+            },
+        ])
+    );
+}
+
+#[derive(GraphQLError, thiserror::Error, Debug)]
+enum PaymentError {
+    #[graphql(message = "card declined: {reason}")]
+    #[error("declined")]
+    Declined {
+        #[graphql(extension)]
+        reason: String,
+    },
+}
+
+#[tokio::test]
+async fn test_displayable_error() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn pay(&self) -> Result<bool, PaymentError> {
+            Err(PaymentError::Declined {
+                reason: "expired".to_string(),
+            })
+        }
+        async fn pay_extended(&self) -> Result<bool> {
+            let paid: Result<bool, PaymentError> = Err(PaymentError::Declined {
+                reason: "expired".to_string(),
+            });
+            paid.extend()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let req = dynamic_graphql::Request::new("{ pay }").root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    // converted with `Display`
+    assert_eq!(
+        serde_json::to_value(&res.errors).unwrap(),
+        serde_json::json!([
+            {
+                "message": "declined",
+                "locations": [{ "line": 1, "column": 3 }],
+            },
+        ])
+    );
+
+    let req =
+        dynamic_graphql::Request::new("{ payExtended }").root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        serde_json::to_value(&res.errors).unwrap(),
+        serde_json::json!([
+            {
+                "message": "card declined: expired",
+                "locations": [{ "line": 1, "column": 3 }],
+                "extensions": { "code": "DECLINED", "reason": "expired" },
+            },
+        ])
+    );
+}
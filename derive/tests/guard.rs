@@ -0,0 +1,4 @@
+mod schema_utils;
+mod guard {
+    mod guard_tests;
+}
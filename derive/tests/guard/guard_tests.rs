@@ -0,0 +1,347 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::value;
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Guard;
+use dynamic_graphql::GuardExt;
+use dynamic_graphql::Interface;
+use dynamic_graphql::Mutation;
+use dynamic_graphql::MutationFields;
+use dynamic_graphql::MutationRoot;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Subscription;
+use dynamic_graphql::SubscriptionFields;
+use dynamic_graphql::SubscriptionRoot;
+use futures_util::stream;
+use futures_util::Stream;
+use futures_util::StreamExt;
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Role {
+    Admin,
+    Guest,
+}
+
+struct RoleGuard(Role);
+
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> dynamic_graphql::Result<()> {
+        if ctx.data_opt::<Role>() == Some(&self.0) {
+            Ok(())
+        } else {
+            Err("Forbidden".into())
+        }
+    }
+}
+
+fn deny(_ctx: &Context<'_>) -> dynamic_graphql::Result<()> {
+    Err("Denied".into())
+}
+
+fn allow(_ctx: &Context<'_>) -> dynamic_graphql::Result<()> {
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_object_guard() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        public: String,
+        #[graphql(guard = "RoleGuard(Role::Admin)")]
+        secret: String,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+    let root = Query {
+        public: "public".to_string(),
+        secret: "secret".to_string(),
+    };
+
+    let req = dynamic_graphql::Request::new("{ public secret }")
+        .data(Role::Admin)
+        .root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({ "public": "public", "secret": "secret" })
+    );
+
+    let root = Query {
+        public: "public".to_string(),
+        secret: "secret".to_string(),
+    };
+    let req = dynamic_graphql::Request::new("{ public }")
+        .data(Role::Guest)
+        .root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({ "public": "public" })
+    );
+
+    let root = Query {
+        public: "public".to_string(),
+        secret: "secret".to_string(),
+    };
+    let req = dynamic_graphql::Request::new("{ public secret }")
+        .data(Role::Guest)
+        .root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Forbidden");
+}
+
+#[tokio::test]
+async fn test_resolved_object_guard() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        #[graphql(guard = "RoleGuard(Role::Admin)")]
+        fn admin(&self) -> String {
+            "admin".to_string()
+        }
+        #[graphql(guard = "RoleGuard(Role::Admin).or(RoleGuard(Role::Guest))")]
+        async fn anyone(&self) -> String {
+            "anyone".to_string()
+        }
+        #[graphql(guard = "RoleGuard(Role::Admin).and(deny)")]
+        fn nobody(&self) -> String {
+            "nobody".to_string()
+        }
+        #[graphql(guard = "allow")]
+        fn with_arg(&self, value: i32) -> i32 {
+            value
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = "{ admin }";
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new(query)
+                .data(Role::Admin)
+                .root_value(FieldValue::owned_any(Query)),
+        )
+        .await;
+    assert_eq!(res.data, value!({ "admin": "admin" }));
+
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new(query)
+                .data(Role::Guest)
+                .root_value(FieldValue::owned_any(Query)),
+        )
+        .await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Forbidden");
+
+    let query = "{ anyone }";
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new(query)
+                .data(Role::Guest)
+                .root_value(FieldValue::owned_any(Query)),
+        )
+        .await;
+    assert_eq!(res.data, value!({ "anyone": "anyone" }));
+
+    let res = schema
+        .execute(dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query)))
+        .await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Forbidden");
+
+    let query = "{ nobody }";
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new(query)
+                .data(Role::Admin)
+                .root_value(FieldValue::owned_any(Query)),
+        )
+        .await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Denied");
+
+    let query = "{ withArg(value: 3) }";
+    let res = schema
+        .execute(dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query)))
+        .await;
+    assert_eq!(res.data, value!({ "withArg": 3 }));
+}
+
+#[tokio::test]
+async fn test_expand_object_and_mutation_guard() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(ExpandObject)]
+    struct QueryExtra<'a>(&'a Query);
+
+    #[ExpandObjectFields]
+    impl QueryExtra<'_> {
+        #[graphql(guard = "deny")]
+        fn extra(&self) -> String {
+            "extra".to_string()
+        }
+    }
+
+    #[derive(MutationRoot)]
+    struct MutationRoot;
+
+    #[derive(Mutation)]
+    struct MyMutation(MutationRoot);
+
+    #[MutationFields]
+    impl MyMutation {
+        #[graphql(guard = "RoleGuard(Role::Admin)")]
+        fn do_it() -> bool {
+            true
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, QueryExtra<'static>, MutationRoot, MyMutation);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let root = Query {
+        foo: "foo".to_string(),
+    };
+    let req = dynamic_graphql::Request::new("{ extra }").root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Denied");
+
+    let res = schema
+        .execute(dynamic_graphql::Request::new("mutation { doIt }").data(Role::Admin))
+        .await;
+    assert_eq!(res.data, value!({ "doIt": true }));
+
+    let res = schema
+        .execute(dynamic_graphql::Request::new("mutation { doIt }").data(Role::Guest))
+        .await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Forbidden");
+}
+
+#[tokio::test]
+async fn test_interface_guard() {
+    #[Interface]
+    trait Node {
+        #[graphql(guard = "RoleGuard(Role::Admin)")]
+        fn the_id(&self) -> String;
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(implements(Node))]
+    struct FooNode;
+
+    impl Node for FooNode {
+        fn the_id(&self) -> String {
+            "foo".to_string()
+        }
+    }
+
+    #[ResolvedObjectFields]
+    impl FooNode {}
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn foo(&self) -> FooNode {
+            FooNode
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, FooNode);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = "{ foo { theId } }";
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new(query)
+                .data(Role::Admin)
+                .root_value(FieldValue::owned_any(Query)),
+        )
+        .await;
+    assert_eq!(res.data, value!({ "foo": { "theId": "foo" } }));
+
+    let res = schema
+        .execute(
+            dynamic_graphql::Request::new(query)
+                .data(Role::Guest)
+                .root_value(FieldValue::owned_any(Query)),
+        )
+        .await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Forbidden");
+}
+
+#[tokio::test]
+async fn test_subscription_guard() {
+    #[derive(SubscriptionRoot)]
+    struct SubscriptionRoot;
+
+    #[derive(Subscription)]
+    struct MySubscription(SubscriptionRoot);
+
+    #[SubscriptionFields]
+    impl MySubscription {
+        #[graphql(guard = "RoleGuard(Role::Admin)")]
+        async fn numbers() -> impl Stream<Item = i32> {
+            stream::iter(1..=2)
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, SubscriptionRoot, MySubscription);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let items: Vec<_> = schema
+        .execute_stream(dynamic_graphql::Request::new("subscription { numbers }").data(Role::Admin))
+        .map(|res| res.into_result().unwrap().data)
+        .collect()
+        .await;
+    assert_eq!(
+        items,
+        vec![value!({ "numbers": 1 }), value!({ "numbers": 2 })]
+    );
+
+    let mut stream = schema.execute_stream(
+        dynamic_graphql::Request::new("subscription { numbers }").data(Role::Guest),
+    );
+    let res = stream.next().await.unwrap();
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Forbidden");
+}
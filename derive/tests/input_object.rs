@@ -1,6 +1,7 @@
 mod schema_utils;
 mod input_object {
     mod list_tests;
+    mod one_of_tests;
     mod tests;
     mod type_tests;
 }
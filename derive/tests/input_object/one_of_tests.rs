@@ -0,0 +1,156 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::InputObject;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::ID;
+
+use crate::schema_utils::normalize_schema;
+
+#[test]
+fn test_impl_object() {
+    #[allow(dead_code)]
+    #[derive(InputObject)]
+    enum SearchBy {
+        Id(ID),
+        Email(String),
+    }
+    assert_eq!(
+        <SearchBy as InputObject>::get_input_object_type_name(),
+        "SearchBy"
+    );
+}
+
+#[tokio::test]
+async fn test_schema() {
+    #[derive(InputObject)]
+    struct ByName {
+        first: String,
+        last: String,
+    }
+
+    /// Search users
+    #[derive(InputObject)]
+    enum SearchBy {
+        Id(ID),
+        Email(String),
+        #[graphql(name = "fullName")]
+        Name(ByName),
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn search(&self, by: SearchBy) -> String {
+            match by {
+                SearchBy::Id(id) => format!("id: {}", id.0),
+                SearchBy::Email(email) => format!("email: {}", email),
+                SearchBy::Name(name) => format!("name: {} {}", name.first, name.last),
+            }
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    input ByName {
+      first: String!
+      last: String!
+    }
+
+    type Query {
+      search(by: SearchBy!): String!
+    }
+
+    "Search users"
+    input SearchBy @oneOf {
+      id: ID
+      email: String
+      fullName: ByName
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            id: search(by: { id: "1" })
+            email: search(by: { email: "foo@example.com" })
+            name: search(by: { fullName: { first: "foo", last: "bar" } })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "id": "id: 1",
+            "email": "email: foo@example.com",
+            "name": "name: foo bar",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_fields() {
+    #[derive(InputObject)]
+    enum SearchBy {
+        Id(ID),
+        Email(String),
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn search(&self, by: SearchBy) -> String {
+            match by {
+                SearchBy::Id(id) => format!("id: {}", id.0),
+                SearchBy::Email(email) => format!("email: {}", email),
+            }
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query($by: SearchBy!) {
+            search(by: $by)
+        }
+    "#;
+
+    let variables = serde_json::json!({ "by": { "id": "1", "email": "foo@example.com" } });
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(variables))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "by", Oneof input objects requires have exactly one field"#,
+    );
+
+    let variables = serde_json::json!({ "by": {} });
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(variables))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+}
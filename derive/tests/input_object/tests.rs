@@ -511,3 +511,167 @@ mod in_mod {
         assert_eq!(data, serde_json::json!({ "example": "hello" }));
     }
 }
+
+#[tokio::test]
+async fn test_default_values() {
+    fn default_name() -> String {
+        "anonymous".to_string()
+    }
+
+    #[derive(InputObject)]
+    struct ExampleInput {
+        #[graphql(default = "anonymous")]
+        pub name: String,
+        #[graphql(default)]
+        pub age: Option<i32>,
+        #[graphql(default = 1)]
+        pub count: i32,
+        #[graphql(default_with = "default_name()")]
+        pub nickname: String,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn example(&self, input: ExampleInput) -> String {
+            format!(
+                "{} {:?} {} {}",
+                input.name, input.age, input.count, input.nickname
+            )
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    input ExampleInput {
+      name: String! = "anonymous"
+      age: Int
+      count: Int! = 1
+      nickname: String! = "anonymous"
+    }
+
+    type Query {
+      example(input: ExampleInput!): String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            defaults: example(input: {})
+            custom: example(input: { name: "foo", age: 3, count: 2, nickname: "bar" })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "defaults": "anonymous None 1 anonymous",
+            "custom": "foo Some(3) 2 bar",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_input_object_default_values() {
+    use std::collections::BTreeSet;
+    use std::sync::Arc;
+
+    #[derive(InputObject)]
+    struct Page {
+        size: i32,
+        #[graphql(skip)]
+        #[allow(dead_code)]
+        cursor: Option<String>,
+    }
+
+    #[derive(InputObject)]
+    enum Order {
+        Asc(String),
+        Desc(String),
+    }
+
+    fn default_page() -> Box<Page> {
+        Box::new(Page {
+            size: 10,
+            cursor: None,
+        })
+    }
+
+    fn default_order() -> Arc<Order> {
+        Arc::new(Order::Desc("createdAt".to_string()))
+    }
+
+    fn default_tags() -> BTreeSet<String> {
+        BTreeSet::from(["a".to_string(), "b".to_string()])
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn search(
+            &self,
+            #[graphql(default_with = "default_page()")] page: Box<Page>,
+            #[graphql(default_with = "default_order()")] order: Arc<Order>,
+            #[graphql(default_with = "default_tags()")] tags: BTreeSet<String>,
+        ) -> String {
+            let order = match &*order {
+                Order::Asc(field) => format!("{} asc", field),
+                Order::Desc(field) => format!("{} desc", field),
+            };
+            format!("{} {} {:?}", page.size, order, tags)
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    input Order @oneOf {
+      asc: String
+      desc: String
+    }
+
+    input Page {
+      size: Int!
+    }
+
+    type Query {
+      search(page: Page! = {size: 10}, order: Order! = {desc: "createdAt"}, tags: [String!]! = ["a", "b"]): String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let req = dynamic_graphql::Request::new("{ search }").root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({ "search": "10 createdAt desc {\"a\", \"b\"}" })
+    );
+}
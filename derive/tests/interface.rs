@@ -3,6 +3,7 @@ mod interface {
     mod as_value_tests;
     mod async_test;
     mod implementation_tests;
+    mod implements_interface_tests;
     mod interface_tests;
     mod list_tests;
     mod output_types_tests;
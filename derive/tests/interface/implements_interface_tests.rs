@@ -0,0 +1,172 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+
+use crate::schema_utils::normalize_schema;
+
+#[Interface]
+trait Node {
+    fn id(&self) -> String;
+}
+
+#[Interface]
+#[graphql(implements(Node))]
+trait Resource: Node {
+    fn id(&self) -> String;
+    fn url(&self) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Resource))]
+struct File {
+    name: String,
+}
+
+impl Node for File {
+    fn id(&self) -> String {
+        format!("file:{}", self.name)
+    }
+}
+
+impl Resource for File {
+    fn id(&self) -> String {
+        Node::id(self)
+    }
+    fn url(&self) -> String {
+        format!("/files/{}", self.name)
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn resource(&self) -> Instance<dyn Resource> {
+        Instance::new_owned(File {
+            name: "a".to_string(),
+        })
+    }
+
+    fn node(&self) -> Instance<dyn Node> {
+        Instance::<dyn Resource>::new_owned(File {
+            name: "b".to_string(),
+        })
+        .upcast()
+    }
+}
+
+#[derive(App)]
+struct App(Query, File);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+    // the sdl of async-graphql doesn't render the parents of interfaces
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type File implements Resource & Node {
+      name: String!
+      id: String!
+      url: String!
+    }
+
+    interface Node {
+      id: String!
+    }
+
+    type Query {
+      resource: Resource!
+      node: Node!
+    }
+
+    interface Resource {
+      id: String!
+      url: String!
+    }
+
+    schema {
+      query: Query
+    }
+"###);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let query = r#"
+        query {
+            resource {
+                id
+                url
+            }
+            node {
+                id
+                ... on Resource {
+                    url
+                }
+                ... on File {
+                    name
+                }
+            }
+        }
+    "#;
+    let schema = App::create_schema().finish().unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "resource": { "id": "file:a", "url": "/files/a" },
+            "node": { "id": "file:b", "url": "/files/b", "name": "b" },
+        })
+    );
+}
+
+#[test]
+fn test_schema_with_missing_parent_field() {
+    #[Interface]
+    trait Named {
+        fn name(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(implements(Named))]
+    trait Titled {
+        fn title(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(implements(Titled))]
+    struct Book {
+        name: String,
+    }
+
+    impl Titled for Book {
+        fn title(&self) -> String {
+            self.name.clone()
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        book: Book,
+    }
+
+    #[derive(App)]
+    struct App(Query, Book);
+
+    let schema = App::create_schema().finish();
+    assert_eq!(
+        schema.err().unwrap().to_string(),
+        r#"Interface "Titled" requires field "name" defined by interface "Named""#
+    );
+}
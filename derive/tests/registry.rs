@@ -10,6 +10,9 @@ use dynamic_graphql::internal::TypeName;
 use dynamic_graphql::value;
 use dynamic_graphql::App;
 use dynamic_graphql::Context;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::RegistryIssue;
 use dynamic_graphql::SimpleObject;
 use std::borrow::Cow;
 
@@ -208,3 +211,103 @@ async fn define_custom_type() {
     let res = schema.execute(req).await;
     assert_eq!(res.data, value!({ "foo": { "value": "the foo" } }));
 }
+
+#[test]
+fn test_try_create_schema() {
+    #[derive(SimpleObject)]
+    struct Foo {
+        value: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct Bar {
+        value: String,
+    }
+
+    // registers another type named Foo
+    struct OtherFoo;
+
+    impl Register for OtherFoo {
+        fn register(registry: Registry) -> Registry {
+            let object = dynamic::Object::new("Foo");
+            let object = object.field(dynamic::Field::new(
+                "other",
+                dynamic::TypeRef::named("String"),
+                |_ctx| dynamic::FieldFuture::new(async move { Ok(None::<dynamic::FieldValue>) }),
+            ));
+            registry
+                .register_type(object)
+                .declare_implement("Foo", "Node")
+                .register_type(dynamic::Union::new("Search").possible_type("Baz"))
+                .declare_possible_type("Search", "Baz")
+        }
+    }
+
+    #[derive(ExpandObject)]
+    struct FooExtra<'a>(&'a Foo);
+
+    #[ExpandObjectFields]
+    impl FooExtra<'_> {
+        fn extra(&self) -> String {
+            "extra".to_string()
+        }
+    }
+
+    #[derive(ExpandObject)]
+    struct FooMore<'a>(&'a Foo);
+
+    #[ExpandObjectFields]
+    impl FooMore<'_> {
+        fn extra(&self) -> String {
+            "more".to_string()
+        }
+    }
+
+    #[derive(ExpandObject)]
+    struct BarExtra<'a>(&'a Bar);
+
+    #[ExpandObjectFields]
+    impl BarExtra<'_> {
+        fn extra(&self) -> String {
+            "extra".to_string()
+        }
+    }
+
+    #[derive(App)]
+    struct App(
+        Foo,
+        OtherFoo,
+        FooExtra<'static>,
+        FooMore<'static>,
+        BarExtra<'static>,
+    );
+
+    let Err(err) = App::try_create_schema() else {
+        panic!("the schema is invalid");
+    };
+    assert_eq!(err.issues.len(), 6);
+    assert_eq!(err.issues[0], RegistryIssue::MissingRoot);
+    insta::assert_snapshot!(err.to_string(), @r###"
+    Invalid schema:
+    - No root object defined
+    - Can't find object: "Bar when defining BarExtra" (registered by registry::test_try_create_schema::BarExtra<'_>)
+    - Type "Foo" is registered by different types: registry::test_try_create_schema::Foo, registry::test_try_create_schema::OtherFoo
+    - Field "Foo.extra" is added by different types: registry::test_try_create_schema::FooExtra<'_>, registry::test_try_create_schema::FooMore<'_>
+    - Type "Foo" implements the unknown interface "Node" (registered by registry::test_try_create_schema::OtherFoo)
+    - Union "Search" has the unknown possible type "Baz" (registered by registry::test_try_create_schema::OtherFoo)
+    "###);
+}
+
+#[test]
+#[should_panic(expected = "No root object defined")]
+fn test_create_schema_panics() {
+    #[derive(SimpleObject)]
+    struct Foo {
+        value: String,
+    }
+
+    #[derive(App)]
+    struct App(Foo);
+
+    App::create_schema();
+}
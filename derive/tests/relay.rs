@@ -0,0 +1,4 @@
+mod schema_utils;
+mod relay {
+    mod relay_tests;
+}
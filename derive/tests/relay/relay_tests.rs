@@ -0,0 +1,268 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::relay::GlobalId;
+use dynamic_graphql::relay::Node;
+use dynamic_graphql::value;
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Result;
+use dynamic_graphql::SimpleObject;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+#[graphql(node(fetch = "fetch_user"))]
+struct User {
+    #[graphql(skip)]
+    id: String,
+    name: String,
+}
+
+impl Node for User {
+    fn node_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+async fn fetch_user(_ctx: &Context<'_>, id: String) -> Result<Option<User>> {
+    if id == "404" {
+        return Ok(None);
+    }
+    Ok(Some(User {
+        name: format!("user {}", id),
+        id,
+    }))
+}
+
+#[derive(ResolvedObject)]
+#[graphql(node(fetch = "Post::fetch"))]
+struct Post {
+    id: u32,
+}
+
+impl Post {
+    async fn fetch(_ctx: &Context<'_>, id: String) -> Result<Option<Post>> {
+        Ok(id.parse().ok().map(|id| Post { id }))
+    }
+}
+
+#[ResolvedObjectFields]
+impl Post {
+    fn title(&self) -> String {
+        format!("post {}", self.id)
+    }
+}
+
+impl Node for Post {
+    fn node_id(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn user(&self) -> User {
+        User {
+            id: "1".to_string(),
+            name: "user 1".to_string(),
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query, User, Post);
+
+#[test]
+fn test_global_id() {
+    let id = GlobalId::new("User", "1").encode();
+    assert_eq!(id.as_str(), "VXNlcjox");
+    assert_eq!(GlobalId::decode(&id), Some(GlobalId::new("User", "1")));
+    assert_eq!(
+        GlobalId::decode(&GlobalId::new("User", "a:b").encode()),
+        Some(GlobalId::new("User", "a:b"))
+    );
+    assert_eq!(GlobalId::decode("not base64!"), None);
+    assert_eq!(GlobalId::decode("VXNlcg=="), None);
+}
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    "An object with a global id"
+    interface Node {
+      "The global id of the object"
+      id: ID!
+    }
+
+    type Post implements Node {
+      title: String!
+      "The global id of the object"
+      id: ID!
+    }
+
+    type Query {
+      user: User!
+      node(id: ID!): Node
+      nodes(ids: [ID!]!): [Node]!
+    }
+
+    type User implements Node {
+      name: String!
+      "The global id of the object"
+      id: ID!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+#[tokio::test]
+async fn test_query_id() {
+    let schema = App::create_schema().finish().unwrap();
+    let query = r#"
+        query {
+            user {
+                id
+                name
+            }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({ "user": { "id": "VXNlcjox", "name": "user 1" } })
+    );
+}
+
+#[tokio::test]
+async fn test_query_node() {
+    let schema = App::create_schema().finish().unwrap();
+    let query = r#"
+        query($user: ID!, $post: ID!) {
+            user: node(id: $user) {
+                id
+                ... on User {
+                    name
+                }
+            }
+            post: node(id: $post) {
+                id
+                ... on Post {
+                    title
+                }
+            }
+        }
+    "#;
+    let variables = value!({
+        "user": GlobalId::new("User", "2").encode().0,
+        "post": GlobalId::new("Post", "3").encode().0,
+    });
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_value(variables))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "user": { "id": "VXNlcjoy", "name": "user 2" },
+            "post": { "id": "UG9zdDoz", "title": "post 3" },
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_query_nodes() {
+    let schema = App::create_schema().finish().unwrap();
+    let query = r#"
+        query($ids: [ID!]!) {
+            nodes(ids: $ids) {
+                id
+                __typename
+            }
+        }
+    "#;
+    let variables = value!({
+        "ids": [
+            GlobalId::new("User", "1").encode().0,
+            GlobalId::new("User", "404").encode().0,
+            GlobalId::new("Unknown", "1").encode().0,
+            GlobalId::new("Post", "1").encode().0,
+        ],
+    });
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_value(variables))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "nodes": [
+                { "id": "VXNlcjox", "__typename": "User" },
+                null,
+                null,
+                { "id": "UG9zdDox", "__typename": "Post" },
+            ]
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_query_invalid_id() {
+    let schema = App::create_schema().finish().unwrap();
+    let query = r#"
+        query {
+            node(id: "invalid") {
+                id
+            }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "invalid node id \"invalid\"");
+}
+
+#[tokio::test]
+async fn test_query_nodes_invalid_id() {
+    let schema = App::create_schema().finish().unwrap();
+    let query = r#"
+        query($ids: [ID!]!) {
+            nodes(ids: $ids) {
+                id
+            }
+        }
+    "#;
+    let variables = value!({
+        "ids": [GlobalId::new("User", "1").encode().0, "invalid"],
+    });
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_value(variables))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    // the invalid id doesn't fail the other nodes
+    assert_eq!(
+        data,
+        serde_json::json!({ "nodes": [{ "id": "VXNlcjox" }, null] })
+    );
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "invalid node id \"invalid\"");
+    assert_eq!(
+        serde_json::to_value(&res.errors[0].path).unwrap(),
+        serde_json::json!(["nodes", 1])
+    );
+}
@@ -1,8 +1,10 @@
 mod schema_utils;
 mod resolved_object {
     mod resolved_object_args_tests;
+    mod resolved_object_collection_tests;
     mod resolved_object_list_args_tests;
     mod resolved_object_list_tests;
+    mod resolved_object_pointer_tests;
     mod resolved_object_result_tests;
     mod resolved_object_tests;
     mod resolved_object_type_tests;
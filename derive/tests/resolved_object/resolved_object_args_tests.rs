@@ -673,3 +673,72 @@ async fn test_query_numbers() {
         r#"Invalid value for argument "name": Failed to parse "Int": Only integers from 0 to 255 are accepted for u8."#,
     );
 }
+
+#[tokio::test]
+async fn test_default_values() {
+    #[derive(dynamic_graphql::Enum, Clone, Copy, Default)]
+    enum Order {
+        #[default]
+        Asc,
+        Desc,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn items(
+            &self,
+            #[graphql(default)] offset: i32,
+            #[graphql(default = 10)] limit: i32,
+            #[graphql(default = "id")] sort_by: String,
+            #[graphql(default_with = "Order::Desc")] order: Order,
+            #[graphql(default)] tags: Vec<String>,
+        ) -> String {
+            let order = match order {
+                Order::Asc => "asc",
+                Order::Desc => "desc",
+            };
+            format!("{} {} {} {} {:?}", offset, limit, sort_by, order, tags)
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Order);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    enum Order {
+      ASC
+      DESC
+    }
+
+    type Query {
+      items(offset: Int! = 0, limit: Int! = 10, sortBy: String! = "id", order: Order! = DESC, tags: [String!]! = []): String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"{
+        defaults: items
+        custom: items(offset: 5, limit: 1, sortBy: "name", order: ASC, tags: ["a"])
+     }"#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "defaults": "0 10 id desc []",
+            "custom": "5 1 name asc [\"a\"]",
+        }),
+    );
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ListIter;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use indexmap::IndexSet;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_output() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query {
+        deque: VecDeque<String>,
+    }
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn deque(&self) -> &VecDeque<String> {
+            &self.deque
+        }
+        fn hash_set(&self) -> HashSet<String> {
+            HashSet::from(["a".to_string()])
+        }
+        fn btree_set(&self) -> BTreeSet<u32> {
+            BTreeSet::from([3, 1, 2])
+        }
+        fn index_set(&self) -> IndexSet<u32> {
+            IndexSet::from([3, 1, 2])
+        }
+        fn array(&self) -> [Option<u32>; 3] {
+            [Some(1), None, Some(3)]
+        }
+        fn squares(&self) -> ListIter<Box<dyn Iterator<Item = u32> + Send>> {
+            ListIter(Box::new((1..=3).map(|n| n * n)))
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      deque: [String!]!
+      hashSet: [String!]!
+      btreeSet: [Int!]!
+      indexSet: [Int!]!
+      array: [Int]!
+      squares: [Int!]!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            deque
+            hashSet
+            btreeSet
+            indexSet
+            array
+            squares
+        }
+    "#;
+    let root = Query {
+        deque: VecDeque::from(["a".to_string(), "b".to_string()]),
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "deque": ["a", "b"],
+            "hashSet": ["a"],
+            "btreeSet": [1, 2, 3],
+            "indexSet": [3, 1, 2],
+            "array": [1, null, 3],
+            "squares": [1, 4, 9],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_input() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn deque(names: VecDeque<String>) -> String {
+            names.into_iter().collect::<Vec<_>>().join(", ")
+        }
+        fn hash_set(names: HashSet<String>) -> usize {
+            names.len()
+        }
+        fn btree_set(names: BTreeSet<String>) -> String {
+            names.into_iter().collect::<Vec<_>>().join(", ")
+        }
+        fn index_set(names: IndexSet<String>) -> String {
+            names.into_iter().collect::<Vec<_>>().join(", ")
+        }
+        fn array(point: [u32; 2]) -> u32 {
+            point[0] + point[1]
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      deque(names: [String!]!): String!
+      hashSet(names: [String!]!): Int!
+      btreeSet(names: [String!]!): String!
+      indexSet(names: [String!]!): String!
+      array(point: [Int!]!): Int!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            deque(names: ["b", "a"])
+            hashSet(names: ["b", "a"])
+            btreeSet(names: ["b", "a"])
+            indexSet(names: ["b", "a"])
+            array(point: [1, 2])
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "deque": "b, a",
+            "hashSet": 2,
+            "btreeSet": "a, b",
+            "indexSet": "b, a",
+            "array": 3,
+        })
+    );
+
+    let query = r#"
+        query {
+            hashSet(names: ["a", "a"])
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "names": Failed to parse "[String!]": duplicated items are not allowed"#
+    );
+
+    let query = r#"
+        query {
+            array(point: [1, 2, 3])
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "point": Failed to parse "[Int!]": expected a list of 2 items, found 3"#
+    );
+}
@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_box_and_arc() {
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query {
+        user: Arc<User>,
+    }
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn shared_user(&self) -> Arc<User> {
+            self.user.clone()
+        }
+        fn user_ref(&self) -> &Arc<User> {
+            &self.user
+        }
+        fn shared_name(&self) -> Arc<String> {
+            Arc::new(self.user.name.clone())
+        }
+        fn shared_users(&self) -> Arc<Vec<User>> {
+            let names = ["a", "b"];
+            Arc::new(
+                names
+                    .iter()
+                    .map(|name| User {
+                        name: name.to_string(),
+                    })
+                    .collect(),
+            )
+        }
+        fn shared_missing_user(&self) -> Arc<Option<User>> {
+            Arc::new(None)
+        }
+        fn boxed_user(name: Arc<String>) -> Box<User> {
+            Box::new(User {
+                name: name.to_string(),
+            })
+        }
+        #[allow(clippy::boxed_local)]
+        fn users(names: Box<[Arc<String>]>) -> Box<[User]> {
+            names
+                .iter()
+                .map(|name| User {
+                    name: name.to_string(),
+                })
+                .collect()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      sharedUser: User!
+      userRef: User!
+      sharedName: String!
+      sharedUsers: [User!]!
+      sharedMissingUser: User
+      boxedUser(name: String!): User!
+      users(names: [String!]!): [User!]!
+    }
+
+    type User {
+      name: String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            sharedUser { name }
+            userRef { name }
+            sharedName
+            sharedUsers { name }
+            sharedMissingUser { name }
+            boxedUser(name: "bar") { name }
+            users(names: ["a", "b"]) { name }
+        }
+    "#;
+    let root = Query {
+        user: Arc::new(User {
+            name: "foo".to_string(),
+        }),
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "sharedUser": { "name": "foo" },
+            "userRef": { "name": "foo" },
+            "sharedName": "foo",
+            "sharedUsers": [{ "name": "a" }, { "name": "b" }],
+            "sharedMissingUser": null,
+            "boxedUser": { "name": "bar" },
+            "users": [{ "name": "a" }, { "name": "b" }],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_recursive_input() {
+    #[derive(InputObject)]
+    struct Filter {
+        name: String,
+        or: Option<Box<Filter>>,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn names(filter: Filter) -> Vec<String> {
+            let mut names = vec![filter.name];
+            let mut or = filter.or;
+            while let Some(filter) = or {
+                names.push(filter.name);
+                or = filter.or;
+            }
+            names
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    input Filter {
+      name: String!
+      or: Filter
+    }
+
+    type Query {
+      names(filter: Filter!): [String!]!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            names(filter: { name: "a", or: { name: "b", or: { name: "c" } } })
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(data, serde_json::json!({ "names": ["a", "b", "c"] }));
+}
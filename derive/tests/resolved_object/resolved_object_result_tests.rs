@@ -237,3 +237,73 @@ async fn test_ref_object_query() {
     assert_eq!(res.errors.len(), 1);
     assert_eq!(res.errors[0].message, "Not found");
 }
+
+#[tokio::test]
+async fn test_list_item_errors() {
+    fn item(index: usize) -> Result<String, MyError> {
+        if index == 1 {
+            Err(MyError::NotFound)
+        } else {
+            Ok(format!("foo {}", index))
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn maybe_foos(&self) -> Vec<Option<Result<String, MyError>>> {
+            (0..3).map(|index| Some(item(index))).collect()
+        }
+        async fn foos(&self) -> Option<Vec<Result<String, MyError>>> {
+            Some((0..3).map(item).collect())
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      maybeFoos: [String]!
+      foos: [String!]
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            maybeFoos
+            foos
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "maybeFoos": ["foo 0", null, "foo 2"],
+        })
+    );
+    let errors = res
+        .errors
+        .iter()
+        .map(|error| (error.message.as_str(), format!("{:?}", error.path)))
+        .collect::<Vec<_>>();
+    // the error of the non-null item fails the whole list
+    assert_eq!(
+        errors,
+        vec![
+            ("Not found", "[Field(\"maybeFoos\"), Index(1)]".to_string()),
+            ("Not found", "[]".to_string()),
+        ]
+    );
+}
@@ -1,9 +1,12 @@
 mod schema_utils;
 mod scalar {
     mod common;
+    mod external_tests;
     mod input_type_tests;
     mod json;
+    mod json_feature_tests;
     mod json_tests;
     mod output_type_tests;
+    mod primitive_tests;
     mod scalar_tests;
 }
@@ -0,0 +1,193 @@
+use dynamic_graphql::App;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_external_scalars() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn chrono_date_time(
+            value: chrono::DateTime<chrono::Utc>,
+        ) -> chrono::DateTime<chrono::Utc> {
+            value
+        }
+        async fn naive_date(value: chrono::NaiveDate) -> chrono::NaiveDate {
+            value
+        }
+        async fn naive_time(value: chrono::NaiveTime) -> chrono::NaiveTime {
+            value
+        }
+        async fn naive_date_time(value: chrono::NaiveDateTime) -> chrono::NaiveDateTime {
+            value
+        }
+        async fn offset_date_time(value: time::OffsetDateTime) -> time::OffsetDateTime {
+            value
+        }
+        async fn date(value: time::Date) -> time::Date {
+            value
+        }
+        async fn primitive_date_time(value: time::PrimitiveDateTime) -> time::PrimitiveDateTime {
+            value
+        }
+        async fn uuid(value: uuid::Uuid) -> uuid::Uuid {
+            value
+        }
+        async fn url(value: url::Url) -> url::Url {
+            value
+        }
+        async fn decimal(value: rust_decimal::Decimal) -> rust_decimal::Decimal {
+            value
+        }
+        async fn big_decimal(value: bigdecimal::BigDecimal) -> bigdecimal::BigDecimal {
+            value
+        }
+        async fn object_id(value: bson::oid::ObjectId) -> bson::oid::ObjectId {
+            value
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    scalar BigDecimal
+
+    scalar Date
+
+    scalar DateTime
+
+    scalar Decimal
+
+    scalar NaiveDate
+
+    scalar NaiveDateTime
+
+    scalar NaiveTime
+
+    scalar ObjectId
+
+    scalar PrimitiveDateTime
+
+    type Query {
+      chronoDateTime(value: DateTime!): DateTime!
+      naiveDate(value: NaiveDate!): NaiveDate!
+      naiveTime(value: NaiveTime!): NaiveTime!
+      naiveDateTime(value: NaiveDateTime!): NaiveDateTime!
+      offsetDateTime(value: DateTime!): DateTime!
+      date(value: Date!): Date!
+      primitiveDateTime(value: PrimitiveDateTime!): PrimitiveDateTime!
+      uuid(value: UUID!): UUID!
+      url(value: Url!): Url!
+      decimal(value: Decimal!): Decimal!
+      bigDecimal(value: BigDecimal!): BigDecimal!
+      objectId(value: ObjectId!): ObjectId!
+    }
+
+    scalar UUID
+
+    scalar Url
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            chronoDateTime(value: "2023-01-02T03:04:05+01:00")
+            naiveDate(value: "2023-01-02")
+            naiveTime(value: "03:04:05.5")
+            naiveDateTime(value: "2023-01-02T03:04:05")
+            offsetDateTime(value: "2023-01-02T03:04:05+01:00")
+            date(value: "2023-01-02")
+            primitiveDateTime(value: "2023-01-02T03:04:05.5")
+            withoutSubsecond: primitiveDateTime(value: "2023-01-02T03:04:05")
+            uuid(value: "67e55044-10b1-426f-9247-bb680e5fe0c8")
+            url(value: "https://example.com/path")
+            decimal(value: "1.50")
+            bigDecimal(value: "123456789012345678901234567890.5")
+            objectId(value: "507f1f77bcf86cd799439011")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query);
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors, vec![]);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "chronoDateTime": "2023-01-02T02:04:05Z",
+            "naiveDate": "2023-01-02",
+            "naiveTime": "03:04:05.500",
+            "naiveDateTime": "2023-01-02T03:04:05",
+            "offsetDateTime": "2023-01-02T03:04:05+01:00",
+            "date": "2023-01-02",
+            "primitiveDateTime": "2023-01-02T03:04:05.5",
+            "withoutSubsecond": "2023-01-02T03:04:05.0",
+            "uuid": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "url": "https://example.com/path",
+            "decimal": "1.50",
+            "bigDecimal": "123456789012345678901234567890.5",
+            "objectId": "507f1f77bcf86cd799439011",
+        })
+    );
+
+    let query = r#"
+        query {
+            uuid(value: "foo")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query);
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "value": Failed to parse "UUID": invalid character: found `o` at 1"#
+    );
+}
+
+#[tokio::test]
+async fn test_external_scalar_format_error() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn offset_date_time() -> Option<time::OffsetDateTime> {
+            // rfc3339 has no seconds in the offset
+            let offset = time::UtcOffset::from_hms(1, 0, 30).unwrap();
+            Some(time::OffsetDateTime::UNIX_EPOCH.to_offset(offset))
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            offsetDateTime
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query);
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        "The offset_second component cannot be formatted into the requested format."
+    );
+}
@@ -422,3 +422,63 @@ async fn test_query_option() {
     let data = res.data.into_json().unwrap();
     assert_eq!(data, serde_json::json!({ "value": "None" }));
 }
+
+#[tokio::test]
+async fn test_query_validator_with_error() {
+    use dynamic_graphql::ErrorExtensions;
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    fn validate_foo(value: &Value) -> dynamic_graphql::Result<()> {
+        match value {
+            Value::String(s) if s.len() <= 5 => Ok(()),
+            Value::String(s) => Err(dynamic_graphql::Error::new(format!("{} is too long", s))
+                .extend_with(|_, e| e.set("code", "TOO_LONG"))),
+            _ => Err("expected a string".into()),
+        }
+    }
+
+    #[derive(Scalar)]
+    #[graphql(validator(validate_foo))]
+    struct Foo(String);
+
+    impl ScalarValue for Foo {
+        fn from_value(value: Value) -> dynamic_graphql::Result<Self>
+        where
+            Self: Sized,
+        {
+            StringValue::try_from(value).map(|v| Foo(v.0))
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn value(value: Foo) -> String {
+            value.0
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let res = schema.execute(r#"{ value(value: "12345") }"#).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data, serde_json::json!({ "value": "12345" }));
+
+    let res = schema.execute(r#"{ value(value: "invalid") }"#).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "value": Failed to parse "Foo": invalid is too long"#,
+    );
+    let extensions = res.errors[0].extensions.as_ref().unwrap();
+    assert_eq!(extensions.get("code"), Some(&Value::from("TOO_LONG")));
+}
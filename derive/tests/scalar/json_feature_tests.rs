@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use dynamic_graphql::App;
+use dynamic_graphql::Json;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(Serialize, Deserialize)]
+struct Settings {
+    theme: String,
+    size: u32,
+}
+
+#[tokio::test]
+async fn test_json() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn settings(settings: Json<Settings>) -> Json<Settings> {
+            Json(Settings {
+                theme: settings.theme.to_uppercase(),
+                size: settings.size + 1,
+            })
+        }
+        async fn value(value: serde_json::Value) -> serde_json::Value {
+            value
+        }
+        async fn counts(names: Vec<String>) -> HashMap<String, usize> {
+            names
+                .into_iter()
+                .map(|name| (name.clone(), name.len()))
+                .collect()
+        }
+        async fn sorted(values: BTreeMap<String, bool>) -> Vec<String> {
+            values.into_keys().collect()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    "A JSON value"
+    scalar JSON
+
+    type Query {
+      settings(settings: JSON!): JSON!
+      value(value: JSON!): JSON!
+      counts(names: [String!]!): JSON!
+      sorted(values: JSON!): [String!]!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            settings(settings: { theme: "dark", size: 2 })
+            value(value: { foo: [1, "bar", null] })
+            counts(names: ["a", "bb"])
+            sorted(values: { b: true, a: false })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query);
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "settings": { "theme": "DARK", "size": 3 },
+            "value": { "foo": [1, "bar", null] },
+            "counts": { "a": 1, "bb": 2 },
+            "sorted": ["a", "b"],
+        })
+    );
+
+    let query = r#"
+        query {
+            settings(settings: { theme: "dark" })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query);
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "settings": Failed to parse "JSON": missing field `size`"#
+    );
+}
@@ -0,0 +1,193 @@
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::Registry;
+use dynamic_graphql::App;
+use dynamic_graphql::BigInt;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::IntOverflowPolicy;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn negative(value: i32) -> i32 {
+        value
+    }
+    fn i128(value: i128) -> i128 {
+        value
+    }
+    fn u128(value: u128) -> u128 {
+        value
+    }
+    fn big_u64(value: BigInt<u64>) -> BigInt<u64> {
+        value
+    }
+    fn char(value: char) -> char {
+        value
+    }
+    fn non_zero(value: NonZeroU32) -> NonZeroU32 {
+        value
+    }
+    fn duration(value: Duration) -> Duration {
+        value
+    }
+    fn large(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+#[derive(App)]
+struct PrimitiveApp(Query);
+
+#[tokio::test]
+async fn test_schema() {
+    let schema = PrimitiveApp::create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    "An integer that may not fit in `Int`, represented as a string"
+    scalar BigInt
+
+    "An ISO 8601 duration, like `P1DT2H3M4.5S`"
+    scalar Duration
+
+    type Query {
+      negative(value: Int!): Int!
+      i128(value: BigInt!): BigInt!
+      u128(value: BigInt!): BigInt!
+      bigU64(value: BigInt!): BigInt!
+      char(value: String!): String!
+      nonZero(value: Int!): Int!
+      duration(value: Duration!): Duration!
+      large: Int!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+#[tokio::test]
+async fn test_values() {
+    let schema = PrimitiveApp::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            negative(value: -42)
+            i128(value: "-170141183460469231731687303715884105728")
+            u128(value: 12)
+            bigU64(value: "18446744073709551615")
+            char(value: "a")
+            nonZero(value: 3)
+            duration(value: "P1DT2H3M4.5S")
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors, vec![]);
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "negative": -42,
+            "i128": "-170141183460469231731687303715884105728",
+            "u128": "12",
+            "bigU64": "18446744073709551615",
+            "char": "a",
+            "nonZero": 3,
+            "duration": "P1DT2H3M4.5S",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_values() {
+    let schema = PrimitiveApp::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            char(value: "ab")
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "value": Failed to parse "String": expected a single character"#
+    );
+
+    let query = r#"
+        query {
+            nonZero(value: 0)
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "value": Failed to parse "Int": zero is not allowed"#
+    );
+
+    let query = r#"
+        query {
+            duration(value: "P1M")
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "value": Failed to parse "Duration": years and months are not supported"#
+    );
+}
+
+#[tokio::test]
+async fn test_int_overflow_policy() {
+    let query = r#"
+        query {
+            large
+        }
+    "#;
+
+    let schema = PrimitiveApp::create_schema().finish().unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data, serde_json::json!({ "large": u64::MAX }));
+
+    let schema = Registry::new()
+        .register::<PrimitiveApp>()
+        .int_overflow_policy(IntOverflowPolicy::Clamp)
+        .create_schema()
+        .finish()
+        .unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data, serde_json::json!({ "large": i32::MAX }));
+
+    let schema = Registry::new()
+        .register::<PrimitiveApp>()
+        .int_overflow_policy(IntOverflowPolicy::Error)
+        .create_schema()
+        .finish()
+        .unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Int cannot represent non 32-bit signed integer value: 18446744073709551615"#
+    );
+}
@@ -1,10 +1,21 @@
+use graphql_parser::schema::Definition;
+
+/// directives defined by the graphql spec, async-graphql 7.2 prints their definitions in the sdl
+/// of every schema, the schema snapshots don't list them
+const BUILTIN_DIRECTIVES: &[&str] = &["include", "skip", "deprecated", "specifiedBy", "oneOf"];
+
 pub fn normalize_schema(sdl: &str) -> String {
-    format!(
-        "{}",
-        graphql_parser::schema::parse_schema::<String>(sdl)
-            .unwrap()
-            .to_owned()
-    )
+    let mut document = graphql_parser::schema::parse_schema::<String>(sdl)
+        .unwrap()
+        .to_owned();
+    document.definitions.retain(|definition| {
+        !matches!(
+            definition,
+            Definition::DirectiveDefinition(directive)
+                if BUILTIN_DIRECTIVES.contains(&directive.name.as_str())
+        )
+    });
+    format!("{}", document)
 }
 
 #[cfg(test)]
@@ -36,4 +47,33 @@ mod tests {
         }
         "###);
     }
+
+    #[test]
+    fn test_normalize_schema_builtin_directives() {
+        let sdl = r#"
+            directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+            directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+            directive @oneOf on INPUT_OBJECT
+            directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+            directive @specifiedBy(url: String!) on SCALAR
+            directive @cached(ttl: Int!) on FIELD_DEFINITION
+
+            type Query {
+                hello: String! @cached(ttl: 10)
+            }
+
+            schema { query: Query }
+        "#;
+        insta::assert_snapshot!(normalize_schema(sdl), @r###"
+        directive @cached(ttl: Int!) on FIELD_DEFINITION
+
+        type Query {
+          hello: String! @cached(ttl: 10)
+        }
+
+        schema {
+          query: Query
+        }
+        "###);
+    }
 }
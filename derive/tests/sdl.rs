@@ -0,0 +1,4 @@
+mod schema_utils;
+mod sdl {
+    mod sdl_tests;
+}
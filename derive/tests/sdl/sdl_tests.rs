@@ -0,0 +1,336 @@
+use crate::schema_utils::normalize_schema;
+use dynamic_graphql::dynamic;
+use dynamic_graphql::internal::Registry;
+use dynamic_graphql::sdl::SdlIssue;
+use dynamic_graphql::sdl::SdlLoader;
+use dynamic_graphql::value;
+use dynamic_graphql::SimpleObject;
+
+const SDL: &str = r#"
+    "A user"
+    type User {
+      id: ID!
+      name: String!
+    }
+
+    type Query {
+      user(id: ID!): User
+      greeting(name: String = "world"): String!
+    }
+
+    extend type Query {
+      version: String @deprecated(reason: "not needed")
+    }
+"#;
+
+fn greeting(ctx: dynamic::ResolverContext) -> dynamic::FieldFuture {
+    dynamic::FieldFuture::new(async move {
+        let name = ctx.args.try_get("name")?.string()?.to_string();
+        Ok(Some(dynamic::FieldValue::value(format!("Hello {}", name))))
+    })
+}
+
+fn version(_ctx: dynamic::ResolverContext) -> dynamic::FieldFuture {
+    dynamic::FieldFuture::from_value(Some("0.1.0".into()))
+}
+
+#[tokio::test]
+async fn test_resolvers() {
+    let registry = SdlLoader::parse(SDL)
+        .unwrap()
+        .resolver("Query.user", |ctx| {
+            dynamic::FieldFuture::new(async move {
+                let id = ctx.args.try_get("id")?.string()?.to_string();
+                Ok(Some(dynamic::FieldValue::value(value!({
+                    "id": id,
+                    "name": "the user",
+                }))))
+            })
+        })
+        .resolver("User.id", |ctx| {
+            dynamic::FieldFuture::new(async move {
+                let parent = ctx.parent_value.as_value().unwrap();
+                let dynamic_graphql::Value::Object(user) = parent else {
+                    unreachable!()
+                };
+                Ok(Some(dynamic::FieldValue::value(user["id"].clone())))
+            })
+        })
+        .resolver("User.name", |ctx| {
+            dynamic::FieldFuture::new(async move {
+                let parent = ctx.parent_value.as_value().unwrap();
+                let dynamic_graphql::Value::Object(user) = parent else {
+                    unreachable!()
+                };
+                Ok(Some(dynamic::FieldValue::value(user["name"].clone())))
+            })
+        })
+        .resolver("Query.greeting", greeting)
+        .resolver("Query.version", version)
+        .load(Registry::new())
+        .unwrap();
+
+    let schema = registry.create_schema().finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      user(id: ID!): User
+      greeting(name: String = "world"): String!
+      version: String @deprecated(reason: "not needed")
+    }
+
+    "A user"
+    type User {
+      id: ID!
+      name: String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            user(id: "1") { id name }
+            greeting
+            other: greeting(name: "foo")
+            version
+        }
+    "#;
+    let res = schema.execute(query).await.into_result().unwrap();
+    assert_eq!(
+        res.data,
+        value!({
+            "user": { "id": "1", "name": "the user" },
+            "greeting": "Hello world",
+            "other": "Hello foo",
+            "version": "0.1.0",
+        })
+    );
+}
+
+#[test]
+fn test_missing_resolvers() {
+    let Err(err) = SdlLoader::parse(SDL)
+        .unwrap()
+        .resolver("Query.greeting", greeting)
+        .resolver("Query.hello", greeting)
+        .load(Registry::new())
+    else {
+        panic!("the resolvers are missing");
+    };
+    assert_eq!(
+        err.issues,
+        vec![
+            SdlIssue::MissingResolver {
+                type_name: "User".to_string(),
+                field_name: "id".to_string(),
+            },
+            SdlIssue::MissingResolver {
+                type_name: "User".to_string(),
+                field_name: "name".to_string(),
+            },
+            SdlIssue::MissingResolver {
+                type_name: "Query".to_string(),
+                field_name: "user".to_string(),
+            },
+            SdlIssue::MissingResolver {
+                type_name: "Query".to_string(),
+                field_name: "version".to_string(),
+            },
+            SdlIssue::UnknownResolver {
+                path: "Query.hello".to_string(),
+            },
+        ]
+    );
+    insta::assert_snapshot!(err.to_string(), @r###"
+    Invalid SDL schema:
+    - Field "User.id" has no resolver
+    - Field "User.name" has no resolver
+    - Field "Query.user" has no resolver
+    - Field "Query.version" has no resolver
+    - Resolver "Query.hello" is not bound to a declared field
+    "###);
+}
+
+#[test]
+fn test_invalid_sdl() {
+    let Err(err) = SdlLoader::parse("type Query { foo: String } type Query { bar: String }") else {
+        panic!("the type is defined twice");
+    };
+    assert_eq!(
+        err.issues,
+        vec![SdlIssue::Invalid(
+            "Type \"Query\" is defined more than once".to_string()
+        )]
+    );
+
+    let Err(err) =
+        SdlLoader::parse("type Query { foo: String } extend input Query { bar: String }")
+    else {
+        panic!("the extension is not an object");
+    };
+    assert_eq!(
+        err.issues,
+        vec![SdlIssue::Invalid(
+            "Type \"Query\" is extended by a different kind of type".to_string()
+        )]
+    );
+}
+
+#[tokio::test]
+async fn test_derived_type() {
+    #[derive(SimpleObject)]
+    struct User {
+        id: dynamic_graphql::ID,
+        name: String,
+    }
+
+    let registry = SdlLoader::parse(SDL)
+        .unwrap()
+        .derived::<User>()
+        .resolver("Query.user", |ctx| {
+            dynamic::FieldFuture::new(async move {
+                let id = ctx.args.try_get("id")?.string()?.to_string();
+                Ok(Some(dynamic::FieldValue::owned_any(User {
+                    id: id.into(),
+                    name: "the user".to_string(),
+                })))
+            })
+        })
+        .resolver("Query.greeting", greeting)
+        .resolver("Query.version", version)
+        .load(Registry::new())
+        .unwrap();
+
+    let schema = registry.create_schema().finish().unwrap();
+    let res = schema
+        .execute(r#"{ user(id: "1") { id name } }"#)
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(
+        res.data,
+        value!({ "user": { "id": "1", "name": "the user" } })
+    );
+}
+
+#[test]
+fn test_derived_type_mismatch() {
+    #[derive(SimpleObject)]
+    struct User {
+        id: String,
+        email: String,
+    }
+
+    let Err(err) = SdlLoader::parse(SDL)
+        .unwrap()
+        .derived::<User>()
+        .resolver("Query.user", version)
+        .resolver("Query.greeting", greeting)
+        .resolver("Query.version", version)
+        .resolver("User.name", version)
+        .load(Registry::new())
+    else {
+        panic!("the derived type doesn't match");
+    };
+    insta::assert_snapshot!(err.to_string(), @r###"
+    Invalid SDL schema:
+    - Resolver "User.name" is not bound to a declared field
+    - "User.email" of the derived type is "String!", it is not declared
+    - "User.id" is declared as "ID!", the derived type has "String!"
+    - "User.name" is declared as "String!", the derived type doesn't have it
+    "###);
+}
+
+#[test]
+fn test_directives() {
+    const SDL: &str = r#"
+        "Cache the value"
+        directive @cacheControl(maxAge: Int = 60) on OBJECT | FIELD_DEFINITION
+        directive @label(name: String!) repeatable on
+          | FIELD_DEFINITION
+
+        type Query @cacheControl {
+          answer: Int! @cacheControl(maxAge: 10)
+          find(filter: Filter): Int @label(name: "a") @label(name: "b")
+        }
+
+        input Filter @oneOf {
+          id: ID
+          name: String
+        }
+    "#;
+    let registry = SdlLoader::parse(SDL)
+        .unwrap()
+        .resolver("Query.answer", |_ctx| {
+            dynamic::FieldFuture::from_value(Some(42.into()))
+        })
+        .resolver("Query.find", |_ctx| dynamic::FieldFuture::from_value(None))
+        .load(Registry::new())
+        .unwrap();
+
+    let sdl = registry.create_sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    input Filter @oneOf {
+      id: ID
+      name: String
+    }
+
+    type Query @cacheControl {
+      answer: Int! @cacheControl(maxAge: 10)
+      find(filter: Filter): Int @label(name: "a") @label(name: "b")
+    }
+
+    schema {
+      query: Query
+    }
+
+    "Cache the value"
+    directive @cacheControl(maxAge: Int = 60) on OBJECT | FIELD_DEFINITION
+
+    directive @label(name: String!) repeatable on FIELD_DEFINITION
+    "###);
+}
+
+#[test]
+fn test_unsupported_directives() {
+    let Err(err) = SdlLoader::parse(
+        "directive @log on FIELD type Query { foo: String } schema @log { query: Query }",
+    ) else {
+        panic!("the directives are not supported");
+    };
+    assert_eq!(
+        err.issues,
+        vec![
+            SdlIssue::Invalid(
+                "Directive \"@log\" has executable locations, they are not supported".to_string()
+            ),
+            SdlIssue::Invalid("Directives of the schema definition are not supported".to_string()),
+        ]
+    );
+
+    let Err(err) =
+        SdlLoader::parse("type Query { foo: String } type Subscription { bar: String @live }")
+            .unwrap()
+            .resolver("Query.foo", |_ctx| dynamic::FieldFuture::from_value(None))
+            .subscription_resolver("Subscription.bar", |_ctx| {
+                dynamic::SubscriptionFieldFuture::new(async {
+                    Ok(futures_util::stream::empty::<
+                        dynamic_graphql::Result<dynamic::FieldValue>,
+                    >())
+                })
+            })
+            .load(Registry::new())
+    else {
+        panic!("the subscription directives are not supported");
+    };
+    assert_eq!(
+        err.issues,
+        vec![SdlIssue::Invalid(
+            "Directives of subscriptions are not supported, found one on \"Subscription.bar\""
+                .to_string()
+        )]
+    );
+}
@@ -2,6 +2,7 @@ mod schema_utils;
 mod simple_object {
     mod list_tests;
     mod object_tests;
+    mod pointer_tests;
     mod type_tests;
     mod with_generics_tests;
 }
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_box_and_arc_fields() {
+    #[derive(SimpleObject)]
+    struct Category {
+        name: String,
+        parent: Option<Box<Category>>,
+    }
+
+    #[derive(SimpleObject)]
+    struct User {
+        name: Arc<String>,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        category: Box<Category>,
+        user: Arc<User>,
+        tags: Box<[String]>,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Category {
+      name: String!
+      parent: Category
+    }
+
+    type Query {
+      category: Category!
+      user: User!
+      tags: [String!]!
+    }
+
+    type User {
+      name: String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+
+    let query = r#"
+        query {
+            category { name parent { name parent { name } } }
+            user { name }
+            tags
+        }
+    "#;
+    let root = Query {
+        category: Box::new(Category {
+            name: "child".to_string(),
+            parent: Some(Box::new(Category {
+                name: "parent".to_string(),
+                parent: None,
+            })),
+        }),
+        user: Arc::new(User {
+            name: Arc::new("foo".to_string()),
+        }),
+        tags: vec!["a".to_string(), "b".to_string()].into_boxed_slice(),
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "category": { "name": "child", "parent": { "name": "parent", "parent": null } },
+            "user": { "name": "foo" },
+            "tags": ["a", "b"],
+        })
+    );
+}
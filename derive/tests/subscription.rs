@@ -7,6 +7,11 @@ use futures_util::StreamExt;
 use crate::schema_utils::normalize_schema;
 
 mod schema_utils;
+mod subscription {
+    mod expand_subscription_tests;
+    mod subscription_tests;
+}
+
 #[tokio::test]
 async fn test_schema() {
     #[derive(SimpleObject)]
@@ -0,0 +1,161 @@
+use dynamic_graphql::dynamic;
+use dynamic_graphql::internal::Register;
+use dynamic_graphql::internal::Registry;
+use dynamic_graphql::value;
+use dynamic_graphql::App;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Subscription;
+use dynamic_graphql::SubscriptionFields;
+use dynamic_graphql::SubscriptionRoot;
+use futures_util::stream;
+use futures_util::Stream;
+use futures_util::StreamExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+#[graphql(root)]
+struct Query {
+    foo: String,
+}
+
+#[tokio::test]
+async fn test_multiple_expansions() {
+    #[derive(SubscriptionRoot)]
+    #[graphql(name = "Subscription")]
+    struct SubscriptionRoot;
+
+    #[derive(Subscription)]
+    struct ChatSubscription(SubscriptionRoot);
+
+    #[SubscriptionFields]
+    impl ChatSubscription {
+        async fn messages() -> impl Stream<Item = String> {
+            stream::iter(vec!["hello".to_string()])
+        }
+    }
+
+    #[derive(Subscription)]
+    struct CounterSubscription(SubscriptionRoot);
+
+    #[SubscriptionFields]
+    impl CounterSubscription {
+        async fn counter() -> impl Stream<Item = i32> {
+            stream::iter(1..=2)
+        }
+    }
+
+    #[derive(App)]
+    struct ChatApp(ChatSubscription);
+
+    #[derive(App)]
+    struct CounterApp(CounterSubscription);
+
+    // expansions are applied after all types are registered, so the root can be listed last
+    #[derive(App)]
+    struct App(Query, ChatApp, CounterApp, SubscriptionRoot);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      foo: String!
+    }
+
+    type Subscription {
+      messages: String!
+      counter: Int!
+    }
+
+    schema {
+      query: Query
+      subscription: Subscription
+    }
+    "###);
+
+    let stream = schema.execute_stream("subscription { counter }");
+    let items: Vec<_> = stream
+        .map(|res| res.into_result().unwrap().data)
+        .collect()
+        .await;
+    assert_eq!(
+        items,
+        vec![value!({ "counter": 1 }), value!({ "counter": 2 })]
+    );
+}
+
+#[test]
+fn test_manual_target() {
+    struct SubscriptionRoot;
+
+    impl Register for SubscriptionRoot {
+        fn register(registry: Registry) -> Registry {
+            let subscription = dynamic::Subscription::new("Subscription");
+            registry
+                .set_subscription("Subscription")
+                .register_type(subscription)
+        }
+    }
+
+    struct Counter;
+
+    impl Register for Counter {
+        fn register(registry: Registry) -> Registry {
+            registry.update_subscription("Subscription", "Counter", |subscription| {
+                subscription.field(dynamic::SubscriptionField::new(
+                    "counter",
+                    dynamic::TypeRef::named_nn(dynamic::TypeRef::INT),
+                    |_ctx| {
+                        dynamic::SubscriptionFieldFuture::new(async {
+                            Ok(stream::iter(vec![Ok(dynamic::FieldValue::value(1))]))
+                        })
+                    },
+                ))
+            })
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Counter, SubscriptionRoot);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      foo: String!
+    }
+
+    type Subscription {
+      counter: Int!
+    }
+
+    schema {
+      query: Query
+      subscription: Subscription
+    }
+    "###);
+}
+
+#[test]
+#[should_panic(expected = "Can't find object: \"SubscriptionRoot when defining ChatSubscription\"")]
+fn test_missing_target() {
+    #[derive(SubscriptionRoot)]
+    struct SubscriptionRoot;
+
+    #[derive(Subscription)]
+    struct ChatSubscription(SubscriptionRoot);
+
+    #[SubscriptionFields]
+    impl ChatSubscription {
+        async fn messages() -> impl Stream<Item = String> {
+            stream::iter(vec!["hello".to_string()])
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, ChatSubscription);
+
+    App::create_schema();
+}
@@ -0,0 +1,247 @@
+use dynamic_graphql::internal::ExpandSubscription;
+use dynamic_graphql::internal::Object;
+use dynamic_graphql::internal::ParentType;
+use dynamic_graphql::value;
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Subscription;
+use dynamic_graphql::SubscriptionFields;
+use dynamic_graphql::SubscriptionRoot;
+use futures_util::stream;
+use futures_util::Stream;
+use futures_util::StreamExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[test]
+fn test_subscription_root() {
+    #[derive(SubscriptionRoot)]
+    struct SubscriptionRoot;
+
+    assert_eq!(
+        <SubscriptionRoot as Object>::get_object_type_name(),
+        "SubscriptionRoot"
+    );
+}
+
+#[test]
+fn test_subscription_root_with_rename() {
+    #[derive(SubscriptionRoot)]
+    #[graphql(name = "Subscription")]
+    struct SubscriptionRoot;
+
+    assert_eq!(
+        <SubscriptionRoot as Object>::get_object_type_name(),
+        "Subscription"
+    );
+}
+
+#[test]
+fn test_subscription() {
+    #[derive(SubscriptionRoot)]
+    struct SubscriptionRoot;
+
+    #[derive(Subscription)]
+    struct MySubscription(SubscriptionRoot);
+
+    assert_eq!(
+        <MySubscription as ExpandSubscription>::get_expand_subscription_name(),
+        "MySubscription"
+    );
+    assert_eq!(
+        <<MySubscription as ParentType>::Type as Object>::get_object_type_name(),
+        "SubscriptionRoot"
+    );
+}
+
+#[tokio::test]
+async fn test_schema() {
+    /// The Root of all Subscriptions
+    #[derive(SubscriptionRoot)]
+    #[graphql(name = "Subscription")]
+    struct SubscriptionRoot;
+
+    #[derive(Subscription)]
+    struct MySubscription(SubscriptionRoot);
+
+    #[SubscriptionFields]
+    impl MySubscription {
+        /// count to the given number
+        async fn count_to(to: i32) -> impl Stream<Item = i32> {
+            stream::iter(1..=to)
+        }
+        #[graphql(deprecation)]
+        async fn old_count() -> impl Stream<Item = i32> {
+            stream::iter(0..0)
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, SubscriptionRoot, MySubscription);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Query {
+      foo: String!
+    }
+
+    "The Root of all Subscriptions"
+    type Subscription {
+      "count to the given number"
+      countTo(to: Int!): Int!
+      oldCount: Int! @deprecated
+    }
+
+    schema {
+      query: Query
+      subscription: Subscription
+    }
+    "###);
+
+    let stream = schema.execute_stream("subscription { countTo(to: 3) }");
+    let items: Vec<_> = stream
+        .map(|res| res.into_result().unwrap().data)
+        .collect()
+        .await;
+    assert_eq!(
+        items,
+        vec![
+            value!({ "countTo": 1 }),
+            value!({ "countTo": 2 }),
+            value!({ "countTo": 3 }),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_objects_and_result() {
+    #[derive(SubscriptionRoot)]
+    struct SubscriptionRoot;
+
+    #[derive(Subscription)]
+    struct MySubscription(SubscriptionRoot);
+
+    #[derive(SimpleObject)]
+    struct Message {
+        text: String,
+    }
+
+    struct Prefix(String);
+
+    #[SubscriptionFields]
+    #[graphql(rename_args = "UPPERCASE")]
+    impl MySubscription {
+        async fn messages(
+            ctx: &Context<'_>,
+            texts: Vec<String>,
+        ) -> dynamic_graphql::Result<impl Stream<Item = Message>> {
+            let prefix = ctx.data::<Prefix>()?.0.clone();
+            Ok(stream::iter(texts).map(move |text| Message {
+                text: format!("{}{}", prefix, text),
+            }))
+        }
+        #[graphql(name = "maybe")]
+        async fn optional_items() -> impl Stream<Item = Option<String>> {
+            stream::iter(vec![Some("a".to_string()), None])
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, SubscriptionRoot, MySubscription);
+
+    let schema = App::create_schema()
+        .data(Prefix("> ".to_string()))
+        .finish()
+        .unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Message {
+      text: String!
+    }
+
+    type Query {
+      foo: String!
+    }
+
+    type SubscriptionRoot {
+      messages(TEXTS: [String!]!): Message!
+      maybe: String
+    }
+
+    schema {
+      query: Query
+      subscription: SubscriptionRoot
+    }
+    "###);
+
+    let stream = schema.execute_stream(r#"subscription { messages(TEXTS: ["a", "b"]) { text } }"#);
+    let items: Vec<_> = stream
+        .map(|res| res.into_result().unwrap().data)
+        .collect()
+        .await;
+    assert_eq!(
+        items,
+        vec![
+            value!({ "messages": { "text": "> a" } }),
+            value!({ "messages": { "text": "> b" } }),
+        ]
+    );
+
+    let stream = schema.execute_stream("subscription { maybe }");
+    let items: Vec<_> = stream
+        .map(|res| res.into_result().unwrap().data)
+        .collect()
+        .await;
+    assert_eq!(
+        items,
+        vec![value!({ "maybe": "a" }), value!({ "maybe": null })]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_error() {
+    #[derive(SubscriptionRoot)]
+    struct SubscriptionRoot;
+
+    #[derive(Subscription)]
+    struct MySubscription(SubscriptionRoot);
+
+    #[SubscriptionFields]
+    impl MySubscription {
+        async fn failing() -> dynamic_graphql::Result<impl Stream<Item = i32>> {
+            Err::<stream::Empty<i32>, _>("not allowed".into())
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, SubscriptionRoot, MySubscription);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let mut stream = schema.execute_stream("subscription { failing }");
+    let res = stream.next().await.unwrap();
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "not allowed");
+}
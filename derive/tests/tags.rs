@@ -0,0 +1,4 @@
+mod schema_utils;
+mod tags {
+    mod tags_tests;
+}
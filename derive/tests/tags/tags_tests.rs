@@ -0,0 +1,373 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::App;
+use dynamic_graphql::Enum;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::Interface;
+use dynamic_graphql::RegistryIssue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::TagFilter;
+
+use crate::schema_utils::normalize_schema;
+
+#[Interface]
+trait Node {
+    fn id(&self) -> String;
+    #[graphql(tag("internal"))]
+    fn created_by(&self) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Node))]
+struct User {
+    #[graphql(skip)]
+    id: String,
+    name: String,
+    #[graphql(tag("admin"))]
+    email: String,
+    #[graphql(tag("internal"))]
+    password_hash: String,
+}
+
+impl Node for User {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    fn created_by(&self) -> String {
+        "system".to_string()
+    }
+}
+
+#[derive(ExpandObject)]
+struct UserSessions<'a>(&'a User);
+
+#[ExpandObjectFields]
+impl UserSessions<'_> {
+    #[graphql(tag("admin"))]
+    fn last_login(&self) -> String {
+        "yesterday".to_string()
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(tag("internal"))]
+struct AuditLog {
+    entries: Vec<String>,
+}
+
+#[derive(Enum)]
+enum Period {
+    Day,
+    Week,
+}
+
+#[derive(InputObject)]
+struct StatsFilter {
+    period: Period,
+}
+
+#[derive(SimpleObject)]
+struct Stats {
+    signups: i32,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    async fn users(&self) -> Vec<User> {
+        vec![user()]
+    }
+    #[graphql(tag("admin"))]
+    async fn user_by_email(&self, email: String) -> Option<User> {
+        Some(user()).filter(|user| user.email == email)
+    }
+    #[graphql(tag("internal"))]
+    async fn audit_log(&self) -> AuditLog {
+        AuditLog {
+            entries: vec!["created".to_string()],
+        }
+    }
+    #[graphql(tag("admin"))]
+    async fn stats(&self, filter: StatsFilter) -> Stats {
+        let signups = match filter.period {
+            Period::Day => 1,
+            Period::Week => 7,
+        };
+        Stats { signups }
+    }
+}
+
+#[derive(App)]
+struct App(Query, UserSessions<'static>);
+
+fn user() -> User {
+    User {
+        id: "1".to_string(),
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+        password_hash: "secret".to_string(),
+    }
+}
+
+fn public() -> TagFilter {
+    TagFilter::new().exclude("admin").exclude("internal")
+}
+
+fn admin() -> TagFilter {
+    TagFilter::new().exclude("internal")
+}
+
+#[test]
+fn test_public_schema() {
+    let schema = App::create_schema_for(&public()).finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    interface Node {
+      id: String!
+    }
+
+    type Query {
+      users: [User!]!
+    }
+
+    type User implements Node {
+      name: String!
+      id: String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+#[test]
+fn test_admin_schema() {
+    let schema = App::create_schema_for(&admin()).finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    interface Node {
+      id: String!
+    }
+
+    enum Period {
+      DAY
+      WEEK
+    }
+
+    type Query {
+      users: [User!]!
+      userByEmail(email: String!): User
+      stats(filter: StatsFilter!): Stats!
+    }
+
+    type Stats {
+      signups: Int!
+    }
+
+    input StatsFilter {
+      period: Period!
+    }
+
+    type User implements Node {
+      name: String!
+      id: String!
+      email: String!
+      lastLogin: String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+#[test]
+fn test_internal_schema() {
+    let schema = App::create_schema_for(&TagFilter::new()).finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type AuditLog {
+      entries: [String!]!
+    }
+
+    interface Node {
+      id: String!
+      createdBy: String!
+    }
+
+    enum Period {
+      DAY
+      WEEK
+    }
+
+    type Query {
+      users: [User!]!
+      userByEmail(email: String!): User
+      auditLog: AuditLog!
+      stats(filter: StatsFilter!): Stats!
+    }
+
+    type Stats {
+      signups: Int!
+    }
+
+    input StatsFilter {
+      period: Period!
+    }
+
+    type User implements Node {
+      name: String!
+      id: String!
+      createdBy: String!
+      email: String!
+      passwordHash: String!
+      lastLogin: String!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+#[test]
+fn test_included_tags() {
+    #[derive(SimpleObject)]
+    #[graphql(tag("public"))]
+    struct Product {
+        name: String,
+        #[graphql(tag("admin"))]
+        cost: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(tag("admin"))]
+    struct Supplier {
+        name: String,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        #[graphql(tag("public"))]
+        products: Vec<Product>,
+        #[graphql(tag("admin"))]
+        suppliers: Vec<Supplier>,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let filter = TagFilter::new().include("public");
+    let schema = App::create_schema_for(&filter).finish().unwrap();
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r###"
+    type Product {
+      name: String!
+    }
+
+    type Query {
+      products: [Product!]!
+    }
+
+    schema {
+      query: Query
+    }
+    "###);
+}
+
+#[tokio::test]
+async fn test_query_variant() {
+    let query = r#"
+        query {
+            users {
+                id
+                name
+                email
+                lastLogin
+            }
+        }
+    "#;
+
+    let schema = App::create_schema_for(&admin()).finish().unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "users": [{
+                "id": "1",
+                "name": "Alice",
+                "email": "alice@example.com",
+                "lastLogin": "yesterday",
+            }],
+        })
+    );
+
+    let schema = App::create_schema_for(&public()).finish().unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Unknown field "email" on type "User"."#
+    );
+}
+
+#[test]
+fn test_dangling_reference() {
+    #[derive(SimpleObject)]
+    #[graphql(tag("internal"))]
+    struct Report {
+        total: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        report: Report,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let filter = TagFilter::new().exclude("internal");
+    let Err(err) = App::try_create_schema_for(&filter) else {
+        panic!("the schema references a removed type");
+    };
+    assert_eq!(
+        err.issues,
+        vec![RegistryIssue::DanglingReference {
+            type_name: "Query".to_string(),
+            field_name: Some("report".to_string()),
+            reference: "Report".to_string(),
+        }]
+    );
+    insta::assert_snapshot!(err.to_string(), @r###"
+    Invalid schema:
+    - Field "Query.report" references the removed type "Report"
+    "###);
+}
+
+#[test]
+#[should_panic(expected = "The root object \"Query\" is removed by the tag filter")]
+fn test_excluded_root() {
+    #[derive(SimpleObject)]
+    #[graphql(root, tag("internal"))]
+    struct Query {
+        value: String,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    App::create_schema_for(&TagFilter::new().exclude("internal"));
+}
@@ -0,0 +1,4 @@
+mod schema_utils;
+mod validator {
+    mod validator_tests;
+}
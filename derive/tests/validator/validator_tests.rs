@@ -0,0 +1,181 @@
+use dynamic_graphql::App;
+use dynamic_graphql::ErrorExtensions;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::MaybeUndefined;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Value;
+
+fn is_even(value: &i32) -> dynamic_graphql::Result<()> {
+    if value % 2 == 0 {
+        Ok(())
+    } else {
+        Err(
+            dynamic_graphql::Error::new(format!("{} is not even", value))
+                .extend_with(|_, e| e.set("code", "NOT_EVEN")),
+        )
+    }
+}
+
+#[tokio::test]
+async fn test_arg_validators() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn name(#[graphql(validator(min_length = 3, max_length = 5))] name: String) -> String {
+            name
+        }
+        fn slug(#[graphql(validator(regex = "^[a-z-]+$"))] slug: &str) -> String {
+            slug.to_string()
+        }
+        fn range(#[graphql(validator(min = 1, max = 10))] value: i32) -> i32 {
+            value
+        }
+        fn even(#[graphql(validator(custom = "is_even"))] value: Option<i32>) -> Option<i32> {
+            value
+        }
+        fn maybe(#[graphql(validator(max = 10))] value: MaybeUndefined<i32>) -> i32 {
+            value.take().unwrap_or_default()
+        }
+        fn items(#[graphql(validator(max_length = 2))] items: Vec<String>) -> usize {
+            items.len()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"{ name(name: "abcd") slug(slug: "a-b") range(value: 1) even(value: 2) nullEven: even maybe(value: 10) items(items: ["a", "b"]) }"#;
+    let res = schema.execute(query).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "name": "abcd",
+            "slug": "a-b",
+            "range": 1,
+            "even": 2,
+            "nullEven": null,
+            "maybe": 10,
+            "items": 2,
+        })
+    );
+
+    let cases = [
+        (
+            r#"{ name(name: "ab") }"#,
+            r#"Invalid value for argument "name": the length is 2, must be greater than or equal to 3"#,
+        ),
+        (
+            r#"{ name(name: "abcdef") }"#,
+            r#"Invalid value for argument "name": the length is 6, must be less than or equal to 5"#,
+        ),
+        (
+            r#"{ slug(slug: "A B") }"#,
+            r#"Invalid value for argument "slug": the value does not match the pattern "^[a-z-]+$""#,
+        ),
+        (
+            r#"{ range(value: 0) }"#,
+            r#"Invalid value for argument "value": the value is 0, must be greater than or equal to 1"#,
+        ),
+        (
+            r#"{ range(value: 11) }"#,
+            r#"Invalid value for argument "value": the value is 11, must be less than or equal to 10"#,
+        ),
+        (
+            r#"{ even(value: 3) }"#,
+            r#"Invalid value for argument "value": 3 is not even"#,
+        ),
+        (
+            r#"{ maybe(value: 11) }"#,
+            r#"Invalid value for argument "value": the value is 11, must be less than or equal to 10"#,
+        ),
+        (
+            r#"{ items(items: ["a", "b", "c"]) }"#,
+            r#"Invalid value for argument "items": the length is 3, must be less than or equal to 2"#,
+        ),
+    ];
+    for (query, message) in cases {
+        let res = schema.execute(query).await;
+        assert_eq!(res.errors.len(), 1, "{}", query);
+        assert_eq!(res.errors[0].message, message);
+    }
+
+    let res = schema.execute("{ even(value: 3) }").await;
+    let extensions = res.errors[0].extensions.as_ref().unwrap();
+    assert_eq!(extensions.get("code"), Some(&Value::from("NOT_EVEN")));
+}
+
+#[tokio::test]
+async fn test_input_object_validators() {
+    #[derive(InputObject)]
+    struct UserInput {
+        #[graphql(validator(min_length = 1))]
+        name: String,
+        #[graphql(validator(min = 0, max = 150))]
+        age: Option<i32>,
+        #[graphql(default = 2, validator(custom = "is_even"))]
+        pairs: i32,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn user(input: UserInput) -> String {
+            format!("{} {:?} {}", input.name, input.age, input.pairs)
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let res = schema.execute(r#"{ user(input: { name: "foo" }) }"#).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({ "user": "foo None 2" })
+    );
+
+    let res = schema
+        .execute(r#"{ user(input: { name: "foo", age: 20, pairs: 4 }) }"#)
+        .await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({ "user": "foo Some(20) 4" })
+    );
+
+    let res = schema.execute(r#"{ user(input: { name: "" }) }"#).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "input": Failed to parse "UserInput": Invalid value for field "name": the length is 0, must be greater than or equal to 1"#
+    );
+
+    let res = schema
+        .execute(r#"{ user(input: { name: "foo", age: 200 }) }"#)
+        .await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "input": Failed to parse "UserInput": Invalid value for field "age": the value is 200, must be less than or equal to 150"#
+    );
+
+    let res = schema
+        .execute(r#"{ user(input: { name: "foo", pairs: 3 }) }"#)
+        .await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "input": Failed to parse "UserInput": Invalid value for field "pairs": 3 is not even"#
+    );
+    let extensions = res.errors[0].extensions.as_ref().unwrap();
+    assert_eq!(extensions.get("code"), Some(&Value::from("NOT_EVEN")));
+}
@@ -0,0 +1,4 @@
+mod schema_utils;
+mod visibility {
+    mod visibility_tests;
+}
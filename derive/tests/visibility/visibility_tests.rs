@@ -0,0 +1,340 @@
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::value;
+use dynamic_graphql::App;
+use dynamic_graphql::Enum;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Value;
+use dynamic_graphql::Variables;
+use dynamic_graphql::VisibleContext;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Caller {
+    Public,
+    Internal,
+}
+
+fn is_internal(ctx: &VisibleContext) -> bool {
+    ctx.data_opt::<Caller>() == Some(&Caller::Internal)
+}
+
+#[Interface]
+trait Node {
+    fn id(&self) -> String;
+    #[graphql(visible = "is_internal")]
+    fn created_by(&self) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Node))]
+struct User {
+    #[graphql(skip)]
+    id: String,
+    name: String,
+    #[graphql(visible = "is_internal")]
+    internal_notes: String,
+    #[graphql(visible = false)]
+    #[allow(dead_code)]
+    password_hash: String,
+}
+
+impl Node for User {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    fn created_by(&self) -> String {
+        "admin".to_string()
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(visible = "is_internal")]
+struct AuditLog {
+    entries: Vec<String>,
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Member,
+    #[graphql(visible = "is_internal")]
+    Staff,
+}
+
+#[derive(InputObject)]
+struct UserFilter {
+    name: Option<String>,
+    #[graphql(visible = "is_internal")]
+    include_deleted: Option<bool>,
+}
+
+fn user() -> User {
+    User {
+        id: "1".to_string(),
+        name: "Alice".to_string(),
+        internal_notes: "VIP".to_string(),
+        password_hash: "secret".to_string(),
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn user(&self) -> User {
+        user()
+    }
+    fn users(
+        &self,
+        filter: Option<UserFilter>,
+        #[graphql(visible = "is_internal")] limit: Option<i32>,
+    ) -> Vec<User> {
+        let include_deleted = filter.and_then(|filter| filter.include_deleted);
+        let count = limit.unwrap_or(1) + i32::from(include_deleted == Some(true));
+        (0..count).map(|_| user()).collect()
+    }
+    fn role(&self, role: Role) -> Role {
+        role
+    }
+    fn audit_log(&self) -> AuditLog {
+        AuditLog {
+            entries: vec!["login".to_string()],
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+async fn execute(request: dynamic_graphql::Request, caller: Caller) -> Result<Value, String> {
+    let schema = App::create_schema().finish().unwrap();
+    let res = schema
+        .execute(
+            request
+                .data(caller)
+                .root_value(FieldValue::owned_any(Query)),
+        )
+        .await;
+    match res.errors.first() {
+        None => Ok(res.data),
+        Some(error) => Err(error.message.clone()),
+    }
+}
+
+async fn execute_query(query: &str, caller: Caller) -> Result<Value, String> {
+    execute(dynamic_graphql::Request::new(query), caller).await
+}
+
+#[tokio::test]
+async fn test_hidden_field() {
+    let query = "{ user { name internalNotes } }";
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Err(r#"Unknown field "internalNotes" on type "User"."#.to_string())
+    );
+    assert_eq!(
+        execute_query(query, Caller::Internal).await,
+        Ok(value!({ "user": { "name": "Alice", "internalNotes": "VIP" } }))
+    );
+
+    let query = "{ user { passwordHash } }";
+    assert_eq!(
+        execute_query(query, Caller::Internal).await,
+        Err(r#"Unknown field "passwordHash" on type "User"."#.to_string())
+    );
+
+    // fragments select hidden fields too
+    let query = "{ user { ...UserFields } } fragment UserFields on User { internalNotes }";
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Err(r#"Unknown field "internalNotes" on type "User"."#.to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_hidden_interface_field() {
+    let query = "{ user { ... on Node { createdBy } } }";
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Err(r#"Unknown field "createdBy" on type "Node"."#.to_string())
+    );
+    assert_eq!(
+        execute_query("{ user { createdBy } }", Caller::Public).await,
+        Err(r#"Unknown field "createdBy" on type "User"."#.to_string())
+    );
+    assert_eq!(
+        execute_query(query, Caller::Internal).await,
+        Ok(value!({ "user": { "createdBy": "admin" } }))
+    );
+}
+
+#[tokio::test]
+async fn test_hidden_type() {
+    let query = "{ auditLog { entries } }";
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Err(r#"Unknown field "auditLog" on type "Query"."#.to_string())
+    );
+    assert_eq!(
+        execute_query(query, Caller::Internal).await,
+        Ok(value!({ "auditLog": { "entries": ["login"] } }))
+    );
+}
+
+#[tokio::test]
+async fn test_hidden_argument() {
+    let query = "{ users(limit: 2) { id } }";
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Err(r#"Unknown argument "limit" on field "users" of type "Query"."#.to_string())
+    );
+    assert_eq!(
+        execute_query(query, Caller::Internal).await,
+        Ok(value!({ "users": [{ "id": "1" }, { "id": "1" }] }))
+    );
+}
+
+#[tokio::test]
+async fn test_hidden_enum_item() {
+    assert_eq!(
+        execute_query("{ role(role: MEMBER) }", Caller::Public).await,
+        Ok(value!({ "role": "MEMBER" }))
+    );
+    assert_eq!(
+        execute_query("{ role(role: STAFF) }", Caller::Public).await,
+        Err(
+            r#"Invalid value for argument "role", enumeration type "Role" does not contain the value "STAFF""#
+                .to_string()
+        )
+    );
+
+    let request = dynamic_graphql::Request::new("query ($role: Role!) { role(role: $role) }")
+        .variables(Variables::from_value(value!({ "role": "STAFF" })));
+    assert_eq!(
+        execute(request, Caller::Public).await,
+        Err(
+            r#"Invalid value for argument "role", enumeration type "Role" does not contain the value "STAFF""#
+                .to_string()
+        )
+    );
+    assert_eq!(
+        execute_query("{ role(role: STAFF) }", Caller::Internal).await,
+        Ok(value!({ "role": "STAFF" }))
+    );
+}
+
+#[tokio::test]
+async fn test_hidden_input_field() {
+    let query = "{ users(filter: { includeDeleted: true }) { id } }";
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Err(
+            r#"Invalid value for argument "filter", unknown field "includeDeleted" of type "UserFilter""#
+                .to_string()
+        )
+    );
+    assert_eq!(
+        execute_query(query, Caller::Internal).await,
+        Ok(value!({ "users": [{ "id": "1" }, { "id": "1" }] }))
+    );
+}
+
+#[tokio::test]
+async fn test_introspection() {
+    let query = r#"
+        {
+            user: __type(name: "User") { fields { name } }
+            node: __type(name: "Node") { fields { name } }
+            role: __type(name: "Role") { enumValues { name } }
+            filter: __type(name: "UserFilter") { inputFields { name } }
+            auditLog: __type(name: "AuditLog") { name }
+            __schema {
+                queryType { fields { name args { name } } }
+            }
+        }
+    "#;
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Ok(value!({
+            "user": { "fields": [{ "name": "name" }, { "name": "id" }] },
+            "node": { "fields": [{ "name": "id" }] },
+            "role": { "enumValues": [{ "name": "MEMBER" }] },
+            "filter": { "inputFields": [{ "name": "name" }] },
+            "auditLog": null,
+            "__schema": {
+                "queryType": {
+                    "fields": [
+                        { "name": "user", "args": [] },
+                        { "name": "users", "args": [{ "name": "filter" }] },
+                        { "name": "role", "args": [{ "name": "role" }] },
+                    ]
+                }
+            }
+        }))
+    );
+    assert_eq!(
+        execute_query(query, Caller::Internal).await,
+        Ok(value!({
+            "user": {
+                "fields": [
+                    { "name": "name" },
+                    { "name": "internalNotes" },
+                    { "name": "id" },
+                    { "name": "createdBy" },
+                ]
+            },
+            "node": { "fields": [{ "name": "id" }, { "name": "createdBy" }] },
+            "role": { "enumValues": [{ "name": "MEMBER" }, { "name": "STAFF" }] },
+            "filter": { "inputFields": [{ "name": "name" }, { "name": "includeDeleted" }] },
+            "auditLog": { "name": "AuditLog" },
+            "__schema": {
+                "queryType": {
+                    "fields": [
+                        { "name": "user", "args": [] },
+                        { "name": "users", "args": [{ "name": "filter" }, { "name": "limit" }] },
+                        { "name": "role", "args": [{ "name": "role" }] },
+                        { "name": "auditLog", "args": [] },
+                    ]
+                }
+            }
+        }))
+    );
+
+    let query = "{ __schema { types { name } } }";
+    let Ok(Value::Object(data)) = execute_query(query, Caller::Public).await else {
+        panic!("the introspection failed");
+    };
+    let types = data["__schema"].to_string();
+    assert!(types.contains(r#"name: "User""#));
+    assert!(!types.contains(r#"name: "AuditLog""#));
+}
+
+#[tokio::test]
+async fn test_introspection_alias_collision() {
+    let query = r#"
+        {
+            __type(name: "User") {
+                fields {
+                    _visibilityName: description
+                    _visibilityName1: name
+                }
+            }
+        }
+    "#;
+    assert_eq!(
+        execute_query(query, Caller::Public).await,
+        Ok(value!({
+            "__type": {
+                "fields": [
+                    { "_visibilityName": null, "_visibilityName1": "name" },
+                    { "_visibilityName": null, "_visibilityName1": "id" },
+                ]
+            }
+        }))
+    );
+}
@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::str::FromStr;
+
+use crate::dynamic;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::resolve_shared_value;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::resolve::ResolveShared;
+use crate::resolve::Shared;
+use crate::to_value::ToValue;
+use crate::types::InputTypeName;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::Scalar;
+use crate::types::TypeName;
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::Value;
+
+const BIG_INT: &str = "BigInt";
+
+/// registers the `BigInt` scalar once for all the big int types
+struct BigIntScalar;
+
+impl Register for BigIntScalar {
+    fn register(registry: Registry) -> Registry {
+        registry.register_type(
+            dynamic::Scalar::new(BIG_INT)
+                .description("An integer that may not fit in `Int`, represented as a string"),
+        )
+    }
+}
+
+/// An integer as a `BigInt` scalar, the value is a string in responses and a string or an
+/// integer in inputs
+///
+/// `i128` and `u128` are `BigInt` too, `BigInt<u64>` can be used for the `u64` values that don't
+/// fit in `Int`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BigInt<T>(pub T);
+
+impl<T> Deref for BigInt<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for BigInt<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for BigInt<T> {
+    fn from(value: T) -> Self {
+        BigInt(value)
+    }
+}
+
+impl<T> BigInt<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// parse a string or an integer
+fn parse_big_int<T, S>(
+    value: Result<dynamic::ValueAccessor>,
+    wrap: impl FnOnce(T) -> S,
+) -> InputValueResult<S>
+where
+    T: FromStr,
+    T::Err: Display,
+    S: InputTypeName,
+{
+    let value = match value?.as_value() {
+        Value::String(s) => s.parse(),
+        Value::Number(n) => n.to_string().parse(),
+        value => return Err(InputValueError::expected_type(value.clone())),
+    };
+    value.map(wrap).map_err(InputValueError::custom)
+}
+
+macro_rules! big_ints {
+    ($(impl<$($generic:ident),*> for $ty:ty where [$($bound:tt)*] => |$value:ident| $inner:expr;)*) => {
+        $(
+            impl<$($generic),*> Register for $ty where $($bound)* {
+                fn register(registry: Registry) -> Registry {
+                    registry.register::<BigIntScalar>()
+                }
+            }
+            impl<$($generic),*> TypeName for $ty where $($bound)* {
+                fn get_type_name() -> Cow<'static, str> {
+                    BIG_INT.into()
+                }
+            }
+            impl<$($generic),*> OutputTypeName for $ty where $($bound)* {}
+            impl<$($generic),*> InputTypeName for $ty where $($bound)* {}
+            impl<$($generic),*> Scalar for $ty where $($bound)* {}
+
+            impl<'a, $($generic),*> ResolveOwned<'a> for $ty where $($bound)* {
+                fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    Ok(Some(FieldValue::value(self.to_value())))
+                }
+            }
+            impl<'a, $($generic),*> ResolveRef<'a> for $ty where $($bound)* {
+                fn resolve_ref(&'a self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    Ok(Some(FieldValue::value(self.to_value())))
+                }
+            }
+            impl<'a, $($generic),*> ResolveShared<'a> for $ty where $($bound)* {
+                fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_shared_value(&shared, ctx)
+                }
+            }
+            impl<$($generic),*> ToValue for $ty where $($bound)* {
+                fn to_value(&self) -> Value {
+                    let $value = self;
+                    Value::String($inner.to_string())
+                }
+            }
+        )*
+    };
+}
+
+big_ints! {
+    impl<T> for BigInt<T> where [T: Display + FromStr + 'static, T::Err: Display] => |value| value.0;
+    impl<> for i128 where [] => |value| value;
+    impl<> for u128 where [] => |value| value;
+}
+
+impl<T> FromValue for BigInt<T>
+where
+    T: Display + FromStr + 'static,
+    T::Err: Display,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        parse_big_int(value, BigInt)
+    }
+}
+
+impl FromValue for i128 {
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        parse_big_int(value, |value| value)
+    }
+}
+
+impl FromValue for u128 {
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        parse_big_int(value, |value| value)
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_graphql::extensions::Extension;
+use async_graphql::extensions::ExtensionContext;
+use async_graphql::extensions::ExtensionFactory;
+use async_graphql::extensions::NextParseQuery;
+use async_graphql::extensions::NextPrepareRequest;
+use async_graphql::parser::types::Directive;
+use async_graphql::parser::types::DocumentOperations;
+use async_graphql::parser::types::ExecutableDocument;
+use async_graphql::parser::types::Field;
+use async_graphql::parser::types::OperationDefinition;
+use async_graphql::parser::types::OperationType;
+use async_graphql::parser::types::Selection;
+use async_graphql::parser::types::SelectionSet;
+use async_graphql::registry::MetaType;
+use async_graphql::registry::MetaTypeName;
+use async_graphql::Positioned;
+use async_graphql::Request;
+use async_graphql::ServerError;
+use async_graphql::ServerResult;
+
+use crate::Name;
+use crate::Value;
+use crate::Variables;
+
+type ComplexityFn = Arc<dyn Fn(usize, &FieldArgs) -> usize + Send + Sync>;
+
+/// The cost of a field, defined with `#[graphql(complexity = ...)]`
+#[derive(Clone)]
+pub enum Complexity {
+    /// fixed cost, the complexity of the selected children is added to it
+    Const(usize),
+    /// computed from the complexity of the selected children and the field arguments
+    Fn(ComplexityFn),
+}
+
+impl Complexity {
+    pub fn with_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize, &FieldArgs) -> usize + Send + Sync + 'static,
+    {
+        Complexity::Fn(Arc::new(f))
+    }
+
+    fn compute(&self, child_complexity: usize, args: &FieldArgs) -> usize {
+        match self {
+            Complexity::Const(complexity) => complexity + child_complexity,
+            Complexity::Fn(f) => f(child_complexity, args),
+        }
+    }
+}
+
+impl From<usize> for Complexity {
+    fn from(value: usize) -> Self {
+        Complexity::Const(value)
+    }
+}
+
+/// Arguments of a field in the query, variables are already replaced with their values
+pub struct FieldArgs {
+    args: Vec<(Name, Value)>,
+}
+
+impl FieldArgs {
+    fn new(field: &Field, variables: &BTreeMap<Name, Value>) -> Self {
+        let args = field
+            .arguments
+            .iter()
+            .map(|(name, value)| {
+                let value = value
+                    .node
+                    .clone()
+                    .into_const_with(|name| {
+                        Ok::<_, Infallible>(variables.get(&name).cloned().unwrap_or_default())
+                    })
+                    .unwrap_or_default();
+                (name.node.clone(), value)
+            })
+            .collect();
+        Self { args }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.args
+            .iter()
+            .find(|(arg_name, _)| arg_name.as_str() == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// type name -> field name -> complexity
+pub(crate) type FieldComplexities = HashMap<String, HashMap<String, Complexity>>;
+
+/// Enforces the complexity limit of the registry, using the complexity of the fields.
+pub(crate) struct ComplexityLimit {
+    pub(crate) fields: Arc<FieldComplexities>,
+    pub(crate) limit: usize,
+}
+
+impl ExtensionFactory for ComplexityLimit {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ComplexityLimitExtension {
+            fields: self.fields.clone(),
+            limit: self.limit,
+            operation_name: Mutex::new(None),
+        })
+    }
+}
+
+struct ComplexityLimitExtension {
+    fields: Arc<FieldComplexities>,
+    limit: usize,
+    // the operation of the request, only this one is executed
+    operation_name: Mutex<Option<String>>,
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for ComplexityLimitExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let request = next.run(ctx, request).await?;
+        *self.operation_name.lock().unwrap() = request.operation_name.clone();
+        Ok(request)
+    }
+
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+        let operation_name = self.operation_name.lock().unwrap().clone();
+        let complexity = query_complexity(
+            &ctx.schema_env.registry,
+            &self.fields,
+            &document,
+            operation_name.as_deref(),
+            variables,
+        );
+        if complexity > self.limit {
+            return Err(ServerError::new("Query is too complex.", None));
+        }
+        Ok(document)
+    }
+}
+
+struct ComplexityVisitor<'a> {
+    registry: &'a async_graphql::registry::Registry,
+    fields: &'a FieldComplexities,
+    document: &'a ExecutableDocument,
+    variables: BTreeMap<Name, Value>,
+    // fragments in the current path, to stop on cycles (they are reported by the validation)
+    fragments: HashSet<&'a str>,
+}
+
+/// The operation that is executed, `None` when it can't be selected (the execution reports it)
+fn selected_operation<'a>(
+    document: &'a ExecutableDocument,
+    operation_name: Option<&str>,
+) -> Option<&'a OperationDefinition> {
+    let operation = match (&document.operations, operation_name) {
+        (DocumentOperations::Single(_), Some(_)) => None,
+        (DocumentOperations::Single(operation), None) => Some(operation),
+        (DocumentOperations::Multiple(operations), Some(name)) => operations.get(name),
+        (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+            operations.values().next()
+        }
+        (DocumentOperations::Multiple(_), None) => None,
+    };
+    operation.map(|operation| &operation.node)
+}
+
+fn query_complexity(
+    registry: &async_graphql::registry::Registry,
+    fields: &FieldComplexities,
+    document: &ExecutableDocument,
+    operation_name: Option<&str>,
+    variables: &Variables,
+) -> usize {
+    let Some(operation) = selected_operation(document, operation_name) else {
+        return 0;
+    };
+    let root = match operation.ty {
+        OperationType::Query => Some(registry.query_type.as_str()),
+        OperationType::Mutation => registry.mutation_type.as_deref(),
+        OperationType::Subscription => registry.subscription_type.as_deref(),
+    };
+    let mut variables: BTreeMap<Name, Value> = (**variables).clone();
+    for definition in &operation.variable_definitions {
+        let definition = &definition.node;
+        if let Some(default_value) = &definition.default_value {
+            variables
+                .entry(definition.name.node.clone())
+                .or_insert_with(|| default_value.node.clone());
+        }
+    }
+    let mut visitor = ComplexityVisitor {
+        registry,
+        fields,
+        document,
+        variables,
+        fragments: HashSet::new(),
+    };
+    let root = root.and_then(|root| registry.types.get(root));
+    visitor.selection_set(root, &operation.selection_set.node)
+}
+
+impl<'a> ComplexityVisitor<'a> {
+    fn selection_set(
+        &mut self,
+        parent: Option<&'a MetaType>,
+        selection_set: &'a SelectionSet,
+    ) -> usize {
+        selection_set
+            .items
+            .iter()
+            .map(|selection| {
+                if self.is_skipped(selection.node.directives()) {
+                    return 0;
+                }
+                match &selection.node {
+                    Selection::Field(field) => self.field(parent, &field.node),
+                    Selection::FragmentSpread(spread) => {
+                        let name = spread.node.fragment_name.node.as_str();
+                        let Some(fragment) = self.document.fragments.get(name) else {
+                            return 0;
+                        };
+                        if !self.fragments.insert(name) {
+                            return 0;
+                        }
+                        let ty = self
+                            .registry
+                            .types
+                            .get(fragment.node.type_condition.node.on.node.as_str());
+                        let complexity = self.selection_set(ty, &fragment.node.selection_set.node);
+                        self.fragments.remove(name);
+                        complexity
+                    }
+                    Selection::InlineFragment(fragment) => {
+                        let ty = match &fragment.node.type_condition {
+                            Some(condition) => {
+                                self.registry.types.get(condition.node.on.node.as_str())
+                            }
+                            None => parent,
+                        };
+                        self.selection_set(ty, &fragment.node.selection_set.node)
+                    }
+                }
+            })
+            .sum()
+    }
+
+    /// the selection is not executed because of `@skip` or `@include`
+    fn is_skipped(&self, directives: &[Positioned<Directive>]) -> bool {
+        directives.iter().any(|directive| {
+            let directive = &directive.node;
+            let skip_if = match directive.name.node.as_str() {
+                "skip" => true,
+                "include" => false,
+                _ => return false,
+            };
+            let condition = directive
+                .get_argument("if")
+                .and_then(|value| {
+                    value
+                        .node
+                        .clone()
+                        .into_const_with(|name| {
+                            Ok::<_, Infallible>(
+                                self.variables.get(&name).cloned().unwrap_or_default(),
+                            )
+                        })
+                        .ok()
+                })
+                .map(|value| value == Value::Boolean(true));
+            condition == Some(skip_if)
+        })
+    }
+
+    fn field(&mut self, parent: Option<&'a MetaType>, field: &'a Field) -> usize {
+        let field_name = field.name.node.as_str();
+        let ty = parent
+            .and_then(|parent| parent.field_by_name(field_name))
+            .and_then(|meta_field| {
+                self.registry
+                    .types
+                    .get(MetaTypeName::concrete_typename(&meta_field.ty))
+            });
+        let child_complexity = self.selection_set(ty, &field.selection_set.node);
+        let complexity = parent
+            .and_then(|parent| self.fields.get(parent.name()))
+            .and_then(|fields| fields.get(field_name));
+        match complexity {
+            Some(complexity) => {
+                complexity.compute(child_complexity, &FieldArgs::new(field, &self.variables))
+            }
+            None => 1 + child_complexity,
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! Relay cursor connections.
+//!
+//! `Connection<Node>` is registered as `NodeConnection` and `Edge<Node>` as `NodeEdge`. Extra
+//! fields are added to them with an `ExpandObject` of the connection or the edge type, the values
+//! of the fields are stored in `additional_fields`.
+
+use std::borrow::Cow;
+use std::future::Future;
+
+use dynamic_graphql_derive::SimpleObject;
+
+use crate::dynamic;
+use crate::registry::Registry;
+use crate::resolve::downcast_parent;
+use crate::resolve::Resolve;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::resolve::ResolveShared;
+use crate::resolve::Shared;
+use crate::types::GetOutputTypeRef;
+use crate::types::Object;
+use crate::types::OutputTypeName;
+use crate::types::ParentType;
+use crate::types::Register;
+use crate::types::TypeName;
+use crate::Context;
+use crate::Error;
+use crate::FieldValue;
+use crate::Result;
+
+/// Values used as cursors of the edges
+pub trait CursorType: Sized {
+    /// returns `None` if the cursor is invalid
+    fn decode_cursor(cursor: &str) -> Option<Self>;
+
+    fn encode_cursor(&self) -> String;
+}
+
+impl CursorType for String {
+    fn decode_cursor(cursor: &str) -> Option<Self> {
+        Some(cursor.to_string())
+    }
+
+    fn encode_cursor(&self) -> String {
+        self.clone()
+    }
+}
+
+macro_rules! int_cursor {
+    ($($t:ty),*) => {
+        $(
+            impl CursorType for $t {
+                fn decode_cursor(cursor: &str) -> Option<Self> {
+                    cursor.parse().ok()
+                }
+
+                fn encode_cursor(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+int_cursor!(i32, i64, u32, u64, usize);
+
+/// Information about pagination in a connection
+#[derive(SimpleObject)]
+pub struct PageInfo {
+    /// When paginating backwards, are there more items?
+    pub has_previous_page: bool,
+    /// When paginating forwards, are there more items?
+    pub has_next_page: bool,
+    /// When paginating backwards, the cursor to continue
+    pub start_cursor: Option<String>,
+    /// When paginating forwards, the cursor to continue
+    pub end_cursor: Option<String>,
+}
+
+/// An edge in a connection, registered as `NodeEdge`
+pub struct Edge<Node, EdgeFields = ()> {
+    pub cursor: String,
+    pub node: Node,
+    pub additional_fields: EdgeFields,
+}
+
+impl<Node> Edge<Node> {
+    pub fn new(cursor: impl CursorType, node: Node) -> Self {
+        Self::with_additional_fields(cursor, node, ())
+    }
+}
+
+impl<Node, EdgeFields> Edge<Node, EdgeFields> {
+    pub fn with_additional_fields(
+        cursor: impl CursorType,
+        node: Node,
+        additional_fields: EdgeFields,
+    ) -> Self {
+        Self {
+            cursor: cursor.encode_cursor(),
+            node,
+            additional_fields,
+        }
+    }
+}
+
+impl<Node, EdgeFields> Register for Edge<Node, EdgeFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        let registry = registry.register::<Node>();
+        let object = dynamic::Object::new(<Self as Object>::get_object_type_name())
+            .description("An edge in a connection")
+            .field(
+                dynamic::Field::new(
+                    "node",
+                    <Node as GetOutputTypeRef>::get_output_type_ref(),
+                    |ctx| {
+                        dynamic::FieldFuture::new(async move {
+                            let edge = downcast_parent::<Self>(ctx.parent_value)?;
+                            Resolve::resolve(&edge.node, ctx.ctx)
+                        })
+                    },
+                )
+                .description("The item at the end of the edge"),
+            )
+            .field(
+                dynamic::Field::new(
+                    "cursor",
+                    <String as GetOutputTypeRef>::get_output_type_ref(),
+                    |ctx| {
+                        dynamic::FieldFuture::new(async move {
+                            let edge = downcast_parent::<Self>(ctx.parent_value)?;
+                            Resolve::resolve(&edge.cursor, ctx.ctx)
+                        })
+                    },
+                )
+                .description("A cursor for use in pagination"),
+            );
+        registry.register_type(object)
+    }
+}
+
+impl<Node, EdgeFields> TypeName for Edge<Node, EdgeFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        format!("{}Edge", <Node as OutputTypeName>::get_output_type_name()).into()
+    }
+}
+
+impl<Node, EdgeFields> OutputTypeName for Edge<Node, EdgeFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+{
+}
+
+impl<Node, EdgeFields> ParentType for Edge<Node, EdgeFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+{
+    type Type = Self;
+}
+
+impl<Node, EdgeFields> Object for Edge<Node, EdgeFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+{
+}
+
+impl<'a, Node, EdgeFields> ResolveOwned<'a> for Edge<Node, EdgeFields>
+where
+    Node: Send + Sync + 'static,
+    EdgeFields: Send + Sync + 'static,
+{
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::owned_any(self)))
+    }
+}
+
+impl<'a, Node, EdgeFields> ResolveRef<'a> for Edge<Node, EdgeFields>
+where
+    Node: Send + Sync + 'static,
+    EdgeFields: Send + Sync + 'static,
+{
+    fn resolve_ref(&'a self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::borrowed_any(self)))
+    }
+}
+
+impl<'a, Node, EdgeFields> ResolveShared<'a> for Edge<Node, EdgeFields>
+where
+    Node: Send + Sync + 'static,
+    EdgeFields: Send + Sync + 'static,
+{
+    fn resolve_shared(shared: Shared<Self>, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::owned_any(shared)))
+    }
+}
+
+/// A page of a list, registered as `NodeConnection`
+pub struct Connection<Node, EdgeFields = (), ConnectionFields = ()> {
+    pub edges: Vec<Edge<Node, EdgeFields>>,
+    pub additional_fields: ConnectionFields,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+}
+
+impl<Node, EdgeFields> Connection<Node, EdgeFields> {
+    pub fn new(has_previous_page: bool, has_next_page: bool) -> Self {
+        Self::with_additional_fields(has_previous_page, has_next_page, ())
+    }
+}
+
+impl<Node, EdgeFields, ConnectionFields> Connection<Node, EdgeFields, ConnectionFields> {
+    pub fn with_additional_fields(
+        has_previous_page: bool,
+        has_next_page: bool,
+        additional_fields: ConnectionFields,
+    ) -> Self {
+        Self {
+            edges: Vec::new(),
+            additional_fields,
+            has_previous_page,
+            has_next_page,
+        }
+    }
+
+    pub fn page_info(&self) -> PageInfo {
+        PageInfo {
+            has_previous_page: self.has_previous_page,
+            has_next_page: self.has_next_page,
+            start_cursor: self.edges.first().map(|edge| edge.cursor.clone()),
+            end_cursor: self.edges.last().map(|edge| edge.cursor.clone()),
+        }
+    }
+}
+
+impl<Node, EdgeFields, ConnectionFields> Register for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        let registry = registry
+            .register::<PageInfo>()
+            .register::<Edge<Node, EdgeFields>>();
+        let object = dynamic::Object::new(<Self as Object>::get_object_type_name())
+            .description("A connection to a list of items")
+            .field(
+                dynamic::Field::new(
+                    "pageInfo",
+                    <PageInfo as GetOutputTypeRef>::get_output_type_ref(),
+                    |ctx| {
+                        dynamic::FieldFuture::new(async move {
+                            let connection = downcast_parent::<Self>(ctx.parent_value)?;
+                            Resolve::resolve(connection.page_info(), ctx.ctx)
+                        })
+                    },
+                )
+                .description("Information to aid in pagination"),
+            )
+            .field(
+                dynamic::Field::new(
+                    "edges",
+                    <Vec<Edge<Node, EdgeFields>> as GetOutputTypeRef>::get_output_type_ref(),
+                    |ctx| {
+                        dynamic::FieldFuture::new(async move {
+                            let connection = downcast_parent::<Self>(ctx.parent_value)?;
+                            Resolve::resolve(&connection.edges, ctx.ctx)
+                        })
+                    },
+                )
+                .description("A list of edges"),
+            )
+            .field(
+                dynamic::Field::new(
+                    "nodes",
+                    <Vec<Node> as GetOutputTypeRef>::get_output_type_ref(),
+                    |ctx| {
+                        dynamic::FieldFuture::new(async move {
+                            let connection = downcast_parent::<Self>(ctx.parent_value)?;
+                            let nodes: Vec<&Node> =
+                                connection.edges.iter().map(|edge| &edge.node).collect();
+                            Resolve::resolve(nodes, ctx.ctx)
+                        })
+                    },
+                )
+                .description("A list of nodes"),
+            );
+        registry.register_type(object)
+    }
+}
+
+impl<Node, EdgeFields, ConnectionFields> TypeName for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        format!(
+            "{}Connection",
+            <Node as OutputTypeName>::get_output_type_name()
+        )
+        .into()
+    }
+}
+
+impl<Node, EdgeFields, ConnectionFields> OutputTypeName
+    for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+}
+
+impl<Node, EdgeFields, ConnectionFields> ParentType
+    for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+    type Type = Self;
+}
+
+impl<Node, EdgeFields, ConnectionFields> Object for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: OutputTypeName + Send + Sync + 'static,
+    for<'a> &'a Node: Resolve<'a>,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+}
+
+impl<'a, Node, EdgeFields, ConnectionFields> ResolveOwned<'a>
+    for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: Send + Sync + 'static,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::owned_any(self)))
+    }
+}
+
+impl<'a, Node, EdgeFields, ConnectionFields> ResolveRef<'a>
+    for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: Send + Sync + 'static,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+    fn resolve_ref(&'a self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::borrowed_any(self)))
+    }
+}
+
+impl<'a, Node, EdgeFields, ConnectionFields> ResolveShared<'a>
+    for Connection<Node, EdgeFields, ConnectionFields>
+where
+    Node: Send + Sync + 'static,
+    EdgeFields: Send + Sync + 'static,
+    ConnectionFields: Send + Sync + 'static,
+{
+    fn resolve_shared(shared: Shared<Self>, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::owned_any(shared)))
+    }
+}
+
+fn count_argument(name: &str, value: Option<i32>) -> Result<Option<usize>> {
+    match value {
+        Some(value) if value < 0 => Err(Error::new(format!(
+            "The \"{}\" parameter must be a non-negative number",
+            name
+        ))),
+        value => Ok(value.map(|value| value as usize)),
+    }
+}
+
+fn cursor_argument<Cursor: CursorType>(
+    name: &str,
+    value: Option<String>,
+) -> Result<Option<Cursor>> {
+    value
+        .map(|value| {
+            Cursor::decode_cursor(&value)
+                .ok_or_else(|| Error::new(format!("Invalid cursor for \"{}\"", name)))
+        })
+        .transpose()
+}
+
+/// Validate the pagination arguments and call `f` with the decoded cursors, `f` produces the
+/// connection.
+///
+/// `first` and `last` can't be used at the same time, and must be non-negative.
+pub async fn query<Cursor, Node, EdgeFields, ConnectionFields, F, R>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    f: F,
+) -> Result<Connection<Node, EdgeFields, ConnectionFields>>
+where
+    Cursor: CursorType,
+    F: FnOnce(Option<Cursor>, Option<Cursor>, Option<usize>, Option<usize>) -> R,
+    R: Future<Output = Result<Connection<Node, EdgeFields, ConnectionFields>>>,
+{
+    if first.is_some() && last.is_some() {
+        return Err(Error::new(
+            "The \"first\" and \"last\" parameters cannot exist at the same time",
+        ));
+    }
+    let first = count_argument("first", first)?;
+    let last = count_argument("last", last)?;
+    let after = cursor_argument("after", after)?;
+    let before = cursor_argument("before", before)?;
+    f(after, before, first, last).await
+}
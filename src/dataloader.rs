@@ -0,0 +1,387 @@
+//! Batch loading of values, to avoid N+1 queries in lists.
+//!
+//! A [`DataLoader`] wraps a [`Loader`]. The keys that are loaded while the same query is
+//! executed are coalesced, the loader is called once with all of them when the resolvers stop
+//! adding keys.
+//!
+//! Loaders are registered in the schema data, `registry.data.insert(DataLoader::new(loader))`,
+//! or in the request data. Resolvers get them with an argument marked with
+//! `#[graphql(loader)]`:
+//!
+//! ```ignore
+//! #[ResolvedObjectFields]
+//! impl Post {
+//!     async fn author(&self, #[graphql(loader)] users: &DataLoader<UserLoader>) -> Result<Option<User>> {
+//!         users.load_one(self.author_id).await
+//!     }
+//! }
+//! ```
+//!
+//! Values are cached by [`DataLoader::with_cache`]. The cache of a loader of the request data is
+//! shared by the clones of the loader and lives as long as them, a loader of the schema data has
+//! a new cache for each request. The loaders of the `#[graphql(loader)]` arguments are declared
+//! by the derives, the loaders that other resolvers get with `get_loader` are declared with
+//! `Registry::declare_loader`.
+
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Poll;
+
+use async_graphql::extensions::Extension;
+use async_graphql::extensions::ExtensionContext;
+use async_graphql::extensions::ExtensionFactory;
+use async_graphql::extensions::NextPrepareRequest;
+use async_graphql::futures_util::future::BoxFuture;
+use async_graphql::futures_util::future::Shared;
+use async_graphql::futures_util::FutureExt;
+use async_graphql::Request;
+use async_graphql::ServerResult;
+
+use crate::data::SchemaData;
+use crate::registry::Registry;
+use crate::Context;
+use crate::Error;
+use crate::Result;
+
+/// Loads the values of a batch of keys
+pub trait Loader<K>: Send + Sync + 'static
+where
+    K: Send + Sync + Hash + Eq + Clone + 'static,
+{
+    type Value: Send + Sync + Clone + 'static;
+
+    /// the keys that are not found are missing in the result
+    fn load(&self, keys: &[K]) -> impl Future<Output = Result<HashMap<K, Self::Value>>> + Send;
+}
+
+type BatchFuture<K, V> = Shared<BoxFuture<'static, Result<Arc<HashMap<K, V>>>>>;
+
+/// the cached values, and the batch that loads the rest of the keys
+struct Enqueued<K, V> {
+    cached: HashMap<K, V>,
+    batch: Option<BatchFuture<K, V>>,
+}
+
+struct Batch<K, V> {
+    id: usize,
+    keys: Vec<K>,
+    future: BatchFuture<K, V>,
+}
+
+/// the pending batch and the cache of a key type
+struct KeyState<K, V> {
+    next_id: usize,
+    pending: Option<Batch<K, V>>,
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Default for KeyState<K, V> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            pending: None,
+            cache: HashMap::new(),
+        }
+    }
+}
+
+struct Inner<T> {
+    loader: Arc<T>,
+    cache: bool,
+    /// key type -> `KeyState`
+    states: Mutex<HashMap<TypeId, Box<dyn Any + Send>>>,
+}
+
+impl<T: Send + Sync + 'static> Inner<T> {
+    fn with_state<K, R>(&self, f: impl FnOnce(&mut KeyState<K, T::Value>) -> R) -> R
+    where
+        T: Loader<K>,
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+    {
+        let mut states = self.states.lock().unwrap();
+        let state = states
+            .entry(TypeId::of::<K>())
+            .or_insert_with(|| Box::new(KeyState::<K, T::Value>::default()))
+            .downcast_mut()
+            .unwrap();
+        f(state)
+    }
+}
+
+/// Batches and optionally caches the loads of a [`Loader`]
+pub struct DataLoader<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Clone for DataLoader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> DataLoader<T> {
+    pub fn new(loader: T) -> Self {
+        Self::create(loader, false)
+    }
+
+    /// the loaded values are cached, and are not loaded again
+    pub fn with_cache(loader: T) -> Self {
+        Self::create(loader, true)
+    }
+
+    fn create(loader: T, cache: bool) -> Self {
+        Self::with_loader(Arc::new(loader), cache)
+    }
+
+    fn with_loader(loader: Arc<T>, cache: bool) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                loader,
+                cache,
+                states: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// the same loader, with an empty cache
+    fn scoped(&self) -> Self {
+        Self::with_loader(self.inner.loader.clone(), self.inner.cache)
+    }
+
+    pub fn loader(&self) -> &T {
+        &self.inner.loader
+    }
+
+    pub async fn load_one<K>(&self, key: K) -> Result<Option<T::Value>>
+    where
+        T: Loader<K>,
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+    {
+        let mut values = self.load_many([key.clone()]).await?;
+        Ok(values.remove(&key))
+    }
+
+    pub async fn load_many<K, I>(&self, keys: I) -> Result<HashMap<K, T::Value>>
+    where
+        T: Loader<K>,
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+        I: IntoIterator<Item = K>,
+    {
+        let keys: HashSet<K> = keys.into_iter().collect();
+        let Enqueued {
+            cached: mut values,
+            batch,
+        } = self.enqueue(&keys);
+        if let Some(batch) = batch {
+            let loaded = batch.await?;
+            values.extend(keys.into_iter().filter_map(|key| {
+                let value = loaded.get(&key)?.clone();
+                Some((key, value))
+            }));
+        }
+        Ok(values)
+    }
+
+    /// remove the cached values of the key type
+    pub fn clear<K>(&self)
+    where
+        T: Loader<K>,
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+    {
+        self.inner.with_state::<K, _>(|state| state.cache.clear());
+    }
+
+    fn enqueue<K>(&self, keys: &HashSet<K>) -> Enqueued<K, T::Value>
+    where
+        T: Loader<K>,
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+    {
+        self.inner.with_state(|state| {
+            let mut values = HashMap::new();
+            let mut missing = Vec::new();
+            for key in keys {
+                match state.cache.get(key) {
+                    Some(value) => {
+                        values.insert(key.clone(), value.clone());
+                    }
+                    None => missing.push(key.clone()),
+                }
+            }
+            if missing.is_empty() {
+                return Enqueued {
+                    cached: values,
+                    batch: None,
+                };
+            }
+            let batch = state.pending.get_or_insert_with(|| {
+                let id = state.next_id;
+                state.next_id += 1;
+                Batch {
+                    id,
+                    keys: Vec::new(),
+                    future: run_batch(self.inner.clone(), id).boxed().shared(),
+                }
+            });
+            batch.keys.extend(missing);
+            Enqueued {
+                cached: values,
+                batch: Some(batch.future.clone()),
+            }
+        })
+    }
+}
+
+/// wait for the other resolvers to add their keys, then load the batch
+async fn run_batch<T, K>(inner: Arc<Inner<T>>, id: usize) -> Result<Arc<HashMap<K, T::Value>>>
+where
+    T: Loader<K>,
+    K: Send + Sync + Hash + Eq + Clone + 'static,
+{
+    // yield until no more keys are added to the batch
+    let mut len = None;
+    loop {
+        YieldNow(false).await;
+        let current = inner.with_state::<K, _>(|state| {
+            state
+                .pending
+                .as_ref()
+                .filter(|batch| batch.id == id)
+                .map(|batch| batch.keys.len())
+        });
+        if current == len {
+            break;
+        }
+        len = current;
+    }
+    let keys = inner.with_state::<K, _>(|state| match state.pending.take() {
+        Some(batch) if batch.id == id => Some(batch.keys),
+        pending => {
+            state.pending = pending;
+            None
+        }
+    });
+    let Some(keys) = keys else {
+        return Err(Error::new("the batch of the data loader is already loaded"));
+    };
+    // the same key can be added by several resolvers
+    let keys: Vec<K> = keys
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let values = inner.loader.load(&keys).await?;
+    if inner.cache {
+        inner.with_state::<K, _>(|state| {
+            state.cache.extend(
+                values
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            )
+        });
+    }
+    Ok(Arc::new(values))
+}
+
+/// yields once to the executor
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// the loaders of the schema data that cache values, with the cache of the request
+#[derive(Default)]
+struct RequestLoaders(Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>);
+
+impl RequestLoaders {
+    fn get_or_scope<T: Send + Sync + 'static>(&self, loader: &DataLoader<T>) -> DataLoader<T> {
+        self.0
+            .lock()
+            .unwrap()
+            .entry(TypeId::of::<DataLoader<T>>())
+            .or_insert_with(|| Box::new(loader.scoped()))
+            .downcast_ref::<DataLoader<T>>()
+            .unwrap()
+            .clone()
+    }
+}
+
+/// Gives each request its own cache of the loaders of the schema data.
+pub(crate) struct LoaderScope;
+
+impl ExtensionFactory for LoaderScope {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(LoaderScopeExtension)
+    }
+}
+
+struct LoaderScopeExtension;
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for LoaderScopeExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        next.run(ctx, request.data(RequestLoaders::default())).await
+    }
+}
+
+/// the loader of a `#[graphql(loader)]` argument
+pub trait LoaderArg {
+    fn declare_loader(registry: Registry) -> Registry;
+}
+
+impl<T: Send + Sync + 'static> LoaderArg for DataLoader<T> {
+    fn declare_loader(registry: Registry) -> Registry {
+        registry.declare_loader::<T>()
+    }
+}
+
+/// the schema data has a `DataLoader<T>` that caches values
+pub(crate) fn is_cached_loader<T: Send + Sync + 'static>(data: &SchemaData) -> bool {
+    data.get::<DataLoader<T>>()
+        .is_some_and(|loader| loader.inner.cache)
+}
+
+/// get the data loader from the request data or the schema data
+///
+/// a loader of the schema data that caches values gets the cache of the request
+pub fn get_loader<T: Send + Sync + 'static>(ctx: &Context<'_>) -> Result<DataLoader<T>> {
+    if let Some(loader) = ctx.data_opt::<DataLoader<T>>() {
+        return Ok(loader.clone());
+    }
+    let Some(loader) = ctx
+        .data_opt::<SchemaData>()
+        .and_then(|data| data.get::<DataLoader<T>>())
+    else {
+        return Err(Error::new(format!(
+            "DataLoader<{}> is not registered",
+            std::any::type_name::<T>()
+        )));
+    };
+    match ctx.data_opt::<RequestLoaders>() {
+        Some(loaders) if loader.inner.cache => Ok(loaders.get_or_scope(loader)),
+        _ => Ok(loader.clone()),
+    }
+}
@@ -0,0 +1,549 @@
+//! Changes between two versions of a schema.
+//!
+//! The changes are classified by their effect on the existing clients: a breaking change can
+//! fail their queries, a dangerous change can change the behavior of their queries, a safe
+//! change doesn't affect them.
+//!
+//! ```ignore
+//! let stored = std::fs::read_to_string("schema.graphql")?;
+//! let schema = App::create_schema().finish()?;
+//! let diff = SchemaDiff::from_sdl(&stored, &schema.sdl())?;
+//! assert!(!diff.is_breaking(), "{}", diff);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use async_graphql::parser;
+use async_graphql::parser::types::BaseType;
+use async_graphql::parser::types::FieldDefinition;
+use async_graphql::parser::types::InputValueDefinition;
+use async_graphql::parser::types::Type;
+use async_graphql::parser::types::TypeDefinition;
+use async_graphql::parser::types::TypeKind;
+use async_graphql::parser::types::TypeSystemDefinition;
+use async_graphql::parser::Positioned;
+
+use crate::dynamic;
+use crate::sdl::kind_name;
+
+/// The effect of a change on the existing clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChangeLevel {
+    /// the existing queries can fail
+    Breaking,
+    /// the existing queries can get unexpected values
+    Dangerous,
+    /// the existing queries are not affected
+    Safe,
+}
+
+impl Display for ChangeLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeLevel::Breaking => write!(f, "breaking"),
+            ChangeLevel::Dangerous => write!(f, "dangerous"),
+            ChangeLevel::Safe => write!(f, "safe"),
+        }
+    }
+}
+
+/// What changed, the types are written like in the SDL, like `[String!]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    TypeAdded,
+    TypeRemoved,
+    /// the kind of the type changed, like `type` to `interface`
+    TypeKindChanged {
+        from: String,
+        to: String,
+    },
+    FieldAdded,
+    FieldRemoved,
+    FieldTypeChanged {
+        from: String,
+        to: String,
+    },
+    /// `required` when the argument is non-null without a default value
+    ArgumentAdded {
+        required: bool,
+    },
+    ArgumentRemoved,
+    ArgumentTypeChanged {
+        from: String,
+        to: String,
+    },
+    ArgumentDefaultChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// `required` when the field is non-null without a default value
+    InputFieldAdded {
+        required: bool,
+    },
+    InputFieldRemoved,
+    InputFieldTypeChanged {
+        from: String,
+        to: String,
+    },
+    InputFieldDefaultChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    EnumValueAdded,
+    EnumValueRemoved,
+    UnionMemberAdded,
+    UnionMemberRemoved,
+    InterfaceAdded,
+    InterfaceRemoved,
+}
+
+/// A change of a type, a field, an argument or a value, `path` is like `Type.field.argument`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    pub level: ChangeLevel,
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+impl SchemaChange {
+    fn new(level: ChangeLevel, path: String, kind: ChangeKind) -> Self {
+        Self { level, path, kind }
+    }
+}
+
+fn optional(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("none")
+}
+
+impl Display for SchemaChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = &self.path;
+        write!(f, "[{}] ", self.level)?;
+        match &self.kind {
+            ChangeKind::TypeAdded => write!(f, "Type \"{}\" was added", path),
+            ChangeKind::TypeRemoved => write!(f, "Type \"{}\" was removed", path),
+            ChangeKind::TypeKindChanged { from, to } => write!(
+                f,
+                "Type \"{}\" changed from \"{}\" to \"{}\"",
+                path, from, to
+            ),
+            ChangeKind::FieldAdded => write!(f, "Field \"{}\" was added", path),
+            ChangeKind::FieldRemoved => write!(f, "Field \"{}\" was removed", path),
+            ChangeKind::FieldTypeChanged { from, to } => write!(
+                f,
+                "Field \"{}\" changed type from \"{}\" to \"{}\"",
+                path, from, to
+            ),
+            ChangeKind::ArgumentAdded { required: true } => {
+                write!(f, "Required argument \"{}\" was added", path)
+            }
+            ChangeKind::ArgumentAdded { required: false } => {
+                write!(f, "Argument \"{}\" was added", path)
+            }
+            ChangeKind::ArgumentRemoved => write!(f, "Argument \"{}\" was removed", path),
+            ChangeKind::ArgumentTypeChanged { from, to } => write!(
+                f,
+                "Argument \"{}\" changed type from \"{}\" to \"{}\"",
+                path, from, to
+            ),
+            ChangeKind::ArgumentDefaultChanged { from, to } => write!(
+                f,
+                "Argument \"{}\" changed default value from \"{}\" to \"{}\"",
+                path,
+                optional(from),
+                optional(to)
+            ),
+            ChangeKind::InputFieldAdded { required: true } => {
+                write!(f, "Required input field \"{}\" was added", path)
+            }
+            ChangeKind::InputFieldAdded { required: false } => {
+                write!(f, "Input field \"{}\" was added", path)
+            }
+            ChangeKind::InputFieldRemoved => write!(f, "Input field \"{}\" was removed", path),
+            ChangeKind::InputFieldTypeChanged { from, to } => write!(
+                f,
+                "Input field \"{}\" changed type from \"{}\" to \"{}\"",
+                path, from, to
+            ),
+            ChangeKind::InputFieldDefaultChanged { from, to } => write!(
+                f,
+                "Input field \"{}\" changed default value from \"{}\" to \"{}\"",
+                path,
+                optional(from),
+                optional(to)
+            ),
+            ChangeKind::EnumValueAdded => write!(f, "Enum value \"{}\" was added", path),
+            ChangeKind::EnumValueRemoved => write!(f, "Enum value \"{}\" was removed", path),
+            ChangeKind::UnionMemberAdded => write!(f, "Union member \"{}\" was added", path),
+            ChangeKind::UnionMemberRemoved => write!(f, "Union member \"{}\" was removed", path),
+            ChangeKind::InterfaceAdded => write!(f, "Interface \"{}\" was added", path),
+            ChangeKind::InterfaceRemoved => write!(f, "Interface \"{}\" was removed", path),
+        }
+    }
+}
+
+/// The changes between two versions of a schema, ordered by path
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    /// compare two built schemas
+    pub fn between(old: &dynamic::Schema, new: &dynamic::Schema) -> Self {
+        Self::from_sdl(&old.sdl(), &new.sdl()).expect("the sdl of a schema is valid")
+    }
+
+    /// compare two SDL documents, like a stored SDL file and the SDL of a built schema
+    pub fn from_sdl(old: &str, new: &str) -> parser::Result<Self> {
+        let old = parse_types(old)?;
+        let new = parse_types(new)?;
+        let mut changes = Vec::new();
+        for (name, old_type) in &old {
+            match new.get(name) {
+                Some(new_type) => diff_type(&mut changes, name, old_type, new_type),
+                None => changes.push(SchemaChange::new(
+                    ChangeLevel::Breaking,
+                    name.clone(),
+                    ChangeKind::TypeRemoved,
+                )),
+            }
+        }
+        for name in new.keys().filter(|name| !old.contains_key(*name)) {
+            changes.push(SchemaChange::new(
+                ChangeLevel::Safe,
+                name.clone(),
+                ChangeKind::TypeAdded,
+            ));
+        }
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(Self { changes })
+    }
+
+    /// the changes of the level
+    pub fn changes_of(&self, level: ChangeLevel) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.level == level)
+    }
+
+    /// the existing queries can fail
+    pub fn is_breaking(&self) -> bool {
+        self.changes_of(ChangeLevel::Breaking).next().is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for SchemaDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, change) in self.changes.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_types(sdl: &str) -> parser::Result<BTreeMap<String, TypeDefinition>> {
+    let document = parser::parse_schema(sdl)?;
+    Ok(document
+        .definitions
+        .into_iter()
+        .filter_map(|definition| match definition {
+            TypeSystemDefinition::Type(ty) => Some((ty.node.name.node.to_string(), ty.node)),
+            _ => None,
+        })
+        .collect())
+}
+
+/// the clients expecting the `old` type can read the `new` type, it is the same type or it is
+/// non-null instead of nullable
+fn is_output_compatible(old: &Type, new: &Type) -> bool {
+    if old.nullable && !new.nullable {
+        let new = Type {
+            base: new.base.clone(),
+            nullable: true,
+        };
+        return is_output_compatible(old, &new);
+    }
+    if old.nullable != new.nullable {
+        return false;
+    }
+    match (&old.base, &new.base) {
+        (BaseType::Named(old), BaseType::Named(new)) => old == new,
+        (BaseType::List(old), BaseType::List(new)) => is_output_compatible(old, new),
+        _ => false,
+    }
+}
+
+/// the clients sending the `old` type can send it to the `new` type, it is the same type or it
+/// is nullable instead of non-null
+fn is_input_compatible(old: &Type, new: &Type) -> bool {
+    is_output_compatible(new, old)
+}
+
+fn compatibility_level(compatible: bool) -> ChangeLevel {
+    if compatible {
+        ChangeLevel::Safe
+    } else {
+        ChangeLevel::Breaking
+    }
+}
+
+fn is_required(value: &InputValueDefinition) -> bool {
+    !value.ty.node.nullable && value.default_value.is_none()
+}
+
+fn default_value(value: &InputValueDefinition) -> Option<String> {
+    value
+        .default_value
+        .as_ref()
+        .map(|value| value.node.to_string())
+}
+
+/// the items by name, the items of a list are unique by name in a valid document
+fn by_name<'a, T>(
+    items: &'a [Positioned<T>],
+    name: impl Fn(&'a T) -> &'a str,
+) -> BTreeMap<&'a str, &'a T> {
+    items
+        .iter()
+        .map(|item| (name(&item.node), &item.node))
+        .collect()
+}
+
+/// compare the names of two lists, like the values of an enum, with the levels of the removed
+/// and added names
+fn diff_names(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    old: Vec<&str>,
+    new: Vec<&str>,
+    removed: (ChangeLevel, ChangeKind),
+    added: (ChangeLevel, ChangeKind),
+) {
+    for name in old.iter().filter(|name| !new.contains(name)) {
+        changes.push(SchemaChange::new(
+            removed.0,
+            format!("{}.{}", type_name, name),
+            removed.1.clone(),
+        ));
+    }
+    for name in new.iter().filter(|name| !old.contains(name)) {
+        changes.push(SchemaChange::new(
+            added.0,
+            format!("{}.{}", type_name, name),
+            added.1.clone(),
+        ));
+    }
+}
+
+fn diff_arguments(
+    changes: &mut Vec<SchemaChange>,
+    path: &str,
+    old: &[Positioned<InputValueDefinition>],
+    new: &[Positioned<InputValueDefinition>],
+) {
+    let old = by_name(old, |argument| &argument.name.node);
+    let new = by_name(new, |argument| &argument.name.node);
+    for (name, old_argument) in &old {
+        let path = format!("{}.{}", path, name);
+        let Some(new_argument) = new.get(name) else {
+            changes.push(SchemaChange::new(
+                ChangeLevel::Breaking,
+                path,
+                ChangeKind::ArgumentRemoved,
+            ));
+            continue;
+        };
+        let (old_type, new_type) = (&old_argument.ty.node, &new_argument.ty.node);
+        if old_type != new_type {
+            changes.push(SchemaChange::new(
+                compatibility_level(is_input_compatible(old_type, new_type)),
+                path.clone(),
+                ChangeKind::ArgumentTypeChanged {
+                    from: old_type.to_string(),
+                    to: new_type.to_string(),
+                },
+            ));
+        }
+        let (from, to) = (default_value(old_argument), default_value(new_argument));
+        if from != to {
+            changes.push(SchemaChange::new(
+                ChangeLevel::Dangerous,
+                path,
+                ChangeKind::ArgumentDefaultChanged { from, to },
+            ));
+        }
+    }
+    for (name, new_argument) in new.iter().filter(|(name, _)| !old.contains_key(*name)) {
+        let required = is_required(new_argument);
+        changes.push(SchemaChange::new(
+            compatibility_level(!required),
+            format!("{}.{}", path, name),
+            ChangeKind::ArgumentAdded { required },
+        ));
+    }
+}
+
+fn diff_fields(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    old: &[Positioned<FieldDefinition>],
+    new: &[Positioned<FieldDefinition>],
+) {
+    let old = by_name(old, |field| &field.name.node);
+    let new = by_name(new, |field| &field.name.node);
+    for (name, old_field) in &old {
+        let path = format!("{}.{}", type_name, name);
+        let Some(new_field) = new.get(name) else {
+            changes.push(SchemaChange::new(
+                ChangeLevel::Breaking,
+                path,
+                ChangeKind::FieldRemoved,
+            ));
+            continue;
+        };
+        let (old_type, new_type) = (&old_field.ty.node, &new_field.ty.node);
+        if old_type != new_type {
+            changes.push(SchemaChange::new(
+                compatibility_level(is_output_compatible(old_type, new_type)),
+                path.clone(),
+                ChangeKind::FieldTypeChanged {
+                    from: old_type.to_string(),
+                    to: new_type.to_string(),
+                },
+            ));
+        }
+        diff_arguments(changes, &path, &old_field.arguments, &new_field.arguments);
+    }
+    for name in new.keys().filter(|name| !old.contains_key(*name)) {
+        changes.push(SchemaChange::new(
+            ChangeLevel::Safe,
+            format!("{}.{}", type_name, name),
+            ChangeKind::FieldAdded,
+        ));
+    }
+}
+
+fn diff_input_fields(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    old: &[Positioned<InputValueDefinition>],
+    new: &[Positioned<InputValueDefinition>],
+) {
+    let old = by_name(old, |field| &field.name.node);
+    let new = by_name(new, |field| &field.name.node);
+    for (name, old_field) in &old {
+        let path = format!("{}.{}", type_name, name);
+        let Some(new_field) = new.get(name) else {
+            changes.push(SchemaChange::new(
+                ChangeLevel::Breaking,
+                path,
+                ChangeKind::InputFieldRemoved,
+            ));
+            continue;
+        };
+        let (old_type, new_type) = (&old_field.ty.node, &new_field.ty.node);
+        if old_type != new_type {
+            changes.push(SchemaChange::new(
+                compatibility_level(is_input_compatible(old_type, new_type)),
+                path.clone(),
+                ChangeKind::InputFieldTypeChanged {
+                    from: old_type.to_string(),
+                    to: new_type.to_string(),
+                },
+            ));
+        }
+        let (from, to) = (default_value(old_field), default_value(new_field));
+        if from != to {
+            changes.push(SchemaChange::new(
+                ChangeLevel::Dangerous,
+                path,
+                ChangeKind::InputFieldDefaultChanged { from, to },
+            ));
+        }
+    }
+    for (name, new_field) in new.iter().filter(|(name, _)| !old.contains_key(*name)) {
+        let required = is_required(new_field);
+        changes.push(SchemaChange::new(
+            compatibility_level(!required),
+            format!("{}.{}", type_name, name),
+            ChangeKind::InputFieldAdded { required },
+        ));
+    }
+}
+
+fn diff_interfaces(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    old: &[Positioned<crate::Name>],
+    new: &[Positioned<crate::Name>],
+) {
+    diff_names(
+        changes,
+        type_name,
+        old.iter().map(|name| name.node.as_str()).collect(),
+        new.iter().map(|name| name.node.as_str()).collect(),
+        (ChangeLevel::Breaking, ChangeKind::InterfaceRemoved),
+        (ChangeLevel::Dangerous, ChangeKind::InterfaceAdded),
+    );
+}
+
+fn diff_type(
+    changes: &mut Vec<SchemaChange>,
+    name: &str,
+    old: &TypeDefinition,
+    new: &TypeDefinition,
+) {
+    match (&old.kind, &new.kind) {
+        (TypeKind::Scalar, TypeKind::Scalar) => {}
+        (TypeKind::Object(old), TypeKind::Object(new)) => {
+            diff_interfaces(changes, name, &old.implements, &new.implements);
+            diff_fields(changes, name, &old.fields, &new.fields);
+        }
+        (TypeKind::Interface(old), TypeKind::Interface(new)) => {
+            diff_interfaces(changes, name, &old.implements, &new.implements);
+            diff_fields(changes, name, &old.fields, &new.fields);
+        }
+        (TypeKind::Union(old), TypeKind::Union(new)) => diff_names(
+            changes,
+            name,
+            old.members.iter().map(|name| name.node.as_str()).collect(),
+            new.members.iter().map(|name| name.node.as_str()).collect(),
+            (ChangeLevel::Breaking, ChangeKind::UnionMemberRemoved),
+            (ChangeLevel::Dangerous, ChangeKind::UnionMemberAdded),
+        ),
+        (TypeKind::Enum(old), TypeKind::Enum(new)) => diff_names(
+            changes,
+            name,
+            old.values
+                .iter()
+                .map(|value| value.node.value.node.as_str())
+                .collect(),
+            new.values
+                .iter()
+                .map(|value| value.node.value.node.as_str())
+                .collect(),
+            (ChangeLevel::Breaking, ChangeKind::EnumValueRemoved),
+            (ChangeLevel::Dangerous, ChangeKind::EnumValueAdded),
+        ),
+        (TypeKind::InputObject(old), TypeKind::InputObject(new)) => {
+            diff_input_fields(changes, name, &old.fields, &new.fields);
+        }
+        (old, new) => changes.push(SchemaChange::new(
+            ChangeLevel::Breaking,
+            name.to_string(),
+            ChangeKind::TypeKindChanged {
+                from: kind_name(old).to_string(),
+                to: kind_name(new).to_string(),
+            },
+        )),
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::dynamic;
+use crate::Value;
+
+/// Where a type system directive can be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectiveLocation {
+    Schema,
+    Scalar,
+    Object,
+    FieldDefinition,
+    ArgumentDefinition,
+    Interface,
+    Union,
+    Enum,
+    EnumValue,
+    InputObject,
+    InputFieldDefinition,
+}
+
+impl Display for DirectiveLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let location = match self {
+            DirectiveLocation::Schema => "SCHEMA",
+            DirectiveLocation::Scalar => "SCALAR",
+            DirectiveLocation::Object => "OBJECT",
+            DirectiveLocation::FieldDefinition => "FIELD_DEFINITION",
+            DirectiveLocation::ArgumentDefinition => "ARGUMENT_DEFINITION",
+            DirectiveLocation::Interface => "INTERFACE",
+            DirectiveLocation::Union => "UNION",
+            DirectiveLocation::Enum => "ENUM",
+            DirectiveLocation::EnumValue => "ENUM_VALUE",
+            DirectiveLocation::InputObject => "INPUT_OBJECT",
+            DirectiveLocation::InputFieldDefinition => "INPUT_FIELD_DEFINITION",
+        };
+        f.write_str(location)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DirectiveArgument {
+    name: String,
+    ty: dynamic::TypeRef,
+    default_value: Option<Value>,
+}
+
+/// The definition of a type system directive, added to the sdl of the schema
+///
+/// The directives are used with `#[graphql(directive = expr)]`, where `expr` is a
+/// [`dynamic::Directive`].
+///
+/// ```
+/// use dynamic_graphql::dynamic::TypeRef;
+/// use dynamic_graphql::DirectiveDefinition;
+/// use dynamic_graphql::DirectiveLocation;
+///
+/// let cache_control = DirectiveDefinition::new("cacheControl")
+///     .argument("maxAge", TypeRef::named(TypeRef::INT))
+///     .location(DirectiveLocation::Object)
+///     .location(DirectiveLocation::FieldDefinition);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectiveDefinition {
+    name: String,
+    description: Option<String>,
+    arguments: Vec<DirectiveArgument>,
+    repeatable: bool,
+    locations: Vec<DirectiveLocation>,
+}
+
+impl DirectiveDefinition {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            arguments: Vec::new(),
+            repeatable: false,
+            locations: Vec::new(),
+        }
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+    pub fn argument(mut self, name: impl Into<String>, ty: impl Into<dynamic::TypeRef>) -> Self {
+        self.arguments.push(DirectiveArgument {
+            name: name.into(),
+            ty: ty.into(),
+            default_value: None,
+        });
+        self
+    }
+    pub fn argument_with_default(
+        mut self,
+        name: impl Into<String>,
+        ty: impl Into<dynamic::TypeRef>,
+        default_value: impl Into<Value>,
+    ) -> Self {
+        self.arguments.push(DirectiveArgument {
+            name: name.into(),
+            ty: ty.into(),
+            default_value: Some(default_value.into()),
+        });
+        self
+    }
+    /// the directive can be used more than once at the same location
+    pub fn repeatable(mut self) -> Self {
+        self.repeatable = true;
+        self
+    }
+    pub fn location(mut self, location: DirectiveLocation) -> Self {
+        self.locations.push(location);
+        self
+    }
+}
+
+impl Display for DirectiveDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(description) = &self.description {
+            writeln!(f, "\"\"\"\n{}\n\"\"\"", description)?;
+        }
+        write!(f, "directive @{}", self.name)?;
+        if !self.arguments.is_empty() {
+            let arguments = self
+                .arguments
+                .iter()
+                .map(|argument| match &argument.default_value {
+                    Some(value) => format!("{}: {} = {}", argument.name, argument.ty, value),
+                    None => format!("{}: {}", argument.name, argument.ty),
+                })
+                .collect::<Vec<_>>();
+            write!(f, "({})", arguments.join(", "))?;
+        }
+        if self.repeatable {
+            write!(f, " repeatable")?;
+        }
+        let locations = self
+            .locations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        write!(f, " on {}", locations.join(" | "))
+    }
+}
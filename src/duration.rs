@@ -0,0 +1,238 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::dynamic;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::resolve_shared_value;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::resolve::ResolveShared;
+use crate::resolve::Shared;
+use crate::to_value::ToValue;
+use crate::types::InputTypeName;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::Scalar;
+use crate::types::TypeName;
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::Value;
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: u64 = 24 * SECONDS_PER_HOUR;
+
+impl Register for Duration {
+    fn register(registry: Registry) -> Registry {
+        registry.register_type(
+            dynamic::Scalar::new("Duration")
+                .description("An ISO 8601 duration, like `P1DT2H3M4.5S`")
+                .specified_by_url("https://en.wikipedia.org/wiki/ISO_8601#Durations"),
+        )
+    }
+}
+
+impl TypeName for Duration {
+    fn get_type_name() -> Cow<'static, str> {
+        "Duration".into()
+    }
+}
+
+impl InputTypeName for Duration {}
+
+impl OutputTypeName for Duration {}
+
+impl Scalar for Duration {}
+
+impl<'a> ResolveOwned<'a> for Duration {
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.to_value())))
+    }
+}
+
+impl<'a> ResolveRef<'a> for Duration {
+    fn resolve_ref(&'a self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.to_value())))
+    }
+}
+
+impl<'a> ResolveShared<'a> for Duration {
+    fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        resolve_shared_value(&shared, ctx)
+    }
+}
+
+impl ToValue for Duration {
+    fn to_value(&self) -> Value {
+        Value::String(format_duration(self))
+    }
+}
+
+impl FromValue for Duration {
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        let value = value?;
+        parse_duration(value.string()?).map_err(InputValueError::custom)
+    }
+}
+
+fn format_duration(duration: &Duration) -> String {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    if secs == 0 && nanos == 0 {
+        return "PT0S".to_string();
+    }
+    let days = secs / SECONDS_PER_DAY;
+    let hours = secs % SECONDS_PER_DAY / SECONDS_PER_HOUR;
+    let minutes = secs % SECONDS_PER_HOUR / SECONDS_PER_MINUTE;
+    let seconds = secs % SECONDS_PER_MINUTE;
+
+    let mut result = "P".to_string();
+    if days > 0 {
+        result.push_str(&format!("{}D", days));
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && nanos == 0 {
+        return result;
+    }
+    result.push('T');
+    if hours > 0 {
+        result.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        result.push_str(&format!("{}M", minutes));
+    }
+    if seconds > 0 || nanos > 0 {
+        result.push_str(&seconds.to_string());
+        if nanos > 0 {
+            let fraction = format!("{:09}", nanos);
+            result.push('.');
+            result.push_str(fraction.trim_end_matches('0'));
+        }
+        result.push('S');
+    }
+    result
+}
+
+/// parse `PnWnDTnHnMnS`, only the seconds can have a fraction, years and months are not
+/// supported because their length is not fixed
+fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let invalid = || format!("invalid duration \"{}\"", value);
+    let rest = value.strip_prefix('P').ok_or_else(invalid)?;
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return Err(invalid()),
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return Err(invalid());
+    }
+
+    let mut duration = Duration::ZERO;
+    for (number, unit) in components(date).ok_or_else(invalid)? {
+        let secs = match unit {
+            'W' => 7 * SECONDS_PER_DAY,
+            'D' => SECONDS_PER_DAY,
+            'Y' | 'M' => return Err("years and months are not supported".to_string()),
+            _ => return Err(invalid()),
+        };
+        let component = parse_units(number, secs).ok_or_else(invalid)?;
+        duration = duration.checked_add(component).ok_or_else(invalid)?;
+    }
+    for (number, unit) in components(time.unwrap_or_default()).ok_or_else(invalid)? {
+        let component = match unit {
+            'H' => parse_units(number, SECONDS_PER_HOUR),
+            'M' => parse_units(number, SECONDS_PER_MINUTE),
+            'S' => parse_seconds(number),
+            _ => return Err(invalid()),
+        };
+        duration = duration
+            .checked_add(component.ok_or_else(invalid)?)
+            .ok_or_else(invalid)?;
+    }
+    Ok(duration)
+}
+
+/// split `1D2W` to `[("1", 'D'), ("2", 'W')]`
+fn components(value: &str) -> Option<Vec<(&str, char)>> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    for (index, c) in value.char_indices() {
+        if c.is_ascii_alphabetic() {
+            if index == start {
+                return None;
+            }
+            result.push((&value[start..index], c));
+            start = index + c.len_utf8();
+        }
+    }
+    (start == value.len()).then_some(result)
+}
+
+/// `value` units of `secs` seconds, `None` when it is not a number or overflows
+fn parse_units(value: &str, secs: u64) -> Option<Duration> {
+    let number: u64 = value.parse().ok()?;
+    number.checked_mul(secs).map(Duration::from_secs)
+}
+
+fn parse_seconds(value: &str) -> Option<Duration> {
+    let (secs, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let secs = secs.parse().ok()?;
+    if fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", fraction).parse().ok()?
+    };
+    Some(Duration::new(secs, nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(&Duration::ZERO), "PT0S");
+        assert_eq!(format_duration(&Duration::from_secs(86400)), "P1D");
+        assert_eq!(format_duration(&Duration::from_millis(500)), "PT0.5S");
+        assert_eq!(
+            format_duration(&Duration::new(86400 + 2 * 3600 + 3 * 60 + 4, 500_000_000)),
+            "P1DT2H3M4.5S"
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT0S"), Ok(Duration::ZERO));
+        assert_eq!(parse_duration("P1W"), Ok(Duration::from_secs(7 * 86400)));
+        assert_eq!(
+            parse_duration("P1DT2H3M4.5S"),
+            Ok(Duration::new(86400 + 2 * 3600 + 3 * 60 + 4, 500_000_000))
+        );
+        assert_eq!(parse_duration("PT90M"), Ok(Duration::from_secs(90 * 60)));
+        assert!(parse_duration("P1Y").is_err());
+        assert!(parse_duration("P").is_err());
+        assert!(parse_duration("PT").is_err());
+        assert!(parse_duration("1D").is_err());
+        assert!(parse_duration("PT1.5M").is_err());
+        assert!(parse_duration("PTS").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration_overflow() {
+        let max = u64::MAX;
+        assert!(parse_duration(&format!("P{}W", max)).is_err());
+        assert!(parse_duration(&format!("P{}D", max)).is_err());
+        assert!(parse_duration(&format!("PT{}H", max)).is_err());
+        assert!(parse_duration(&format!("PT{}M", max)).is_err());
+        assert!(parse_duration(&format!("PT{}S", max)).is_ok());
+        assert!(parse_duration(&format!("P1DT{}S", max)).is_err());
+        assert!(parse_duration(&format!("PT1H{}S", max)).is_err());
+        assert!(parse_duration(&format!("PT{}.5S", max)).is_ok());
+    }
+}
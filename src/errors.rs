@@ -57,6 +57,16 @@ impl<T> InputValueError<T> {
         ))
     }
 
+    /// An error returned by a validator, the message and extensions are kept as is.
+    #[must_use]
+    pub fn validation(error: crate::Error) -> Self {
+        Self {
+            message: error.message,
+            extensions: error.extensions,
+            phantom: PhantomData,
+        }
+    }
+
     /// Propagate the error message to a different type.
     pub fn propagate<U: GetInputTypeRef>(self) -> InputValueError<U>
     where
@@ -123,3 +133,166 @@ impl<T> From<InputValueError<T>> for async_graphql::Error {
 }
 
 pub type InputValueResult<T> = Result<T, InputValueError<T>>;
+
+/// A problem of the registered types, found when the schema is created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryIssue {
+    /// no object is set as the query root
+    MissingRoot,
+    /// the object or subscription expanded by `expansion` is not registered
+    MissingExpandTarget {
+        target: String,
+        expansion: String,
+        rust_type: Option<&'static str>,
+    },
+    /// different rust types are registered with the same name
+    DuplicateType {
+        name: String,
+        rust_types: Vec<Option<&'static str>>,
+    },
+    /// the same field is added to an object by different expansions
+    DuplicateField {
+        type_name: String,
+        field_name: String,
+        rust_types: Vec<Option<&'static str>>,
+    },
+    /// the interface implemented by a type is not registered
+    UnknownInterface {
+        type_name: String,
+        interface: String,
+        rust_type: Option<&'static str>,
+    },
+    /// the possible type of a union is not registered
+    UnknownPossibleType {
+        union: String,
+        possible_type: String,
+        rust_type: Option<&'static str>,
+    },
+    /// the query root is removed by the tag filter
+    ExcludedRoot { name: String },
+    /// a kept type, or a field of the type, references a type removed by the tag filter
+    DanglingReference {
+        type_name: String,
+        field_name: Option<String>,
+        reference: String,
+    },
+    /// different directives are defined with the same name
+    DuplicateDirective { name: String },
+}
+
+/// the rust type that registered something, `None` when the registry is used directly
+fn rust_type_name(rust_type: &Option<&'static str>) -> &'static str {
+    rust_type.unwrap_or("the registry")
+}
+
+fn rust_type_names(rust_types: &[Option<&'static str>]) -> String {
+    rust_types
+        .iter()
+        .map(rust_type_name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Display for RegistryIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistryIssue::MissingRoot => write!(f, "No root object defined"),
+            RegistryIssue::MissingExpandTarget {
+                target,
+                expansion,
+                rust_type,
+            } => write!(
+                f,
+                "Can't find object: \"{} when defining {}\" (registered by {})",
+                target,
+                expansion,
+                rust_type_name(rust_type)
+            ),
+            RegistryIssue::DuplicateType { name, rust_types } => write!(
+                f,
+                "Type \"{}\" is registered by different types: {}",
+                name,
+                rust_type_names(rust_types)
+            ),
+            RegistryIssue::DuplicateField {
+                type_name,
+                field_name,
+                rust_types,
+            } => write!(
+                f,
+                "Field \"{}.{}\" is added by different types: {}",
+                type_name,
+                field_name,
+                rust_type_names(rust_types)
+            ),
+            RegistryIssue::UnknownInterface {
+                type_name,
+                interface,
+                rust_type,
+            } => write!(
+                f,
+                "Type \"{}\" implements the unknown interface \"{}\" (registered by {})",
+                type_name,
+                interface,
+                rust_type_name(rust_type)
+            ),
+            RegistryIssue::UnknownPossibleType {
+                union,
+                possible_type,
+                rust_type,
+            } => write!(
+                f,
+                "Union \"{}\" has the unknown possible type \"{}\" (registered by {})",
+                union,
+                possible_type,
+                rust_type_name(rust_type)
+            ),
+            RegistryIssue::ExcludedRoot { name } => {
+                write!(
+                    f,
+                    "The root object \"{}\" is removed by the tag filter",
+                    name
+                )
+            }
+            RegistryIssue::DanglingReference {
+                type_name,
+                field_name: Some(field_name),
+                reference,
+            } => write!(
+                f,
+                "Field \"{}.{}\" references the removed type \"{}\"",
+                type_name, field_name, reference
+            ),
+            RegistryIssue::DanglingReference {
+                type_name,
+                field_name: None,
+                reference,
+            } => write!(
+                f,
+                "Type \"{}\" references the removed type \"{}\"",
+                type_name, reference
+            ),
+            RegistryIssue::DuplicateDirective { name } => {
+                write!(f, "Directive \"@{}\" is defined more than once", name)
+            }
+        }
+    }
+}
+
+/// All the problems of the registered types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryError {
+    pub issues: Vec<RegistryIssue>,
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid schema:")?;
+        for issue in &self.issues {
+            write!(f, "\n- {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RegistryError {}
@@ -0,0 +1,150 @@
+//! Apollo Federation v2 subgraph support.
+//!
+//! Federation is enabled with `Registry::enable_federation` or by adding [`Federation`] to the
+//! app. Objects declare their keys with `#[graphql(key = "id")]`, and methods of
+//! `ResolvedObjectFields` or `ExpandObjectFields` marked with `#[graphql(entity)]` resolve the
+//! representations of `_entities(representations:)`.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use async_graphql::futures_util::future::join_all;
+use async_graphql::futures_util::future::BoxFuture;
+
+use crate::dynamic;
+use crate::dynamic::ObjectAccessor;
+use crate::null_items::item_or_null;
+use crate::registry::Registry;
+use crate::types::Register;
+use crate::Context;
+use crate::Error;
+use crate::FieldValue;
+use crate::Result;
+
+/// Enables federation when it's registered, e.g. `#[derive(App)] struct App(Query, Federation);`
+pub struct Federation;
+
+impl Register for Federation {
+    fn register(registry: Registry) -> Registry {
+        registry.enable_federation()
+    }
+}
+
+/// Context of an entity resolver, the arguments are the fields of the representation
+pub struct EntityContext<'a> {
+    pub ctx: &'a Context<'a>,
+    pub args: ObjectAccessor<'a>,
+    pub parent_value: &'a FieldValue<'a>,
+}
+
+impl<'a> Deref for EntityContext<'a> {
+    type Target = Context<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.ctx
+    }
+}
+
+type EntityResolverFn = Box<
+    dyn for<'a> Fn(EntityContext<'a>) -> BoxFuture<'a, Result<Option<FieldValue<'static>>>>
+        + Send
+        + Sync,
+>;
+
+struct EntityResolver {
+    type_name: String,
+    /// fields of the representation required by the resolver
+    keys: Vec<String>,
+    resolver: EntityResolverFn,
+}
+
+#[derive(Default)]
+pub(crate) struct EntityResolvers(Vec<EntityResolver>);
+
+impl EntityResolvers {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn type_names(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|resolver| resolver.type_name.as_str())
+    }
+
+    pub(crate) fn add<F>(&mut self, type_name: &str, keys: &[&str], resolver: F)
+    where
+        F: for<'a> Fn(EntityContext<'a>) -> BoxFuture<'a, Result<Option<FieldValue<'static>>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.0.push(EntityResolver {
+            type_name: type_name.to_string(),
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            resolver: Box::new(resolver),
+        });
+    }
+
+    /// the first resolver of the type that has all of its keys in the representation
+    fn find(
+        &self,
+        type_name: &str,
+        representation: &ObjectAccessor<'_>,
+    ) -> Option<&EntityResolver> {
+        self.0.iter().find(|resolver| {
+            resolver.type_name == type_name
+                && resolver
+                    .keys
+                    .iter()
+                    .all(|key| representation.get(key).is_some())
+        })
+    }
+}
+
+/// resolver of the `_entities` field
+///
+/// The items of `[_Entity]!` are nullable: an entity that is not found is null, and a
+/// representation that can't be resolved is null with an error at its index.
+pub(crate) fn resolve_entities<'a>(
+    resolvers: Arc<EntityResolvers>,
+    ctx: dynamic::ResolverContext<'a>,
+) -> dynamic::FieldFuture<'a> {
+    dynamic::FieldFuture::new(async move {
+        let representations = ctx.args.try_get("representations")?.list()?;
+        // the entities are resolved concurrently, so their loaders are batched
+        let entities = join_all(
+            representations
+                .iter()
+                .map(|representation| resolve_entity(&resolvers, &ctx, representation)),
+        )
+        .await;
+        let values = entities
+            .into_iter()
+            .enumerate()
+            .map(|(index, entity)| item_or_null(ctx.ctx, index, entity))
+            .collect::<Vec<_>>();
+        Ok(Some(FieldValue::list(values)))
+    })
+}
+
+async fn resolve_entity<'a>(
+    resolvers: &EntityResolvers,
+    ctx: &dynamic::ResolverContext<'a>,
+    representation: dynamic::ValueAccessor<'a>,
+) -> Result<Option<FieldValue<'static>>> {
+    let representation = representation.object()?;
+    let type_name = representation.try_get("__typename")?.string()?;
+    let Some(resolver) = resolvers.find(type_name, &representation) else {
+        return Err(Error::new(format!(
+            "no entity resolver for \"{}\" matches the representation",
+            type_name
+        )));
+    };
+    let type_name = type_name.to_string();
+    let value = (resolver.resolver)(EntityContext {
+        ctx: ctx.ctx,
+        args: representation,
+        parent_value: ctx.parent_value,
+    })
+    .await?;
+    Ok(value.map(|value| value.with_type(type_name)))
+}
@@ -1,3 +1,20 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroI8;
+use std::num::NonZeroIsize;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroU8;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
 use crate::dynamic;
 use crate::errors::InputValueError;
 use crate::errors::InputValueResult;
@@ -62,7 +79,7 @@ macro_rules! int_from_value {
         $(
             impl FromValue for $t {
                 fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
-                    Self::try_from(value?.u64()?).map_err(|_| {
+                    Self::try_from(value?.i64()?).map_err(|_| {
                         InputValueError::custom(format!(
                             "Only integers from {} to {} are accepted for {}.",
                             Self::MIN,
@@ -79,6 +96,36 @@ macro_rules! int_from_value {
 uint_from_value!(u8, u16, u32, u64, usize);
 int_from_value!(i8, i16, i32, i64, isize);
 
+macro_rules! non_zero_from_value {
+    ($($t:ty => $int:ty),*) => {
+        $(
+            impl FromValue for $t {
+                fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+                    let value = <$int>::from_value(value).map_err(InputValueError::propagate)?;
+                    Self::new(value).ok_or_else(|| InputValueError::custom("zero is not allowed"))
+                }
+            }
+        )*
+    };
+}
+
+non_zero_from_value!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroIsize => isize
+);
+
+impl FromValue for char {
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        let value = value?;
+        let value = value.string()?;
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(InputValueError::custom("expected a single character")),
+        }
+    }
+}
+
 impl<T> FromValue for Option<T>
 where
     T: FromValue + GetInputTypeRef,
@@ -131,3 +178,111 @@ where
             .collect()
     }
 }
+
+impl<T> FromValue for Box<[T]>
+where
+    T: FromValue + GetInputTypeRef,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        Vec::<T>::from_value(value)
+            .map(Vec::into_boxed_slice)
+            .map_err(InputValueError::propagate)
+    }
+}
+
+impl<T> FromValue for Box<T>
+where
+    T: FromValue + GetInputTypeRef,
+    Self: GetInputTypeRef,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        T::from_value(value)
+            .map(Box::new)
+            .map_err(InputValueError::propagate)
+    }
+}
+
+impl<T> FromValue for Arc<T>
+where
+    T: FromValue + GetInputTypeRef,
+    Self: GetInputTypeRef,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        T::from_value(value)
+            .map(Arc::new)
+            .map_err(InputValueError::propagate)
+    }
+}
+
+impl<T> FromValue for VecDeque<T>
+where
+    T: FromValue + GetInputTypeRef,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        Vec::<T>::from_value(value)
+            .map(VecDeque::from)
+            .map_err(InputValueError::propagate)
+    }
+}
+
+impl<T, const N: usize> FromValue for [T; N]
+where
+    T: FromValue + GetInputTypeRef,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        let items = Vec::<T>::from_value(value).map_err(InputValueError::propagate)?;
+        let len = items.len();
+        items.try_into().map_err(|_| {
+            InputValueError::custom(format!("expected a list of {} items, found {}", N, len))
+        })
+    }
+}
+
+/// collect the items of a set, duplicated items are rejected
+fn set_from_value<T, S>(
+    value: Result<dynamic::ValueAccessor>,
+    mut insert: impl FnMut(&mut S, T) -> bool,
+) -> InputValueResult<S>
+where
+    T: FromValue + GetInputTypeRef,
+    S: Default + GetInputTypeRef,
+{
+    let mut set = S::default();
+    for item in value?.list()?.iter() {
+        let item = T::from_value(Ok(item)).map_err(InputValueError::propagate)?;
+        if !insert(&mut set, item) {
+            return Err(InputValueError::custom("duplicated items are not allowed"));
+        }
+    }
+    Ok(set)
+}
+
+impl<T, S> FromValue for HashSet<T, S>
+where
+    T: FromValue + GetInputTypeRef + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        set_from_value(value, HashSet::insert)
+    }
+}
+
+impl<T> FromValue for BTreeSet<T>
+where
+    T: FromValue + GetInputTypeRef + Ord,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        set_from_value(value, BTreeSet::insert)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> FromValue for indexmap::IndexSet<T, S>
+where
+    T: FromValue + GetInputTypeRef + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        set_from_value(value, indexmap::IndexSet::insert)
+    }
+}
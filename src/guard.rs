@@ -0,0 +1,67 @@
+use std::future::Future;
+
+use crate::Context;
+use crate::Result;
+
+/// A check that runs before a field is resolved.
+///
+/// Guards are attached to fields with `#[graphql(guard = "expr")]`, if the guard returns an error
+/// the field is not resolved and the error is returned instead.
+pub trait Guard {
+    fn check(&self, ctx: &Context<'_>) -> impl Future<Output = Result<()>> + Send;
+}
+
+impl<F> Guard for F
+where
+    F: Fn(&Context<'_>) -> Result<()>,
+{
+    fn check(&self, ctx: &Context<'_>) -> impl Future<Output = Result<()>> + Send {
+        let result = self(ctx);
+        async move { result }
+    }
+}
+
+/// Combinators for [`Guard`]
+pub trait GuardExt: Guard + Sized {
+    /// both guards must pass
+    fn and<R: Guard>(self, other: R) -> And<Self, R> {
+        And(self, other)
+    }
+
+    /// at least one of the guards must pass, the error of the second guard is returned if both fail
+    fn or<R: Guard>(self, other: R) -> Or<Self, R> {
+        Or(self, other)
+    }
+}
+
+impl<T: Guard> GuardExt for T {}
+
+/// Guard returned by [`GuardExt::and`]
+pub struct And<A, B>(A, B);
+
+impl<A, B> Guard for And<A, B>
+where
+    A: Guard + Sync,
+    B: Guard + Sync,
+{
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        self.0.check(ctx).await?;
+        self.1.check(ctx).await
+    }
+}
+
+/// Guard returned by [`GuardExt::or`]
+pub struct Or<A, B>(A, B);
+
+impl<A, B> Guard for Or<A, B>
+where
+    A: Guard + Sync,
+    B: Guard + Sync,
+{
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        match self.0.check(ctx).await {
+            Ok(()) => Ok(()),
+            Err(_) => self.1.check(ctx).await,
+        }
+    }
+}
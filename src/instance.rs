@@ -51,6 +51,25 @@ where
     }
 }
 
+impl<'v, I: ?Sized> Instance<'v, I>
+where
+    I: Interface,
+{
+    /// convert the instance to an instance of a parent interface of `I`
+    #[inline]
+    pub fn upcast<P>(self) -> Instance<'v, P>
+    where
+        P: Interface + ?Sized,
+        I: InterfaceMark<P>,
+    {
+        Instance {
+            _interface: std::marker::PhantomData,
+            _target: std::marker::PhantomData,
+            value: self.value,
+        }
+    }
+}
+
 impl<'a, I> ResolveOwned<'a> for Instance<'a, I>
 where
     I: ?Sized + Interface,
@@ -0,0 +1,181 @@
+//! The `JSON` scalar, values are converted with serde.
+//!
+//! [`Json<T>`], `serde_json::Value`, `HashMap<String, V>` and `BTreeMap<String, V>` are all
+//! mapped to the same `JSON` scalar.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Settings {
+//!     theme: String,
+//! }
+//!
+//! #[ResolvedObjectFields]
+//! impl Query {
+//!     fn settings(&self) -> Json<Settings> {
+//!         todo!()
+//!     }
+//!     fn save(&self, settings: Json<Settings>) -> HashMap<String, u32> {
+//!         todo!()
+//!     }
+//! }
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::dynamic;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::resolve_shared_value;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::resolve::ResolveShared;
+use crate::resolve::Shared;
+use crate::to_value::ToValue;
+use crate::types::InputTypeName;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::Scalar;
+use crate::types::TypeName;
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::Value;
+
+const JSON: &str = "JSON";
+
+/// registers the `JSON` scalar once for all the json types
+struct JsonScalar;
+
+impl Register for JsonScalar {
+    fn register(registry: Registry) -> Registry {
+        registry.register_type(dynamic::Scalar::new(JSON).description("A JSON value"))
+    }
+}
+
+/// A value of any serde type, as a `JSON` scalar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Json<T>(pub T);
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Json(value)
+    }
+}
+
+impl<T> Json<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+fn to_field_value<'a, T: Serialize>(value: &T) -> Result<Option<FieldValue<'a>>> {
+    Ok(Some(FieldValue::value(async_graphql::to_value(value)?)))
+}
+
+fn from_json_value<T>(value: Result<dynamic::ValueAccessor>) -> InputValueResult<T>
+where
+    T: DeserializeOwned + InputTypeName,
+{
+    let value = value?.as_value().clone();
+    async_graphql::from_value(value).map_err(InputValueError::custom)
+}
+
+macro_rules! json_types {
+    ($(impl<$($generic:ident),*> for $ty:ty where [$($bound:tt)*];)*) => {
+        $(
+            impl<$($generic),*> Register for $ty where $($bound)* {
+                fn register(registry: Registry) -> Registry {
+                    registry.register::<JsonScalar>()
+                }
+            }
+            impl<$($generic),*> TypeName for $ty where $($bound)* {
+                fn get_type_name() -> Cow<'static, str> {
+                    JSON.into()
+                }
+            }
+            impl<$($generic),*> OutputTypeName for $ty where $($bound)* {}
+            impl<$($generic),*> InputTypeName for $ty where $($bound)* {}
+            impl<$($generic),*> Scalar for $ty where $($bound)* {}
+
+            impl<'a, $($generic),*> ResolveOwned<'a> for $ty where $($bound)* {
+                fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    to_field_value(&self)
+                }
+            }
+            impl<'a, $($generic),*> ResolveRef<'a> for $ty where $($bound)* {
+                fn resolve_ref(&'a self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    to_field_value(self)
+                }
+            }
+            impl<'a, $($generic),*> ResolveShared<'a> for $ty where $($bound)* {
+                fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_shared_value(&shared, ctx)
+                }
+            }
+            impl<$($generic),*> FromValue for $ty where $($bound)* {
+                fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+                    from_json_value(value)
+                }
+            }
+            impl<$($generic),*> ToValue for $ty where $($bound)* {
+                fn to_value(&self) -> Value {
+                    async_graphql::to_value(self).unwrap_or(Value::Null)
+                }
+            }
+        )*
+    };
+}
+
+json_types! {
+    impl<T> for Json<T> where [T: Serialize + DeserializeOwned];
+    impl<> for serde_json::Value where [];
+    impl<V, S> for HashMap<String, V, S> where [V: Serialize + DeserializeOwned, S: BuildHasher + Default];
+    impl<V> for BTreeMap<String, V> where [V: Serialize + DeserializeOwned];
+}
+
+impl<T> Serialize for Json<T>
+where
+    T: Serialize,
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Json<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Json)
+    }
+}
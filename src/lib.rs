@@ -1,13 +1,34 @@
 mod any_box;
+mod big_int;
+mod complexity;
+pub mod connection;
 mod data;
+pub mod dataloader;
+pub mod diff;
+mod directives;
+mod duration;
 mod errors;
+pub mod federation;
 mod from_value;
+mod guard;
 mod instance;
+#[cfg(feature = "json")]
+mod json;
+mod list_iter;
+mod null_items;
 mod registry;
+#[cfg(feature = "relay")]
+pub mod relay;
 mod resolve;
+pub mod sdl;
+mod subscription;
+mod tags;
+mod to_value;
 mod type_ref_builder;
 mod types;
 mod upload;
+pub mod validators;
+mod visibility;
 
 #[doc(no_inline)]
 pub use async_graphql::dynamic;
@@ -22,6 +43,8 @@ pub use async_graphql::Data;
 #[doc(no_inline)]
 pub use async_graphql::Error;
 #[doc(no_inline)]
+pub use async_graphql::ErrorExtensions;
+#[doc(no_inline)]
 pub use async_graphql::Lookahead;
 #[doc(no_inline)]
 pub use async_graphql::MaybeUndefined;
@@ -32,6 +55,8 @@ pub use async_graphql::Request;
 #[doc(no_inline)]
 pub use async_graphql::Result;
 #[doc(no_inline)]
+pub use async_graphql::ResultExt;
+#[doc(no_inline)]
 pub use async_graphql::Upload;
 #[doc(no_inline)]
 pub use async_graphql::UploadValue;
@@ -44,17 +69,33 @@ pub use async_graphql::ID;
 
 pub mod internal {
     pub use crate::any_box::AnyBox;
+    pub use crate::complexity::Complexity;
+    pub use crate::dataloader::get_loader;
+    pub use crate::dataloader::LoaderArg;
     pub use crate::errors::InputValueError;
     pub use crate::errors::InputValueResult;
     pub use crate::from_value::FromValue;
+    pub use crate::guard::Guard;
     pub use crate::instance::RegisterInstance;
     pub use crate::registry::Registry;
+    #[cfg(feature = "relay")]
+    pub use crate::relay::register_node;
+    #[cfg(feature = "relay")]
+    pub use crate::relay::FetchNode;
+    pub use crate::resolve::downcast_parent;
+    pub use crate::resolve::resolve_shared_value;
     pub use crate::resolve::Resolve;
     pub use crate::resolve::ResolveOwned;
     pub use crate::resolve::ResolveRef;
+    pub use crate::resolve::ResolveShared;
+    pub use crate::resolve::Shared;
+    pub use crate::subscription::resolve_stream;
+    pub use crate::to_value::object_value;
+    pub use crate::to_value::ToValue;
     pub use crate::type_ref_builder::TypeRefBuilder;
     pub use crate::types::Enum;
     pub use crate::types::ExpandObject;
+    pub use crate::types::ExpandSubscription;
     pub use crate::types::GetInputTypeRef;
     pub use crate::types::GetOutputTypeRef;
     pub use crate::types::InputObject;
@@ -68,18 +109,29 @@ pub mod internal {
     pub use crate::types::Register;
     pub use crate::types::RegisterFns;
     pub use crate::types::Scalar;
+    pub use crate::types::Subscription;
     pub use crate::types::TypeName;
     pub use crate::types::Union;
+    pub use crate::validators::register_scalar_validator;
+    pub use crate::validators::validate_scalar;
+    pub use crate::validators::ScalarValidatorResult;
+    pub use crate::visibility::hidden;
 }
 
 pub mod experimental {
     pub use crate::data::GetSchemaData;
 }
 
+pub use big_int::BigInt;
+pub use complexity::Complexity;
+pub use complexity::FieldArgs;
+pub use directives::DirectiveDefinition;
+pub use directives::DirectiveLocation;
 pub use dynamic_graphql_derive::App;
 pub use dynamic_graphql_derive::Enum;
 pub use dynamic_graphql_derive::ExpandObject;
 pub use dynamic_graphql_derive::ExpandObjectFields;
+pub use dynamic_graphql_derive::GraphQLError;
 pub use dynamic_graphql_derive::InputObject;
 #[doc = include_str!("./docs/interface.md")]
 pub use dynamic_graphql_derive::Interface;
@@ -93,6 +145,22 @@ pub use dynamic_graphql_derive::ResolvedObjectFields;
 pub use dynamic_graphql_derive::Scalar;
 #[doc = include_str!("./docs/simple-object.md")]
 pub use dynamic_graphql_derive::SimpleObject;
+pub use dynamic_graphql_derive::Subscription;
+pub use dynamic_graphql_derive::SubscriptionFields;
+pub use dynamic_graphql_derive::SubscriptionRoot;
 pub use dynamic_graphql_derive::Union;
+pub use errors::RegistryError;
+pub use errors::RegistryIssue;
+pub use guard::And;
+pub use guard::Guard;
+pub use guard::GuardExt;
+pub use guard::Or;
 pub use instance::Instance;
+#[cfg(feature = "json")]
+pub use json::Json;
+pub use list_iter::ListIter;
+pub use resolve::IntOverflowPolicy;
+pub use tags::TagFilter;
 pub use types::ScalarValue;
+pub use visibility::VisibleContext;
+pub use visibility::VisibleFn;
@@ -0,0 +1,53 @@
+use crate::registry::Registry;
+use crate::resolve::resolve_list;
+use crate::resolve::Resolve;
+use crate::resolve::ResolveOwned;
+use crate::type_ref_builder::TypeRefBuilder;
+use crate::types::GetOutputTypeRef;
+use crate::types::Register;
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+
+/// A list of the items of an iterator, the items are resolved without collecting them first
+///
+/// ```ignore
+/// #[ResolvedObjectFields]
+/// impl Query {
+///     fn squares(&self) -> ListIter<Box<dyn Iterator<Item = u32> + Send>> {
+///         ListIter(Box::new((1..=self.count).map(|n| n * n)))
+///     }
+/// }
+/// ```
+pub struct ListIter<I>(pub I);
+
+impl<I> Register for ListIter<I>
+where
+    I: IntoIterator,
+    I::Item: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<I::Item>()
+    }
+}
+
+impl<I> GetOutputTypeRef for ListIter<I>
+where
+    I: IntoIterator,
+    I::Item: GetOutputTypeRef,
+{
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        I::Item::get_output_type_ref().list()
+    }
+}
+
+impl<'a, I> ResolveOwned<'a> for ListIter<I>
+where
+    I: IntoIterator,
+    I::Item: GetOutputTypeRef + Resolve<'a>,
+{
+    fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        resolve_list::<I::Item, _>(self.0.into_iter(), ctx)
+    }
+}
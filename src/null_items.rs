@@ -0,0 +1,103 @@
+//! Null items in lists of unions and interfaces.
+//!
+//! The dynamic schema requires `FieldValue::WithType` for every item of a list of unions or
+//! interfaces, so a `FieldValue::NULL` item is an error even when the item type is nullable.
+//! The resolvers of these lists use [`null_item`] for the missing items, and the [`NullItems`]
+//! extension resolves them to null.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_graphql::extensions::Extension;
+use async_graphql::extensions::ExtensionContext;
+use async_graphql::extensions::ExtensionFactory;
+use async_graphql::extensions::NextPrepareRequest;
+use async_graphql::extensions::NextResolve;
+use async_graphql::extensions::ResolveInfo;
+use async_graphql::PathSegment;
+use async_graphql::Request;
+use async_graphql::ServerResult;
+
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::Value;
+
+/// paths of the null items of the request, e.g. `nodes.1`
+#[derive(Default)]
+struct NullItemPaths(Mutex<HashSet<String>>);
+
+/// The item at `index` of the list resolved by `ctx`, resolved to null
+pub(crate) fn null_item(ctx: &Context<'_>, index: usize) -> FieldValue<'static> {
+    if let (Some(paths), Some(path_node)) = (ctx.data_opt::<NullItemPaths>(), ctx.path_node) {
+        paths
+            .0
+            .lock()
+            .unwrap()
+            .insert(format!("{}.{}", path_node, index));
+    }
+    FieldValue::NULL
+}
+
+/// The item at `index` of the list resolved by `ctx`, an item that is missing or that failed is
+/// null and its error is added with the path of the item
+pub(crate) fn item_or_null<'a>(
+    ctx: &Context<'_>,
+    index: usize,
+    item: Result<Option<FieldValue<'a>>>,
+) -> FieldValue<'a> {
+    match item {
+        Ok(Some(value)) => value,
+        Ok(None) => null_item(ctx, index),
+        Err(error) => {
+            let mut error = ctx.set_error_path(error.into_server_error(ctx.item.pos));
+            error.path.push(PathSegment::Index(index));
+            ctx.add_error(error);
+            null_item(ctx, index)
+        }
+    }
+}
+
+pub(crate) struct NullItems;
+
+impl ExtensionFactory for NullItems {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(NullItemsExtension)
+    }
+}
+
+struct NullItemsExtension;
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for NullItemsExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        next.run(ctx, request.data(NullItemPaths::default())).await
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        // list items have the list as parent type
+        if info.parent_type.starts_with('[') {
+            if let Some(paths) = ctx.data_opt::<NullItemPaths>() {
+                let is_null = {
+                    let paths = paths.0.lock().unwrap();
+                    !paths.is_empty() && paths.contains(&info.path_node.to_string())
+                };
+                if is_null {
+                    return Ok(None);
+                }
+            }
+        }
+        next.run(ctx, info).await
+    }
+}
@@ -3,10 +3,36 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::mem;
+use std::sync::Arc;
 
+use async_graphql::futures_util::future::BoxFuture;
+
+use crate::complexity::Complexity;
+use crate::complexity::ComplexityLimit;
+use crate::complexity::FieldComplexities;
 use crate::data::SchemaData;
+use crate::dataloader::is_cached_loader;
+use crate::dataloader::LoaderScope;
+use crate::directives::DirectiveDefinition;
 use crate::dynamic;
+use crate::errors::RegistryError;
+use crate::errors::RegistryIssue;
+use crate::federation::resolve_entities;
+use crate::federation::EntityContext;
+use crate::federation::EntityResolvers;
+use crate::null_items::NullItems;
+use crate::resolve::IntOverflowPolicy;
+use crate::tags::Reference;
+use crate::tags::TagFilter;
+use crate::tags::TagGraph;
+use crate::tags::TaggedField;
+use crate::tags::TaggedFieldValue;
 use crate::types::Register;
+use crate::visibility::Visibilities;
+use crate::visibility::VisibilityFilter;
+use crate::visibility::VisibleFn;
+use crate::FieldValue;
+use crate::Result;
 
 pub struct Registry {
     pub data: SchemaData,
@@ -14,10 +40,39 @@ pub struct Registry {
     mutation: Option<String>,
     subscription: Option<String>,
     objects: HashMap<String, dynamic::Object>,
+    subscriptions: HashMap<String, dynamic::Subscription>,
     types: Vec<dynamic::Type>,
     // name of all registered types
     names: HashSet<TypeId>,
-    pending_expand_objects: VecDeque<PendingExpandObject>,
+    pending_expand_objects: VecDeque<PendingExpand<dynamic::Object>>,
+    pending_expand_subscriptions: VecDeque<PendingExpand<dynamic::Subscription>>,
+    // expansions of the root object, the target is known when the schema is created
+    pending_expand_root: VecDeque<PendingExpand<dynamic::Object>>,
+    complexities: FieldComplexities,
+    limit_complexity: Option<usize>,
+    limit_depth: Option<usize>,
+    federation: bool,
+    entities: EntityResolvers,
+    // a list of unions or interfaces has null items
+    null_items: bool,
+    visibilities: Visibilities,
+    // the loaders used by the resolvers, to know if a loader of the schema data caches values
+    loaders: HashMap<TypeId, fn(&SchemaData) -> bool>,
+    directives: Vec<DirectiveDefinition>,
+    type_tags: HashMap<String, Vec<String>>,
+    tagged_fields: Vec<TaggedField>,
+    // (type name, field name) of the fields and the types they use
+    field_types: Vec<(String, String, Vec<String>)>,
+    // the rust types that are being registered, the last one registers the types
+    registering: Vec<&'static str>,
+    // the registered type names and the rust types that registered them
+    type_names: Vec<(String, Option<&'static str>)>,
+    // (type name, field name) of the fields added by expansions
+    expanded_fields: Vec<(String, String, Option<&'static str>)>,
+    // (type name, interface name) of the implemented interfaces
+    implements: Vec<(String, String, Option<&'static str>)>,
+    // (union name, type name) of the possible types of unions
+    possible_types: Vec<(String, String, Option<&'static str>)>,
 }
 
 impl Default for Registry {
@@ -34,17 +89,48 @@ impl Registry {
             mutation: None,
             subscription: None,
             objects: Default::default(),
+            subscriptions: Default::default(),
             types: Default::default(),
             names: Default::default(),
             pending_expand_objects: Default::default(),
+            pending_expand_subscriptions: Default::default(),
+            pending_expand_root: Default::default(),
+            complexities: Default::default(),
+            limit_complexity: None,
+            limit_depth: None,
+            federation: false,
+            entities: Default::default(),
+            null_items: false,
+            visibilities: Default::default(),
+            loaders: Default::default(),
+            directives: Default::default(),
+            type_tags: Default::default(),
+            tagged_fields: Default::default(),
+            field_types: Default::default(),
+            registering: Default::default(),
+            type_names: Default::default(),
+            expanded_fields: Default::default(),
+            implements: Default::default(),
+            possible_types: Default::default(),
         }
     }
 }
 
-struct PendingExpandObject {
+struct PendingExpand<T> {
     target: String,
     expansion: String,
-    map_fn: Box<dyn FnOnce(dynamic::Object) -> dynamic::Object>,
+    rust_type: Option<&'static str>,
+    map_fn: Box<dyn FnOnce(T) -> T>,
+}
+
+impl<T> PendingExpand<T> {
+    fn missing_target(&self) -> RegistryIssue {
+        RegistryIssue::MissingExpandTarget {
+            target: self.target.clone(),
+            expansion: self.expansion.clone(),
+            rust_type: self.rust_type,
+        }
+    }
 }
 
 impl Registry {
@@ -65,10 +151,16 @@ impl Registry {
     }
     pub fn register_type(mut self, ty: impl Into<dynamic::Type>) -> Self {
         let ty = ty.into();
+        self.type_names
+            .push((type_name(&ty).to_string(), self.current_rust_type()));
         match ty {
             dynamic::Type::Object(object) => {
                 self.objects.insert(object.type_name().to_string(), object);
             }
+            dynamic::Type::Subscription(subscription) => {
+                self.subscriptions
+                    .insert(subscription.type_name().to_string(), subscription);
+            }
             _ => {
                 self.types.push(ty);
             }
@@ -79,13 +171,229 @@ impl Registry {
     where
         F: FnOnce(dynamic::Object) -> dynamic::Object + 'static,
     {
-        self.pending_expand_objects.push_back(PendingExpandObject {
+        self.pending_expand_objects.push_back(PendingExpand {
             target: target.to_string(),
             expansion: expansion_name.to_string(),
+            rust_type: self.current_rust_type(),
+            map_fn: Box::new(f),
+        });
+        self
+    }
+    /// update the root query object, whatever type is used as root
+    pub fn update_root_object<F>(mut self, expansion_name: &str, f: F) -> Self
+    where
+        F: FnOnce(dynamic::Object) -> dynamic::Object + 'static,
+    {
+        self.pending_expand_root.push_back(PendingExpand {
+            target: String::new(),
+            expansion: expansion_name.to_string(),
+            rust_type: self.current_rust_type(),
             map_fn: Box::new(f),
         });
         self
     }
+    pub fn update_subscription<F>(mut self, target: &str, expansion_name: &str, f: F) -> Self
+    where
+        F: FnOnce(dynamic::Subscription) -> dynamic::Subscription + 'static,
+    {
+        self.pending_expand_subscriptions.push_back(PendingExpand {
+            target: target.to_string(),
+            expansion: expansion_name.to_string(),
+            rust_type: self.current_rust_type(),
+            map_fn: Box::new(f),
+        });
+        self
+    }
+    /// declare a field added to `type_name` by an expansion, the same field can't be added
+    /// by different types
+    pub fn declare_expanded_field(mut self, type_name: &str, field_name: &str) -> Self {
+        self.expanded_fields.push((
+            type_name.to_string(),
+            field_name.to_string(),
+            self.current_rust_type(),
+        ));
+        self
+    }
+    /// declare that `type_name` implements `interface`, the interface must be registered
+    pub fn declare_implement(mut self, type_name: &str, interface: &str) -> Self {
+        self.implements.push((
+            type_name.to_string(),
+            interface.to_string(),
+            self.current_rust_type(),
+        ));
+        self
+    }
+    /// declare a possible type of `union`, the type must be registered
+    pub fn declare_possible_type(mut self, union: &str, type_name: &str) -> Self {
+        self.possible_types.push((
+            union.to_string(),
+            type_name.to_string(),
+            self.current_rust_type(),
+        ));
+        self
+    }
+    /// declare the types used by a field and its arguments, the types that are only used by the
+    /// fields removed by [`Registry::create_schema_for`] are removed with them
+    pub fn declare_field_types(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        type_names: &[&str],
+    ) -> Self {
+        self.field_types.push((
+            type_name.to_string(),
+            field_name.to_string(),
+            type_names.iter().map(|name| name.to_string()).collect(),
+        ));
+        self
+    }
+    /// tag a type, the tags select the schemas that contain it, see [`TagFilter`]
+    pub fn set_type_tags(mut self, type_name: &str, tags: &[&str]) -> Self {
+        self.type_tags
+            .entry(type_name.to_string())
+            .or_default()
+            .extend(tags.iter().map(|tag| tag.to_string()));
+        self
+    }
+    /// add a tagged field to an object when the schema is created, unless its tags are
+    /// filtered out
+    pub fn add_tagged_field(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        tags: &[&str],
+        field: dynamic::Field,
+    ) -> Self {
+        self.tagged_fields.push(TaggedField {
+            type_name: type_name.to_string(),
+            field_name: field_name.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            value: TaggedFieldValue::Object(field),
+        });
+        self
+    }
+    /// add a tagged field to an interface when the schema is created, unless its tags are
+    /// filtered out
+    pub fn add_tagged_interface_field(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        tags: &[&str],
+        field: dynamic::InterfaceField,
+    ) -> Self {
+        self.tagged_fields.push(TaggedField {
+            type_name: type_name.to_string(),
+            field_name: field_name.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            value: TaggedFieldValue::Interface(field),
+        });
+        self
+    }
+    /// define a type system directive, the definitions are added to the sdl by
+    /// [`Registry::create_sdl`]
+    pub fn register_directive(mut self, directive: DirectiveDefinition) -> Self {
+        if !self.directives.contains(&directive) {
+            self.directives.push(directive);
+        }
+        self
+    }
+    /// set the complexity of a field, used when the complexity of a query is limited
+    pub fn set_field_complexity(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        complexity: impl Into<Complexity>,
+    ) -> Self {
+        self.complexities
+            .entry(type_name.to_string())
+            .or_default()
+            .insert(field_name.to_string(), complexity.into());
+        self
+    }
+    /// hide the type in the requests where `visible` returns `false`, the fields and arguments
+    /// of the type are hidden with it
+    pub fn set_type_visible(mut self, type_name: &str, visible: VisibleFn) -> Self {
+        self.visibilities.set_type(type_name, visible);
+        self
+    }
+    /// hide a field of an object, an interface or an input object, or an item of an enum, in
+    /// the requests where `visible` returns `false`
+    pub fn set_field_visible(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        visible: VisibleFn,
+    ) -> Self {
+        self.visibilities.set_field(type_name, field_name, visible);
+        self
+    }
+    /// hide an argument of a field in the requests where `visible` returns `false`
+    pub fn set_argument_visible(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        argument_name: &str,
+        visible: VisibleFn,
+    ) -> Self {
+        self.visibilities
+            .set_argument(type_name, field_name, argument_name, visible);
+        self
+    }
+    /// declare a loader used by the resolvers, the `DataLoader<T>` of the schema data gets a cache
+    /// for each request when it caches values
+    pub fn declare_loader<T: Send + Sync + 'static>(mut self) -> Self {
+        self.loaders
+            .insert(TypeId::of::<T>(), is_cached_loader::<T>);
+        self
+    }
+    /// limit the complexity of queries, fields cost `1` unless their complexity is set
+    #[inline]
+    pub fn limit_complexity(mut self, complexity: usize) -> Self {
+        self.limit_complexity = Some(complexity);
+        self
+    }
+    #[inline]
+    pub fn limit_depth(mut self, depth: usize) -> Self {
+        self.limit_depth = Some(depth);
+        self
+    }
+    /// set what to do with integer outputs that don't fit in `Int`, they are returned as is by
+    /// default
+    #[inline]
+    pub fn int_overflow_policy(mut self, policy: IntOverflowPolicy) -> Self {
+        self.data.insert(policy);
+        self
+    }
+    /// add the apollo federation `_service` and `_entities` fields to the schema
+    #[inline]
+    pub fn enable_federation(mut self) -> Self {
+        self.federation = true;
+        self
+    }
+    /// resolve the items of lists created with `null_item` to null
+    #[inline]
+    #[cfg(feature = "relay")]
+    pub(crate) fn enable_null_items(mut self) -> Self {
+        self.null_items = true;
+        self
+    }
+    /// resolve the representations of `type_name` that contain all the `keys` in `_entities`,
+    /// federation is enabled when an entity resolver is registered
+    pub fn register_entity_resolver<F>(
+        mut self,
+        type_name: &str,
+        keys: &[&str],
+        resolver: F,
+    ) -> Self
+    where
+        F: for<'a> Fn(EntityContext<'a>) -> BoxFuture<'a, Result<Option<FieldValue<'static>>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.entities.add(type_name, keys, resolver);
+        self
+    }
 }
 
 impl Registry {
@@ -95,62 +403,407 @@ impl Registry {
             return self;
         }
         self.names.insert(ty);
-        T::register(self)
+        self.registering.push(std::any::type_name::<T>());
+        let mut registry = T::register(self);
+        registry.registering.pop();
+        registry
     }
 
-    fn apply_pending_objects(&mut self) {
-        loop {
-            if self.pending_expand_objects.is_empty() {
-                break;
-            }
-            let mut changed = false;
-            let pending_expand_objects = mem::take(&mut self.pending_expand_objects);
-            self.pending_expand_objects = pending_expand_objects
-                .into_iter()
-                .filter_map(|pending| {
-                    if let Some(object) = self.objects.remove(&pending.target) {
-                        self.objects
-                            .insert(pending.target, (pending.map_fn)(object));
-                        changed = true;
-                        None
-                    } else {
-                        Some(pending)
+    fn current_rust_type(&self) -> Option<&'static str> {
+        self.registering.last().copied()
+    }
+
+    pub(crate) fn is_registered(&self, name: &str) -> bool {
+        self.type_names
+            .iter()
+            .any(|(type_name, _)| type_name == name)
+    }
+
+    /// apply the expansions, the expansions of missing types are returned
+    fn apply_pending_objects(&mut self) -> Vec<RegistryIssue> {
+        let mut issues = Vec::new();
+        if !self.pending_expand_root.is_empty() {
+            match self.root {
+                Some(ref root) => {
+                    for mut pending in mem::take(&mut self.pending_expand_root) {
+                        pending.target = root.clone();
+                        self.pending_expand_objects.push_back(pending);
                     }
-                })
-                .collect();
-            if !changed {
-                let keys = self
-                    .pending_expand_objects
+                }
+                None => issues.push(RegistryIssue::MissingRoot),
+            }
+        }
+        issues.extend(apply_pending(
+            &mut self.objects,
+            &mut self.pending_expand_objects,
+        ));
+        issues.extend(apply_pending(
+            &mut self.subscriptions,
+            &mut self.pending_expand_subscriptions,
+        ));
+        issues
+    }
+
+    /// the problems of the registered types, the expansions are not applied
+    fn check(&self) -> Vec<RegistryIssue> {
+        let mut issues = Vec::new();
+        if self.root.is_none() {
+            issues.push(RegistryIssue::MissingRoot);
+        }
+
+        let missing_objects = self
+            .pending_expand_objects
+            .iter()
+            .filter(|pending| !self.objects.contains_key(&pending.target))
+            .map(PendingExpand::missing_target);
+        let missing_subscriptions = self
+            .pending_expand_subscriptions
+            .iter()
+            .filter(|pending| !self.subscriptions.contains_key(&pending.target))
+            .map(PendingExpand::missing_target);
+        issues.extend(missing_objects.chain(missing_subscriptions));
+
+        let mut types: HashMap<&str, Vec<Option<&'static str>>> = HashMap::new();
+        for (name, rust_type) in &self.type_names {
+            let rust_types = types.entry(name).or_default();
+            if !rust_types.contains(rust_type) {
+                rust_types.push(*rust_type);
+            }
+        }
+        for (name, _) in &self.type_names {
+            let Some(rust_types) = types.remove(name.as_str()) else {
+                continue;
+            };
+            if rust_types.len() > 1 {
+                issues.push(RegistryIssue::DuplicateType {
+                    name: name.clone(),
+                    rust_types,
+                });
+            }
+        }
+
+        let mut fields: HashMap<(&str, &str), Vec<Option<&'static str>>> = HashMap::new();
+        for (type_name, field_name, rust_type) in &self.expanded_fields {
+            let rust_types = fields.entry((type_name, field_name)).or_default();
+            if !rust_types.contains(rust_type) {
+                rust_types.push(*rust_type);
+            }
+        }
+        for (type_name, field_name, _) in &self.expanded_fields {
+            let Some(rust_types) = fields.remove(&(type_name.as_str(), field_name.as_str())) else {
+                continue;
+            };
+            if rust_types.len() > 1 {
+                issues.push(RegistryIssue::DuplicateField {
+                    type_name: type_name.clone(),
+                    field_name: field_name.clone(),
+                    rust_types,
+                });
+            }
+        }
+
+        for (type_name, interface, rust_type) in &self.implements {
+            if !self.is_registered(interface) {
+                issues.push(RegistryIssue::UnknownInterface {
+                    type_name: type_name.clone(),
+                    interface: interface.clone(),
+                    rust_type: *rust_type,
+                });
+            }
+        }
+        for (union, possible_type, rust_type) in &self.possible_types {
+            if !self.is_registered(possible_type) {
+                issues.push(RegistryIssue::UnknownPossibleType {
+                    union: union.clone(),
+                    possible_type: possible_type.clone(),
+                    rust_type: *rust_type,
+                });
+            }
+        }
+
+        let mut directives = HashSet::new();
+        for directive in &self.directives {
+            if !directives.insert(directive.name()) {
+                issues.push(RegistryIssue::DuplicateDirective {
+                    name: directive.name().to_string(),
+                });
+            }
+        }
+        issues
+    }
+
+    /// the registered types and their references, for the tag filters
+    fn tag_graph<'a>(&'a self, query: &'a str) -> TagGraph<'a> {
+        let objects = self
+            .objects
+            .keys()
+            .chain(self.subscriptions.keys())
+            .map(|name| (name.as_str(), true));
+        let types = self
+            .types
+            .iter()
+            .map(|ty| (type_name(ty), matches!(ty, dynamic::Type::Interface(_))));
+        let fields = self
+            .field_types
+            .iter()
+            .flat_map(|(type_name, field_name, type_names)| {
+                type_names
                     .iter()
-                    .map(|p| format!("{} when defining {}", p.target, p.expansion))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                panic!("Can't find object: {:?}", keys);
+                    .map(move |target| Reference::Field(type_name, field_name, target))
+            });
+        let implements = self
+            .implements
+            .iter()
+            .flat_map(|(type_name, interface, _)| {
+                [
+                    Reference::Implement(type_name, interface),
+                    Reference::Implementation(interface, type_name),
+                ]
+            });
+        let possible_types = self
+            .possible_types
+            .iter()
+            .map(|(union, type_name, _)| Reference::PossibleType(union, type_name));
+        TagGraph {
+            types: objects.chain(types).collect(),
+            type_tags: &self.type_tags,
+            fields: &self.tagged_fields,
+            references: fields.chain(implements).chain(possible_types).collect(),
+            query,
+            roots: self
+                .mutation
+                .iter()
+                .chain(self.subscription.iter())
+                .map(String::as_str)
+                .chain(self.entities.type_names())
+                .collect(),
+        }
+    }
+
+    /// add the tagged fields to their types, the fields of missing types are dropped
+    fn apply_tagged_fields(&mut self, keep: impl Fn(usize) -> bool) {
+        let mut interface_fields: HashMap<String, Vec<dynamic::InterfaceField>> = HashMap::new();
+        for (index, field) in mem::take(&mut self.tagged_fields).into_iter().enumerate() {
+            if !keep(index) {
+                continue;
+            }
+            match field.value {
+                TaggedFieldValue::Object(value) => {
+                    if let Some(object) = self.objects.remove(&field.type_name) {
+                        self.objects.insert(field.type_name, object.field(value));
+                    }
+                }
+                TaggedFieldValue::Interface(value) => {
+                    interface_fields
+                        .entry(field.type_name)
+                        .or_default()
+                        .push(value);
+                }
             }
         }
+        if interface_fields.is_empty() {
+            return;
+        }
+        self.types = mem::take(&mut self.types)
+            .into_iter()
+            .map(|ty| match ty {
+                dynamic::Type::Interface(interface) => {
+                    let fields = interface_fields
+                        .remove(interface.type_name())
+                        .unwrap_or_default();
+                    let interface = fields
+                        .into_iter()
+                        .fold(interface, |interface, field| interface.field(field));
+                    dynamic::Type::Interface(interface)
+                }
+                ty => ty,
+            })
+            .collect();
     }
+
+    /// create the schema, panics if the registered types are invalid
     pub fn create_schema(self) -> dynamic::SchemaBuilder {
-        let Some(ref root) = self.root else {
-            panic!("No root object defined");
+        self.try_create_schema()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// the sdl of the schema followed by the definitions of the registered directives, panics if
+    /// the registered types are invalid
+    pub fn create_sdl(self) -> String {
+        let definitions: Vec<String> = self.directives.iter().map(ToString::to_string).collect();
+        let schema = self
+            .create_schema()
+            .finish()
+            .unwrap_or_else(|err| panic!("{}", err));
+        let mut sdl = schema.sdl();
+        for definition in definitions {
+            sdl.push('\n');
+            sdl.push_str(&definition);
+            sdl.push('\n');
+        }
+        sdl
+    }
+
+    /// create the schema, all the problems of the registered types are returned at once
+    pub fn try_create_schema(self) -> Result<dynamic::SchemaBuilder, RegistryError> {
+        self.try_create_schema_for(&TagFilter::new())
+    }
+
+    /// create the schema with the types and fields kept by the tag filter, panics if the
+    /// registered types are invalid or if a kept element references a removed type
+    pub fn create_schema_for(self, filter: &TagFilter) -> dynamic::SchemaBuilder {
+        self.try_create_schema_for(filter)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// create the schema with the types and fields kept by the tag filter, the types that are
+    /// no longer reachable are removed and the references to removed types are returned as
+    /// problems
+    pub fn try_create_schema_for(
+        mut self,
+        filter: &TagFilter,
+    ) -> Result<dynamic::SchemaBuilder, RegistryError> {
+        let issues = self.check();
+        if !issues.is_empty() {
+            return Err(RegistryError { issues });
+        }
+        let issues = self.apply_pending_objects();
+        if !issues.is_empty() {
+            return Err(RegistryError { issues });
+        }
+        let Some(root) = self.root.clone() else {
+            return Err(RegistryError { issues });
         };
-        let schema =
-            dynamic::Schema::build(root, self.mutation.as_deref(), self.subscription.as_deref());
-        self.apply_into_schema_builder(schema)
+        let pruned = self.tag_graph(&root).prune(filter);
+        if !pruned.issues.is_empty() {
+            return Err(RegistryError {
+                issues: pruned.issues,
+            });
+        }
+        self.apply_tagged_fields(|index| pruned.fields.contains(&index));
+        let removed = pruned.types;
+        self.objects.retain(|name, _| !removed.contains(name));
+        self.subscriptions.retain(|name, _| !removed.contains(name));
+        self.types.retain(|ty| !removed.contains(type_name(ty)));
+        let mutation = self.mutation.take().filter(|name| !removed.contains(name));
+        let subscription = self
+            .subscription
+            .take()
+            .filter(|name| !removed.contains(name));
+
+        let schema = dynamic::Schema::build(&root, mutation.as_deref(), subscription.as_deref());
+        Ok(self.build_schema(schema))
     }
 
     pub fn apply_into_schema_builder(
         mut self,
         schema: dynamic::SchemaBuilder,
     ) -> dynamic::SchemaBuilder {
-        self.apply_pending_objects();
+        let issues = self.apply_pending_objects();
+        if !issues.is_empty() {
+            panic!("{}", RegistryError { issues });
+        }
+        self.apply_tagged_fields(|_| true);
+        self.build_schema(schema)
+    }
+
+    fn build_schema(self, schema: dynamic::SchemaBuilder) -> dynamic::SchemaBuilder {
         let schema = self
             .objects
             .into_iter()
             .fold(schema, |schema, (_, object)| schema.register(object));
+        let schema = self
+            .subscriptions
+            .into_iter()
+            .fold(schema, |schema, (_, subscription)| {
+                schema.register(subscription)
+            });
         let schema = self
             .types
             .into_iter()
             .fold(schema, |schema, object| schema.register(object));
+        let schema = match self.limit_depth {
+            Some(depth) => schema.limit_depth(depth),
+            None => schema,
+        };
+        let federation = self.federation || !self.entities.is_empty();
+        let schema = if federation {
+            let entities = Arc::new(self.entities);
+            schema
+                .enable_federation()
+                .entity_resolver(move |ctx| resolve_entities(entities.clone(), ctx))
+        } else {
+            schema
+        };
+        let schema = if federation || self.null_items {
+            schema.extension(NullItems)
+        } else {
+            schema
+        };
+        let cached_loaders = self.loaders.values().any(|is_cached| is_cached(&self.data));
+        let schema = if cached_loaders {
+            schema.extension(LoaderScope)
+        } else {
+            schema
+        };
+        let schema = match self.limit_complexity {
+            Some(limit) => schema.extension(ComplexityLimit {
+                fields: Arc::new(self.complexities),
+                limit,
+            }),
+            None => schema,
+        };
+        let schema = if self.visibilities.is_empty() {
+            schema
+        } else {
+            schema.extension(VisibilityFilter {
+                visibilities: Arc::new(self.visibilities),
+            })
+        };
         schema.data(self.data)
     }
 }
+
+fn type_name(ty: &dynamic::Type) -> &str {
+    match ty {
+        dynamic::Type::Scalar(scalar) => scalar.type_name(),
+        dynamic::Type::Object(object) => object.type_name(),
+        dynamic::Type::InputObject(input_object) => input_object.type_name(),
+        dynamic::Type::Enum(enum_type) => enum_type.type_name(),
+        dynamic::Type::Interface(interface) => interface.type_name(),
+        dynamic::Type::Union(union) => union.type_name(),
+        dynamic::Type::Subscription(subscription) => subscription.type_name(),
+        dynamic::Type::Upload => "Upload",
+    }
+}
+
+/// apply the expansions until no target is found, the remaining expansions are returned
+fn apply_pending<T>(
+    types: &mut HashMap<String, T>,
+    pending_expands: &mut VecDeque<PendingExpand<T>>,
+) -> Vec<RegistryIssue> {
+    loop {
+        if pending_expands.is_empty() {
+            return Vec::new();
+        }
+        let mut changed = false;
+        *pending_expands = mem::take(pending_expands)
+            .into_iter()
+            .filter_map(|pending| {
+                if let Some(ty) = types.remove(&pending.target) {
+                    types.insert(pending.target, (pending.map_fn)(ty));
+                    changed = true;
+                    None
+                } else {
+                    Some(pending)
+                }
+            })
+            .collect();
+        if !changed {
+            return pending_expands
+                .iter()
+                .map(PendingExpand::missing_target)
+                .collect();
+        }
+    }
+}
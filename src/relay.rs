@@ -0,0 +1,190 @@
+//! Relay global object identification: the `Node` interface, global ids and the `node` and
+//! `nodes` query fields.
+//!
+//! Objects implement `Node` with `#[graphql(node(fetch = "path"))]`, the `id` field is the
+//! global id of the object, built from the object type name and `Node::node_id`.
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use async_graphql::futures_util::future::join_all;
+use async_graphql::futures_util::future::BoxFuture;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use dynamic_graphql_derive::Interface;
+
+use crate::data::GetSchemaData;
+use crate::dynamic;
+use crate::from_value::FromValue;
+use crate::instance::Instance;
+use crate::null_items::item_or_null;
+use crate::registry::Registry;
+use crate::resolve::Resolve;
+use crate::types::GetInputTypeRef;
+use crate::types::GetOutputTypeRef;
+use crate::types::InterfaceMark;
+use crate::types::Object;
+use crate::types::Register;
+use crate::Context;
+use crate::Error;
+use crate::FieldValue;
+use crate::Result;
+use crate::ID;
+
+/// An object with a global id
+#[Interface]
+pub trait Node {
+    /// The global id of the object
+    fn id(&self) -> ID
+    where
+        Self: Sized + Object,
+    {
+        GlobalId::new(<Self as Object>::get_object_type_name(), self.node_id()).encode()
+    }
+
+    /// the id of the node, unique for its type
+    #[graphql(skip)]
+    fn node_id(&self) -> String;
+}
+
+/// The global id of a node, encoded as an opaque base64 string of `TypeName:id`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalId {
+    pub type_name: String,
+    pub id: String,
+}
+
+impl GlobalId {
+    pub fn new(type_name: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            id: id.into(),
+        }
+    }
+
+    pub fn encode(&self) -> ID {
+        ID(STANDARD.encode(format!("{}:{}", self.type_name, self.id)))
+    }
+
+    /// returns `None` if the id is not a valid global id
+    pub fn decode(id: &str) -> Option<Self> {
+        let decoded = String::from_utf8(STANDARD.decode(id).ok()?).ok()?;
+        let (type_name, id) = decoded.split_once(':')?;
+        Some(Self::new(type_name, id))
+    }
+}
+
+/// Loads a node by its id, implemented by `#[graphql(node(fetch = "path"))]`
+pub trait FetchNode: Node + Sized {
+    fn fetch_node(
+        ctx: &Context<'_>,
+        id: String,
+    ) -> impl Future<Output = Result<Option<Self>>> + Send;
+}
+
+type NodeFetcher = for<'a> fn(
+    &'a Context<'_>,
+    String,
+) -> BoxFuture<'a, Result<Option<Instance<'static, dyn Node>>>>;
+
+/// type name -> fetcher of the node
+#[derive(Default)]
+struct NodeFetchers(HashMap<String, NodeFetcher>);
+
+fn fetch_instance<'a, T>(
+    ctx: &'a Context<'_>,
+    id: String,
+) -> BoxFuture<'a, Result<Option<Instance<'static, dyn Node>>>>
+where
+    T: FetchNode + InterfaceMark<dyn Node> + Object + Send + Sync + 'static,
+{
+    Box::pin(async move { Ok(T::fetch_node(ctx, id).await?.map(Instance::new_owned)) })
+}
+
+/// Fetch a node by its global id.
+///
+/// Returns `None` if the type of the node is not registered or the node is not found.
+pub async fn fetch_node(ctx: &Context<'_>, id: &ID) -> Result<Option<Instance<'static, dyn Node>>> {
+    let Some(global_id) = GlobalId::decode(id) else {
+        return Err(Error::new(format!("invalid node id \"{}\"", id.as_str())));
+    };
+    let fetcher = ctx
+        .get_schema_data()
+        .get::<NodeFetchers>()
+        .and_then(|fetchers| fetchers.0.get(&global_id.type_name));
+    match fetcher {
+        Some(fetcher) => fetcher(ctx, global_id.id).await,
+        None => Ok(None),
+    }
+}
+
+/// register the fetcher of the node, and the `node` and `nodes` fields of the root query
+///
+/// `nodes` returns the nodes in the order of the ids, null when a node is not found
+pub fn register_node<T>(mut registry: Registry) -> Registry
+where
+    T: FetchNode + InterfaceMark<dyn Node> + Object + Send + Sync + 'static,
+{
+    let fetchers: &mut NodeFetchers = registry.data.get_mut_or_default();
+    fetchers.0.insert(
+        <T as Object>::get_object_type_name().to_string(),
+        fetch_instance::<T>,
+    );
+    registry.register::<NodeQuery>()
+}
+
+struct NodeQuery;
+
+impl Register for NodeQuery {
+    fn register(registry: Registry) -> Registry {
+        let registry = registry.register::<Option<Instance<dyn Node>>>();
+
+        let node = dynamic::Field::new(
+            "node",
+            <Option<Instance<dyn Node>> as GetOutputTypeRef>::get_output_type_ref(),
+            |ctx| {
+                dynamic::FieldFuture::new(async move {
+                    let id: ID = FromValue::from_value(ctx.args.try_get("id"))
+                        .map_err(|e| e.into_arg_error("id"))?;
+                    let node = fetch_node(ctx.ctx, &id).await?;
+                    Resolve::resolve(node, ctx.ctx)
+                })
+            },
+        )
+        .argument(dynamic::InputValue::new(
+            "id",
+            <ID as GetInputTypeRef>::get_input_type_ref(),
+        ));
+
+        let nodes = dynamic::Field::new(
+            "nodes",
+            <Vec<Option<Instance<dyn Node>>> as GetOutputTypeRef>::get_output_type_ref(),
+            |ctx| {
+                dynamic::FieldFuture::new(async move {
+                    let ids: Vec<ID> = FromValue::from_value(ctx.args.try_get("ids"))
+                        .map_err(|e| e.into_arg_error("ids"))?;
+                    // instances are not `Send`, so each node is resolved once it's fetched
+                    let nodes = join_all(ids.iter().enumerate().map(|(index, id)| async move {
+                        let node = fetch_node(ctx.ctx, id).await?;
+                        Resolve::resolve(node, &ctx.ctx.with_index(index))
+                    }))
+                    .await;
+                    let nodes = nodes
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, node)| item_or_null(ctx.ctx, index, node))
+                        .collect::<Vec<_>>();
+                    Ok(Some(FieldValue::list(nodes)))
+                })
+            },
+        )
+        .argument(dynamic::InputValue::new(
+            "ids",
+            <Vec<ID> as GetInputTypeRef>::get_input_type_ref(),
+        ));
+
+        registry
+            .enable_null_items()
+            .update_root_object("NodeQuery", |object| object.field(node).field(nodes))
+    }
+}
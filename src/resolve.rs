@@ -1,5 +1,22 @@
+use std::any::Any;
 use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroI8;
+use std::num::NonZeroIsize;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroU8;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
 
+use crate::data::SchemaData;
+use crate::types::GetOutputTypeRef;
 use crate::Context;
 use crate::Error;
 use crate::FieldValue;
@@ -18,6 +35,187 @@ pub trait Resolve<'a> {
     fn resolve(self, ctx: &Context) -> Result<Option<FieldValue<'a>>>;
 }
 
+/// Resolves a value owned by a shared `Arc`, e.g. an owned `Arc<T>` result or its items
+///
+/// objects keep the shared value as their parent value, and values are copied out of it
+pub trait ResolveShared<'a>: Sized {
+    fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>>;
+}
+
+type Projection<T> = dyn Fn(&(dyn Any + Send + Sync)) -> &T + Send + Sync;
+
+/// A value borrowed from a shared owner, e.g. an item of an `Arc<Vec<T>>`
+pub struct Shared<T> {
+    owner: Arc<dyn Any + Send + Sync>,
+    project: Arc<Projection<T>>,
+}
+
+impl<T: Send + Sync + 'static> Shared<T> {
+    pub fn new(value: Arc<T>) -> Self {
+        Self {
+            owner: value,
+            project: Arc::new(|owner| {
+                owner
+                    .downcast_ref::<T>()
+                    .expect("the owner is the shared value")
+            }),
+        }
+    }
+}
+
+impl<T> Shared<T> {
+    pub fn get(&self) -> &T {
+        (self.project)(&*self.owner)
+    }
+}
+
+impl<T: 'static> Shared<T> {
+    /// a part of the shared value, owned by the same arc
+    pub fn map<U>(&self, f: impl Fn(&T) -> &U + Send + Sync + 'static) -> Shared<U> {
+        let project = self.project.clone();
+        let project: Arc<Projection<U>> = Arc::new(move |owner| f(project(owner)));
+        Shared {
+            owner: self.owner.clone(),
+            project,
+        }
+    }
+}
+
+/// resolve a shared value that doesn't borrow its owner, e.g. a scalar or an enum
+pub fn resolve_shared_value<'a, T>(
+    shared: &Shared<T>,
+    ctx: &Context,
+) -> Result<Option<FieldValue<'a>>>
+where
+    T: for<'b> ResolveRef<'b>,
+{
+    let value = shared.get().resolve_ref(ctx)?;
+    Ok(value
+        .and_then(|value| value.as_value().cloned())
+        .map(FieldValue::value))
+}
+
+mod resolve_shared {
+    use super::*;
+    // Option<T>
+    impl<'a, T> ResolveShared<'a> for Option<T>
+    where
+        T: ResolveShared<'a> + Send + Sync + 'static,
+    {
+        fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            match shared.get() {
+                None => Ok(None),
+                Some(_) => T::resolve_shared(
+                    shared.map(|value| value.as_ref().expect("the value is some")),
+                    ctx,
+                ),
+            }
+        }
+    }
+    // Vec<T>
+    impl<'a, T> ResolveShared<'a> for Vec<T>
+    where
+        T: GetOutputTypeRef + ResolveShared<'a> + Send + Sync + 'static,
+    {
+        fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            let items = (0..shared.get().len()).map(|index| shared.map(move |list| &list[index]));
+            resolve_list::<T, _>(items, ctx)
+        }
+    }
+    // Box<[T]>
+    impl<'a, T> ResolveShared<'a> for Box<[T]>
+    where
+        T: GetOutputTypeRef + ResolveShared<'a> + Send + Sync + 'static,
+    {
+        fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            let items = (0..shared.get().len()).map(|index| shared.map(move |list| &list[index]));
+            resolve_list::<T, _>(items, ctx)
+        }
+    }
+    // VecDeque<T>
+    impl<'a, T> ResolveShared<'a> for VecDeque<T>
+    where
+        T: GetOutputTypeRef + ResolveShared<'a> + Send + Sync + 'static,
+    {
+        fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            let items = (0..shared.get().len()).map(|index| shared.map(move |list| &list[index]));
+            resolve_list::<T, _>(items, ctx)
+        }
+    }
+    // [T; N]
+    impl<'a, T, const N: usize> ResolveShared<'a> for [T; N]
+    where
+        T: GetOutputTypeRef + ResolveShared<'a> + Send + Sync + 'static,
+    {
+        fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            let items = (0..N).map(|index| shared.map(move |list| &list[index]));
+            resolve_list::<T, _>(items, ctx)
+        }
+    }
+    // Box<T>
+    impl<'a, T> ResolveShared<'a> for Box<T>
+    where
+        T: ResolveShared<'a> + Send + Sync + 'static,
+    {
+        #[inline]
+        fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            T::resolve_shared(shared.map(|value| &**value), ctx)
+        }
+    }
+    // Arc<T>
+    impl<'a, T> ResolveShared<'a> for Arc<T>
+    where
+        T: ResolveShared<'a> + Send + Sync + 'static,
+    {
+        #[inline]
+        fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            T::resolve_shared(shared.map(|value| &**value), ctx)
+        }
+    }
+}
+
+macro_rules! shared_values {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> ResolveShared<'a> for $ty {
+                #[inline]
+                fn resolve_shared(shared: Shared<Self>, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_shared_value(&shared, ctx)
+                }
+            }
+        )*
+    };
+}
+
+shared_values!(
+    String,
+    bool,
+    f32,
+    f64,
+    char,
+    ID,
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize
+);
+
 mod resolve_ref {
     use super::*;
     // &Option<T>
@@ -37,18 +235,41 @@ mod resolve_ref {
     // &Vec<T>
     impl<'a, T> ResolveRef<'a> for Vec<T>
     where
+        T: GetOutputTypeRef,
         &'a T: Resolve<'a> + 'a,
     {
         fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list::<T, _>(self.iter(), ctx)
+        }
+    }
+    // &Box<T>
+    impl<'a, T> ResolveRef<'a> for Box<T>
+    where
+        T: ResolveRef<'a>,
+    {
+        #[inline]
+        fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            (**self).resolve_ref(ctx)
+        }
+    }
+    // &Arc<T>
+    impl<'a, T> ResolveRef<'a> for Arc<T>
+    where
+        T: ResolveRef<'a>,
+    {
+        #[inline]
+        fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            (**self).resolve_ref(ctx)
+        }
+    }
+    // &Box<[T]>
+    impl<'a, T> ResolveRef<'a> for Box<[T]>
+    where
+        T: GetOutputTypeRef,
+        &'a T: Resolve<'a> + 'a,
+    {
+        fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list::<T, _>(self.iter(), ctx)
         }
     }
     // &ID
@@ -124,36 +345,64 @@ mod resolve_own {
     // Vec<T>
     impl<'a, T> ResolveOwned<'a> for Vec<T>
     where
-        T: Resolve<'a>,
+        T: GetOutputTypeRef + Resolve<'a>,
     {
         fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.into_iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list::<T, _>(self.into_iter(), ctx)
         }
     }
 
     // &[T]
     impl<'a, T> ResolveOwned<'a> for &'a [T]
     where
+        T: GetOutputTypeRef,
         &'a T: Resolve<'a>,
     {
         fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list::<T, _>(self.iter(), ctx)
+        }
+    }
+
+    // Box<T>
+    impl<'a, T> ResolveOwned<'a> for Box<T>
+    where
+        T: Resolve<'a>,
+    {
+        #[inline]
+        fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            (*self).resolve(ctx)
+        }
+    }
+
+    // Arc<T>, the value is resolved from the shared arc without cloning it
+    impl<'a, T> ResolveOwned<'a> for Arc<T>
+    where
+        T: ResolveShared<'a> + Send + Sync + 'static,
+    {
+        #[inline]
+        fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            T::resolve_shared(Shared::new(self), ctx)
+        }
+    }
+
+    // Shared<T>
+    impl<'a, T> ResolveOwned<'a> for Shared<T>
+    where
+        T: ResolveShared<'a>,
+    {
+        #[inline]
+        fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            T::resolve_shared(self, ctx)
+        }
+    }
+
+    // Box<[T]>
+    impl<'a, T> ResolveOwned<'a> for Box<[T]>
+    where
+        T: GetOutputTypeRef + Resolve<'a>,
+    {
+        fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list::<T, _>(self.into_vec().into_iter(), ctx)
         }
     }
 
@@ -174,6 +423,97 @@ mod resolve_own {
     }
 }
 
+macro_rules! list_resolves {
+    ($(impl<$($generic:ident),*> for $ty:ty;)*) => {
+        $(
+            impl<'a, T, $($generic),*> ResolveOwned<'a> for $ty
+            where
+                T: GetOutputTypeRef + Resolve<'a>,
+            {
+                fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_list::<T, _>(self.into_iter(), ctx)
+                }
+            }
+            impl<'a, T, $($generic),*> ResolveRef<'a> for $ty
+            where
+                T: GetOutputTypeRef,
+                &'a T: Resolve<'a> + 'a,
+            {
+                fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_list::<T, _>(self.iter(), ctx)
+                }
+            }
+        )*
+    };
+}
+
+list_resolves! {
+    impl<> for VecDeque<T>;
+    impl<S> for HashSet<T, S>;
+    impl<> for BTreeSet<T>;
+}
+
+#[cfg(feature = "indexmap")]
+list_resolves! {
+    impl<S> for indexmap::IndexSet<T, S>;
+}
+
+// [T; N]
+impl<'a, T, const N: usize> ResolveOwned<'a> for [T; N]
+where
+    T: GetOutputTypeRef + Resolve<'a>,
+{
+    fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        resolve_list::<T, _>(self.into_iter(), ctx)
+    }
+}
+
+// &[T; N]
+impl<'a, T, const N: usize> ResolveRef<'a> for [T; N]
+where
+    T: GetOutputTypeRef,
+    &'a T: Resolve<'a> + 'a,
+{
+    fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        resolve_list::<T, _>(self.iter(), ctx)
+    }
+}
+
+/// the parent value of a field resolver, a shared object keeps its owner as the parent value
+pub fn downcast_parent<'b, T: Any>(parent: &'b FieldValue) -> Result<&'b T> {
+    match parent.downcast_ref::<Shared<T>>() {
+        Some(parent) => Ok(parent.get()),
+        None => parent.try_downcast_ref::<T>(),
+    }
+}
+
+/// resolve the items of a list of `T`
+///
+/// the error of a nullable item is added to the response with the path of the item and the item
+/// is null, the error of a non-null item fails the whole list
+pub(crate) fn resolve_list<'a, T, I>(items: I, ctx: &Context) -> Result<Option<FieldValue<'a>>>
+where
+    T: GetOutputTypeRef + ?Sized,
+    I: Iterator,
+    I::Item: Resolve<'a>,
+{
+    let nullable = T::get_output_type_ref().is_nullable();
+    let mut values = Vec::new();
+    for (index, item) in items.enumerate() {
+        let ctx_idx = ctx.with_index(index);
+        match item.resolve(&ctx_idx) {
+            Ok(Some(value)) => values.push(value),
+            Ok(None) => values.push(FieldValue::NULL),
+            Err(err) if nullable => {
+                ctx_idx.add_error(ctx_idx.set_error_path(err.into_server_error(ctx.item.pos)));
+                values.push(FieldValue::NULL);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(Some(FieldValue::list(values)))
+}
+
 // T
 impl<'a, T: ResolveOwned<'a>> Resolve<'a> for T {
     #[inline]
@@ -201,4 +541,106 @@ macro_rules! resolves {
     };
 }
 
-resolves!(String, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, bool, f32, f64);
+resolves!(String, bool, f32, f64);
+
+/// What to do when an integer output doesn't fit in the 32 bits of the graphql `Int`
+///
+/// set by [`Registry::int_overflow_policy`](crate::internal::Registry::int_overflow_policy)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IntOverflowPolicy {
+    /// the value is returned as is
+    #[default]
+    Allow,
+    /// the field fails with an error
+    Error,
+    /// the value is clamped to the range of `Int`
+    Clamp,
+}
+
+fn resolve_int<'a>(value: i128, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+    if let Ok(value) = i32::try_from(value) {
+        return Ok(Some(FieldValue::value(value)));
+    }
+    let policy = ctx
+        .data_opt::<SchemaData>()
+        .and_then(|data| data.get::<IntOverflowPolicy>())
+        .copied()
+        .unwrap_or_default();
+    match policy {
+        IntOverflowPolicy::Allow if value < 0 => Ok(Some(FieldValue::value(value as i64))),
+        IntOverflowPolicy::Allow => Ok(Some(FieldValue::value(value as u64))),
+        IntOverflowPolicy::Error => Err(Error::new(format!(
+            "Int cannot represent non 32-bit signed integer value: {}",
+            value
+        ))),
+        IntOverflowPolicy::Clamp if value < 0 => Ok(Some(FieldValue::value(i32::MIN))),
+        IntOverflowPolicy::Clamp => Ok(Some(FieldValue::value(i32::MAX))),
+    }
+}
+
+macro_rules! int_resolves {
+    ($($ty:ident),*) => {
+        $(
+            impl <'a> ResolveOwned<'a> for $ty {
+                #[inline]
+                fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_int(self as i128, ctx)
+                }
+            }
+            impl <'a> ResolveRef<'a> for $ty {
+                #[inline]
+                fn resolve_ref(&self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_int(*self as i128, ctx)
+                }
+            }
+        )*
+    };
+}
+
+int_resolves!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! non_zero_resolves {
+    ($($ty:ident),*) => {
+        $(
+            impl <'a> ResolveOwned<'a> for $ty {
+                #[inline]
+                fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_int(self.get() as i128, ctx)
+                }
+            }
+            impl <'a> ResolveRef<'a> for $ty {
+                #[inline]
+                fn resolve_ref(&self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_int(self.get() as i128, ctx)
+                }
+            }
+        )*
+    };
+}
+
+non_zero_resolves!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize
+);
+
+impl<'a> ResolveOwned<'a> for char {
+    #[inline]
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.to_string())))
+    }
+}
+
+impl<'a> ResolveRef<'a> for char {
+    #[inline]
+    fn resolve_ref(&self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.to_string())))
+    }
+}
@@ -0,0 +1,949 @@
+//! Schema first types, loaded from an SDL document.
+//!
+//! The types of the document are registered as dynamic types, the fields of the objects are
+//! resolved by the resolvers bound to their `Type.field` path. The types registered by
+//! [`SdlLoader::derived`] are used instead of the declared types with the same name, they must
+//! have the same fields.
+//!
+//! ```ignore
+//! let registry = SdlLoader::parse(SDL)?
+//!     .resolver("Query.hello", |_ctx| {
+//!         FieldFuture::new(async move { Ok(Some(FieldValue::value("world"))) })
+//!     })
+//!     .derived::<User>()
+//!     .load(Registry::new())?;
+//! let schema = registry.create_schema().finish()?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use async_graphql::parser;
+use async_graphql::parser::types::BaseType;
+use async_graphql::parser::types::ConstDirective;
+use async_graphql::parser::types::DirectiveDefinition as DirectiveDeclaration;
+use async_graphql::parser::types::DirectiveLocation as DeclaredLocation;
+use async_graphql::parser::types::FieldDefinition;
+use async_graphql::parser::types::InputValueDefinition;
+use async_graphql::parser::types::SchemaDefinition;
+use async_graphql::parser::types::Type;
+use async_graphql::parser::types::TypeDefinition;
+use async_graphql::parser::types::TypeKind;
+use async_graphql::parser::types::TypeSystemDefinition;
+use async_graphql::parser::Positioned;
+
+use crate::dynamic;
+use crate::registry::Registry;
+use crate::types::Register;
+use crate::DirectiveDefinition;
+use crate::DirectiveLocation;
+use crate::Value;
+
+type FieldResolver =
+    Arc<dyn for<'a> Fn(dynamic::ResolverContext<'a>) -> dynamic::FieldFuture<'a> + Send + Sync>;
+
+type SubscriptionResolver = Arc<
+    dyn for<'a> Fn(dynamic::ResolverContext<'a>) -> dynamic::SubscriptionFieldFuture<'a>
+        + Send
+        + Sync,
+>;
+
+/// A problem of the SDL document or of its resolvers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SdlIssue {
+    /// the document can't be parsed, or a type extension doesn't match the extended type
+    Invalid(String),
+    /// a declared field has no resolver
+    MissingResolver {
+        type_name: String,
+        field_name: String,
+    },
+    /// a resolver is bound to a field that is not declared, or that belongs to a derived type
+    UnknownResolver { path: String },
+    /// a derived type doesn't match its declaration, `path` is the type or the field, `None`
+    /// when it is missing
+    Mismatch {
+        path: String,
+        expected: Option<String>,
+        found: Option<String>,
+    },
+    /// the schema with the derived types can't be created, so they are not checked
+    Schema(String),
+}
+
+impl Display for SdlIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SdlIssue::Invalid(message) => write!(f, "{}", message),
+            SdlIssue::MissingResolver {
+                type_name,
+                field_name,
+            } => write!(f, "Field \"{}.{}\" has no resolver", type_name, field_name),
+            SdlIssue::UnknownResolver { path } => {
+                write!(f, "Resolver \"{}\" is not bound to a declared field", path)
+            }
+            SdlIssue::Mismatch {
+                path,
+                expected: Some(expected),
+                found: Some(found),
+            } => write!(
+                f,
+                "\"{}\" is declared as \"{}\", the derived type has \"{}\"",
+                path, expected, found
+            ),
+            SdlIssue::Mismatch {
+                path,
+                expected: Some(expected),
+                found: None,
+            } => write!(
+                f,
+                "\"{}\" is declared as \"{}\", the derived type doesn't have it",
+                path, expected
+            ),
+            SdlIssue::Mismatch {
+                path,
+                found: Some(found),
+                ..
+            } => write!(
+                f,
+                "\"{}\" of the derived type is \"{}\", it is not declared",
+                path, found
+            ),
+            SdlIssue::Mismatch { path, .. } => write!(f, "\"{}\" doesn't match", path),
+            SdlIssue::Schema(message) => {
+                write!(f, "The derived types can't be checked: {}", message)
+            }
+        }
+    }
+}
+
+/// All the problems of the SDL document and of its resolvers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SdlError {
+    pub issues: Vec<SdlIssue>,
+}
+
+impl Display for SdlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid SDL schema:")?;
+        for issue in &self.issues {
+            write!(f, "\n- {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SdlError {}
+
+/// Registers the types of an SDL document, with the resolvers of their fields
+pub struct SdlLoader {
+    types: Vec<TypeDefinition>,
+    schema: Option<SchemaDefinition>,
+    directives: Vec<DirectiveDefinition>,
+    resolvers: HashMap<String, FieldResolver>,
+    subscription_resolvers: HashMap<String, SubscriptionResolver>,
+    derived: Vec<fn(Registry) -> Registry>,
+}
+
+impl SdlLoader {
+    /// parse the document, the type extensions are merged into the extended types and the
+    /// directive definitions are registered with the types
+    pub fn parse(sdl: &str) -> Result<Self, SdlError> {
+        let document = parser::parse_schema(sdl).map_err(|err| SdlError {
+            issues: vec![SdlIssue::Invalid(err.to_string())],
+        })?;
+        let mut types: Vec<TypeDefinition> = Vec::new();
+        let mut schema: Option<SchemaDefinition> = None;
+        let mut directives = Vec::new();
+        let mut issues = Vec::new();
+        for definition in document.definitions {
+            match definition {
+                TypeSystemDefinition::Schema(definition) => {
+                    let definition = definition.node;
+                    if !definition.directives.is_empty() {
+                        issues.push(SdlIssue::Invalid(
+                            "Directives of the schema definition are not supported".to_string(),
+                        ));
+                    }
+                    match schema {
+                        Some(ref mut schema) => {
+                            schema.query = schema.query.take().or(definition.query);
+                            schema.mutation = schema.mutation.take().or(definition.mutation);
+                            schema.subscription =
+                                schema.subscription.take().or(definition.subscription);
+                        }
+                        None => schema = Some(definition),
+                    }
+                }
+                TypeSystemDefinition::Type(definition) => {
+                    let definition = definition.node;
+                    let name = definition.name.node.to_string();
+                    let existing = types.iter_mut().find(|ty| ty.name.node == name);
+                    match (existing, definition.extend) {
+                        (None, _) => types.push(definition),
+                        (Some(ty), true) => {
+                            if !extend_type(ty, definition) {
+                                issues.push(SdlIssue::Invalid(format!(
+                                    "Type \"{}\" is extended by a different kind of type",
+                                    name
+                                )));
+                            }
+                        }
+                        (Some(_), false) => issues.push(SdlIssue::Invalid(format!(
+                            "Type \"{}\" is defined more than once",
+                            name
+                        ))),
+                    }
+                }
+                TypeSystemDefinition::Directive(definition) => {
+                    match directive_definition(sdl, &definition.node) {
+                        Ok(directive) => directives.push(directive),
+                        Err(issue) => issues.push(issue),
+                    }
+                }
+            }
+        }
+        if !issues.is_empty() {
+            return Err(SdlError { issues });
+        }
+        Ok(Self {
+            types,
+            schema,
+            directives,
+            resolvers: Default::default(),
+            subscription_resolvers: Default::default(),
+            derived: Default::default(),
+        })
+    }
+
+    /// bind the resolver of a field of an object, `path` is `Type.field`
+    pub fn resolver<F>(mut self, path: &str, resolver: F) -> Self
+    where
+        F: for<'a> Fn(dynamic::ResolverContext<'a>) -> dynamic::FieldFuture<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resolvers.insert(path.to_string(), Arc::new(resolver));
+        self
+    }
+
+    /// bind the resolver of a field of the subscription root, `path` is `Subscription.field`
+    pub fn subscription_resolver<F>(mut self, path: &str, resolver: F) -> Self
+    where
+        F: for<'a> Fn(dynamic::ResolverContext<'a>) -> dynamic::SubscriptionFieldFuture<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.subscription_resolvers
+            .insert(path.to_string(), Arc::new(resolver));
+        self
+    }
+
+    /// register a derived type, the types it registers are used instead of the declared types
+    /// with the same name
+    pub fn derived<T: Register + 'static>(mut self) -> Self {
+        self.derived.push(|registry| registry.register::<T>());
+        self
+    }
+
+    /// register the derived types and the declared types, fails if a declared field has no
+    /// resolver or if a derived type doesn't match its declaration
+    pub fn load(self, registry: Registry) -> Result<Registry, SdlError> {
+        let (registry, mut issues) = self.build(registry);
+        issues.extend(self.check_derived());
+        if issues.is_empty() {
+            Ok(registry)
+        } else {
+            Err(SdlError { issues })
+        }
+    }
+
+    fn is_declared(&self, name: &str) -> bool {
+        self.types.iter().any(|ty| ty.name.node == name)
+    }
+
+    /// the names of the query, mutation and subscription roots, by default `Query`, `Mutation`
+    /// and `Subscription` when they are declared
+    fn root_names(&self, registry: &Registry) -> [Option<String>; 3] {
+        let root = |name: Option<&Positioned<crate::Name>>, default: &str| match name {
+            Some(name) => Some(name.node.to_string()),
+            None if self.is_declared(default) || registry.is_registered(default) => {
+                Some(default.to_string())
+            }
+            None => None,
+        };
+        let schema = self.schema.as_ref();
+        [
+            root(schema.and_then(|s| s.query.as_ref()), "Query"),
+            root(schema.and_then(|s| s.mutation.as_ref()), "Mutation"),
+            root(schema.and_then(|s| s.subscription.as_ref()), "Subscription"),
+        ]
+    }
+
+    /// register the types, the fields without resolver resolve to `null` and are reported
+    fn build(&self, registry: Registry) -> (Registry, Vec<SdlIssue>) {
+        let registry = self
+            .derived
+            .iter()
+            .fold(registry, |registry, register| register(registry));
+        let registry = self
+            .directives
+            .iter()
+            .fold(registry, |registry, directive| {
+                registry.register_directive(directive.clone())
+            });
+        let [query, mutation, subscription] = self.root_names(&registry);
+
+        let mut builder = Builder {
+            loader: self,
+            issues: Vec::new(),
+            used: HashSet::new(),
+        };
+        let declared: Vec<&TypeDefinition> = self
+            .types
+            .iter()
+            .filter(|ty| !registry.is_registered(&ty.name.node))
+            .collect();
+        let mut registry = declared.into_iter().fold(registry, |registry, ty| {
+            let is_subscription = subscription.as_deref() == Some(ty.name.node.as_str());
+            builder.register(registry, ty, is_subscription)
+        });
+
+        let mut unknown: Vec<&String> = self
+            .resolvers
+            .keys()
+            .chain(self.subscription_resolvers.keys())
+            .filter(|path| !builder.used.contains(path.as_str()))
+            .collect();
+        unknown.sort();
+        let mut issues = builder.issues;
+        issues.extend(
+            unknown
+                .into_iter()
+                .map(|path| SdlIssue::UnknownResolver { path: path.clone() }),
+        );
+
+        if let Some(query) = query {
+            registry = registry.set_root(&query);
+        }
+        if let Some(mutation) = mutation {
+            registry = registry.set_mutation(&mutation);
+        }
+        if let Some(subscription) = subscription {
+            registry = registry.set_subscription(&subscription);
+        }
+        (registry, issues)
+    }
+
+    /// compare the derived types with their declarations, using the SDL of a schema with the
+    /// derived types
+    fn check_derived(&self) -> Vec<SdlIssue> {
+        if self.derived.is_empty() {
+            return Vec::new();
+        }
+        let derived = self
+            .derived
+            .iter()
+            .fold(Registry::new(), |registry, register| register(registry));
+        let declared: Vec<&TypeDefinition> = self
+            .types
+            .iter()
+            .filter(|ty| derived.is_registered(&ty.name.node))
+            .collect();
+        if declared.is_empty() {
+            return Vec::new();
+        }
+
+        let (registry, _) = self.build(Registry::new());
+        let schema = match registry.try_create_schema() {
+            Ok(schema) => schema.finish().map_err(|err| err.0),
+            Err(err) => Err(err.to_string()),
+        };
+        let document = schema
+            .and_then(|schema| parser::parse_schema(schema.sdl()).map_err(|err| err.to_string()));
+        let document = match document {
+            Ok(document) => document,
+            Err(err) => return vec![SdlIssue::Schema(err)],
+        };
+        let exported: HashMap<String, TypeDefinition> = document
+            .definitions
+            .into_iter()
+            .filter_map(|definition| match definition {
+                TypeSystemDefinition::Type(ty) => Some((ty.node.name.node.to_string(), ty.node)),
+                _ => None,
+            })
+            .collect();
+
+        declared
+            .into_iter()
+            .flat_map(|ty| compare_types(ty, exported.get(ty.name.node.as_str())))
+            .collect()
+    }
+}
+
+/// builds the dynamic types of the declarations
+struct Builder<'a> {
+    loader: &'a SdlLoader,
+    issues: Vec<SdlIssue>,
+    /// the paths of the used resolvers
+    used: HashSet<String>,
+}
+
+impl Builder<'_> {
+    fn register(
+        &mut self,
+        registry: Registry,
+        ty: &TypeDefinition,
+        is_subscription: bool,
+    ) -> Registry {
+        let name = ty.name.node.as_str();
+        let description = ty.description.as_ref().map(|d| d.node.as_str());
+        match &ty.kind {
+            TypeKind::Scalar => {
+                let mut scalar = dynamic::Scalar::new(name);
+                if let Some(description) = description {
+                    scalar = scalar.description(description);
+                }
+                if let Some(url) = get_directive_string(&ty.directives, "specifiedBy", "url") {
+                    scalar = scalar.specified_by_url(url);
+                }
+                for directive in applied_directives(&ty.directives) {
+                    scalar = scalar.directive(directive);
+                }
+                registry.register_type(scalar)
+            }
+            TypeKind::Object(object) if is_subscription => {
+                let mut registry = registry;
+                let mut subscription = dynamic::Subscription::new(name);
+                if let Some(description) = description {
+                    subscription = subscription.description(description);
+                }
+                self.unsupported_directives(name, &ty.directives);
+                for field in &object.fields {
+                    subscription = subscription.field(self.subscription_field(name, &field.node));
+                    registry = declare_field_types(registry, name, &field.node);
+                }
+                registry.register_type(subscription)
+            }
+            TypeKind::Object(object) => {
+                let mut registry = registry;
+                let mut dynamic_object = dynamic::Object::new(name);
+                if let Some(description) = description {
+                    dynamic_object = dynamic_object.description(description);
+                }
+                for directive in applied_directives(&ty.directives) {
+                    dynamic_object = dynamic_object.directive(directive);
+                }
+                for interface in &object.implements {
+                    dynamic_object = dynamic_object.implement(interface.node.as_str());
+                    registry = registry.declare_implement(name, &interface.node);
+                }
+                for field in &object.fields {
+                    dynamic_object = dynamic_object.field(self.field(name, &field.node));
+                    registry = declare_field_types(registry, name, &field.node);
+                }
+                registry.register_type(dynamic_object)
+            }
+            TypeKind::Interface(interface) => {
+                let mut registry = registry;
+                let mut dynamic_interface = dynamic::Interface::new(name);
+                if let Some(description) = description {
+                    dynamic_interface = dynamic_interface.description(description);
+                }
+                for directive in applied_directives(&ty.directives) {
+                    dynamic_interface = dynamic_interface.directive(directive);
+                }
+                for parent in &interface.implements {
+                    dynamic_interface = dynamic_interface.implement(parent.node.as_str());
+                    registry = registry.declare_implement(name, &parent.node);
+                }
+                for field in &interface.fields {
+                    dynamic_interface = dynamic_interface.field(interface_field(&field.node));
+                    registry = declare_field_types(registry, name, &field.node);
+                }
+                registry.register_type(dynamic_interface)
+            }
+            TypeKind::Union(union) => {
+                let mut registry = registry;
+                let mut dynamic_union = dynamic::Union::new(name);
+                if let Some(description) = description {
+                    dynamic_union = dynamic_union.description(description);
+                }
+                for directive in applied_directives(&ty.directives) {
+                    dynamic_union = dynamic_union.directive(directive);
+                }
+                for member in &union.members {
+                    dynamic_union = dynamic_union.possible_type(member.node.as_str());
+                    registry = registry.declare_possible_type(name, &member.node);
+                }
+                registry.register_type(dynamic_union)
+            }
+            TypeKind::Enum(enum_type) => {
+                let mut dynamic_enum = dynamic::Enum::new(name);
+                if let Some(description) = description {
+                    dynamic_enum = dynamic_enum.description(description);
+                }
+                for directive in applied_directives(&ty.directives) {
+                    dynamic_enum = dynamic_enum.directive(directive);
+                }
+                for value in &enum_type.values {
+                    let value = &value.node;
+                    let mut item = dynamic::EnumItem::new(value.value.node.as_str());
+                    if let Some(description) = &value.description {
+                        item = item.description(&description.node);
+                    }
+                    if let Some(reason) = get_deprecation(&value.directives) {
+                        item = item.deprecation(reason.as_deref());
+                    }
+                    for directive in applied_directives(&value.directives) {
+                        item = item.directive(directive);
+                    }
+                    dynamic_enum = dynamic_enum.item(item);
+                }
+                registry.register_type(dynamic_enum)
+            }
+            TypeKind::InputObject(input_object) => {
+                let mut registry = registry;
+                let mut dynamic_input = dynamic::InputObject::new(name);
+                if let Some(description) = description {
+                    dynamic_input = dynamic_input.description(description);
+                }
+                if ty.directives.iter().any(|d| d.node.name.node == "oneOf") {
+                    dynamic_input = dynamic_input.oneof();
+                }
+                for directive in applied_directives(&ty.directives) {
+                    dynamic_input = dynamic_input.directive(directive);
+                }
+                for field in &input_object.fields {
+                    dynamic_input = dynamic_input.field(input_value(&field.node));
+                    registry = registry.declare_field_types(
+                        name,
+                        &field.node.name.node,
+                        &[named_type(&field.node.ty.node)],
+                    );
+                }
+                registry.register_type(dynamic_input)
+            }
+        }
+    }
+
+    /// the resolver of the field, reported when it is missing
+    fn take_resolver<R: Clone>(
+        &mut self,
+        resolvers: &HashMap<String, R>,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<R> {
+        let path = format!("{}.{}", type_name, field_name);
+        let resolver = resolvers.get(&path).cloned();
+        if resolver.is_some() {
+            self.used.insert(path);
+        } else {
+            self.issues.push(SdlIssue::MissingResolver {
+                type_name: type_name.to_string(),
+                field_name: field_name.to_string(),
+            });
+        }
+        resolver
+    }
+
+    fn field(&mut self, type_name: &str, field: &FieldDefinition) -> dynamic::Field {
+        let name = field.name.node.as_str();
+        let resolver = self.take_resolver(&self.loader.resolvers, type_name, name);
+        let mut dynamic_field =
+            dynamic::Field::new(name, type_ref(&field.ty.node), move |ctx| match &resolver {
+                Some(resolver) => resolver(ctx),
+                None => dynamic::FieldFuture::from_value(None),
+            });
+        if let Some(description) = &field.description {
+            dynamic_field = dynamic_field.description(&description.node);
+        }
+        if let Some(reason) = get_deprecation(&field.directives) {
+            dynamic_field = dynamic_field.deprecation(reason.as_deref());
+        }
+        for directive in applied_directives(&field.directives) {
+            dynamic_field = dynamic_field.directive(directive);
+        }
+        for argument in &field.arguments {
+            dynamic_field = dynamic_field.argument(input_value(&argument.node));
+        }
+        dynamic_field
+    }
+
+    fn subscription_field(
+        &mut self,
+        type_name: &str,
+        field: &FieldDefinition,
+    ) -> dynamic::SubscriptionField {
+        let name = field.name.node.as_str();
+        let resolver = self.take_resolver(&self.loader.subscription_resolvers, type_name, name);
+        self.unsupported_directives(&format!("{}.{}", type_name, name), &field.directives);
+        let mut dynamic_field =
+            dynamic::SubscriptionField::new(name, type_ref(&field.ty.node), move |ctx| {
+                match &resolver {
+                    Some(resolver) => resolver(ctx),
+                    None => dynamic::SubscriptionFieldFuture::new(async {
+                        Ok(async_graphql::futures_util::stream::empty::<
+                            crate::Result<dynamic::FieldValue>,
+                        >())
+                    }),
+                }
+            });
+        if let Some(description) = &field.description {
+            dynamic_field = dynamic_field.description(&description.node);
+        }
+        if let Some(reason) = get_deprecation(&field.directives) {
+            dynamic_field = dynamic_field.deprecation(reason.as_deref());
+        }
+        for argument in &field.arguments {
+            dynamic_field = dynamic_field.argument(input_value(&argument.node));
+        }
+        dynamic_field
+    }
+
+    /// report the directives applied where the dynamic types can't have them
+    fn unsupported_directives(&mut self, path: &str, directives: &[Positioned<ConstDirective>]) {
+        self.issues.extend(applied_directives(directives).map(|_| {
+            SdlIssue::Invalid(format!(
+                "Directives of subscriptions are not supported, found one on \"{}\"",
+                path
+            ))
+        }));
+    }
+}
+
+fn interface_field(field: &FieldDefinition) -> dynamic::InterfaceField {
+    let mut dynamic_field =
+        dynamic::InterfaceField::new(field.name.node.as_str(), type_ref(&field.ty.node));
+    if let Some(description) = &field.description {
+        dynamic_field = dynamic_field.description(&description.node);
+    }
+    if let Some(reason) = get_deprecation(&field.directives) {
+        dynamic_field = dynamic_field.deprecation(reason.as_deref());
+    }
+    for directive in applied_directives(&field.directives) {
+        dynamic_field = dynamic_field.directive(directive);
+    }
+    for argument in &field.arguments {
+        dynamic_field = dynamic_field.argument(input_value(&argument.node));
+    }
+    dynamic_field
+}
+
+fn input_value(value: &InputValueDefinition) -> dynamic::InputValue {
+    let mut input_value =
+        dynamic::InputValue::new(value.name.node.as_str(), type_ref(&value.ty.node));
+    if let Some(description) = &value.description {
+        input_value = input_value.description(&description.node);
+    }
+    if let Some(default_value) = &value.default_value {
+        input_value = input_value.default_value(default_value.node.clone());
+    }
+    for directive in applied_directives(&value.directives) {
+        input_value = input_value.directive(directive);
+    }
+    input_value
+}
+
+fn type_ref(ty: &Type) -> dynamic::TypeRef {
+    let base = match &ty.base {
+        BaseType::Named(name) => dynamic::TypeRef::Named(name.to_string().into()),
+        BaseType::List(item) => dynamic::TypeRef::List(Box::new(type_ref(item))),
+    };
+    if ty.nullable {
+        base
+    } else {
+        dynamic::TypeRef::NonNull(Box::new(base))
+    }
+}
+
+/// the name of the named type, without the list and non-null wrappers
+fn named_type(ty: &Type) -> &str {
+    match &ty.base {
+        BaseType::Named(name) => name,
+        BaseType::List(item) => named_type(item),
+    }
+}
+
+/// declare the types used by the field and its arguments, for the tag filters
+fn declare_field_types(registry: Registry, type_name: &str, field: &FieldDefinition) -> Registry {
+    let type_names: Vec<&str> = std::iter::once(named_type(&field.ty.node))
+        .chain(
+            field
+                .arguments
+                .iter()
+                .map(|argument| named_type(&argument.node.ty.node)),
+        )
+        .collect();
+    registry.declare_field_types(type_name, &field.name.node, &type_names)
+}
+
+fn get_directive_string<'a>(
+    directives: &'a [Positioned<ConstDirective>],
+    name: &str,
+    argument: &str,
+) -> Option<&'a str> {
+    let directive = directives.iter().find(|d| d.node.name.node == name)?;
+    match &directive.node.get_argument(argument)?.node {
+        Value::String(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// `Some` with the optional reason when the field or the value is deprecated
+fn get_deprecation(directives: &[Positioned<ConstDirective>]) -> Option<Option<String>> {
+    directives
+        .iter()
+        .any(|d| d.node.name.node == "deprecated")
+        .then(|| get_directive_string(directives, "deprecated", "reason").map(String::from))
+}
+
+/// the directives that the dynamic types don't set themselves, applied as they are
+fn applied_directives(
+    directives: &[Positioned<ConstDirective>],
+) -> impl Iterator<Item = dynamic::Directive> + '_ {
+    directives
+        .iter()
+        .map(|directive| &directive.node)
+        .filter(|directive| {
+            !matches!(
+                directive.name.node.as_str(),
+                "deprecated" | "specifiedBy" | "oneOf"
+            )
+        })
+        .map(|directive| {
+            directive.arguments.iter().fold(
+                dynamic::Directive::new(directive.name.node.as_str()),
+                |applied, (name, value)| applied.argument(name.node.as_str(), value.node.clone()),
+            )
+        })
+}
+
+/// the definition of a declared directive, only the type system locations are supported
+fn directive_definition(
+    sdl: &str,
+    declaration: &DirectiveDeclaration,
+) -> Result<DirectiveDefinition, SdlIssue> {
+    let name = declaration.name.node.as_str();
+    let mut definition = DirectiveDefinition::new(name);
+    if let Some(description) = &declaration.description {
+        definition = definition.description(&description.node);
+    }
+    for argument in &declaration.arguments {
+        let argument = &argument.node;
+        let ty = type_ref(&argument.ty.node);
+        definition = match &argument.default_value {
+            Some(default_value) => definition.argument_with_default(
+                argument.name.node.as_str(),
+                ty,
+                default_value.node.clone(),
+            ),
+            None => definition.argument(argument.name.node.as_str(), ty),
+        };
+    }
+    if is_repeatable(sdl, declaration) {
+        definition = definition.repeatable();
+    }
+    for location in &declaration.locations {
+        let location = match location.node {
+            DeclaredLocation::Schema => DirectiveLocation::Schema,
+            DeclaredLocation::Scalar => DirectiveLocation::Scalar,
+            DeclaredLocation::Object => DirectiveLocation::Object,
+            DeclaredLocation::FieldDefinition => DirectiveLocation::FieldDefinition,
+            DeclaredLocation::ArgumentDefinition => DirectiveLocation::ArgumentDefinition,
+            DeclaredLocation::Interface => DirectiveLocation::Interface,
+            DeclaredLocation::Union => DirectiveLocation::Union,
+            DeclaredLocation::Enum => DirectiveLocation::Enum,
+            DeclaredLocation::EnumValue => DirectiveLocation::EnumValue,
+            DeclaredLocation::InputObject => DirectiveLocation::InputObject,
+            DeclaredLocation::InputFieldDefinition => DirectiveLocation::InputFieldDefinition,
+            _ => {
+                return Err(SdlIssue::Invalid(format!(
+                    "Directive \"@{}\" has executable locations, they are not supported",
+                    name
+                )))
+            }
+        };
+        definition = definition.location(location);
+    }
+    Ok(definition)
+}
+
+/// the parser marks every directive as repeatable, so the keyword is looked up before the
+/// locations
+fn is_repeatable(sdl: &str, declaration: &DirectiveDeclaration) -> bool {
+    let Some(location) = declaration.locations.first() else {
+        return false;
+    };
+    let offset: usize = sdl
+        .split_inclusive('\n')
+        .take(location.pos.line - 1)
+        .map(str::len)
+        .sum();
+    let line = &sdl[offset..];
+    let column = line
+        .char_indices()
+        .nth(location.pos.column - 1)
+        .map_or(line.len(), |(index, _)| index);
+    let before = sdl[..offset + column].trim_end();
+    let before = before.strip_suffix('|').unwrap_or(before).trim_end();
+    let Some(before) = before.strip_suffix("on") else {
+        return false;
+    };
+    before
+        .trim_end()
+        .strip_suffix("repeatable")
+        .is_some_and(|before| !before.ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_'))
+}
+
+/// merge a type extension into the extended type, `false` if they are different kinds
+fn extend_type(ty: &mut TypeDefinition, extension: TypeDefinition) -> bool {
+    match (&mut ty.kind, extension.kind) {
+        (TypeKind::Scalar, TypeKind::Scalar) => {}
+        (TypeKind::Object(object), TypeKind::Object(extension)) => {
+            object.implements.extend(extension.implements);
+            object.fields.extend(extension.fields);
+        }
+        (TypeKind::Interface(interface), TypeKind::Interface(extension)) => {
+            interface.implements.extend(extension.implements);
+            interface.fields.extend(extension.fields);
+        }
+        (TypeKind::Union(union), TypeKind::Union(extension)) => {
+            union.members.extend(extension.members);
+        }
+        (TypeKind::Enum(enum_type), TypeKind::Enum(extension)) => {
+            enum_type.values.extend(extension.values);
+        }
+        (TypeKind::InputObject(input_object), TypeKind::InputObject(extension)) => {
+            input_object.fields.extend(extension.fields);
+        }
+        _ => return false,
+    }
+    ty.directives.extend(extension.directives);
+    true
+}
+
+/// the keyword of the kind of type in an SDL document
+pub(crate) fn kind_name(kind: &TypeKind) -> &'static str {
+    match kind {
+        TypeKind::Scalar => "scalar",
+        TypeKind::Object(_) => "type",
+        TypeKind::Interface(_) => "interface",
+        TypeKind::Union(_) => "union",
+        TypeKind::Enum(_) => "enum",
+        TypeKind::InputObject(_) => "input",
+    }
+}
+
+fn field_signature(field: &FieldDefinition) -> String {
+    if field.arguments.is_empty() {
+        return field.ty.node.to_string();
+    }
+    let arguments = field
+        .arguments
+        .iter()
+        .map(|argument| format!("{}: {}", argument.node.name.node, argument.node.ty.node))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("({}): {}", arguments, field.ty.node)
+}
+
+/// the members of a type, fields, values or possible types, with their signature
+fn type_members(ty: &TypeDefinition) -> BTreeMap<String, String> {
+    match &ty.kind {
+        TypeKind::Scalar => BTreeMap::new(),
+        TypeKind::Object(object) => object
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.node.name.node.to_string(),
+                    field_signature(&field.node),
+                )
+            })
+            .collect(),
+        TypeKind::Interface(interface) => interface
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.node.name.node.to_string(),
+                    field_signature(&field.node),
+                )
+            })
+            .collect(),
+        TypeKind::Union(union) => union
+            .members
+            .iter()
+            .map(|member| (member.node.to_string(), member.node.to_string()))
+            .collect(),
+        TypeKind::Enum(enum_type) => enum_type
+            .values
+            .iter()
+            .map(|value| {
+                (
+                    value.node.value.node.to_string(),
+                    value.node.value.node.to_string(),
+                )
+            })
+            .collect(),
+        TypeKind::InputObject(input_object) => input_object
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.node.name.node.to_string(),
+                    field.node.ty.node.to_string(),
+                )
+            })
+            .collect(),
+    }
+}
+
+fn compare_types(declared: &TypeDefinition, found: Option<&TypeDefinition>) -> Vec<SdlIssue> {
+    let name = declared.name.node.as_str();
+    let expected_kind = kind_name(&declared.kind);
+    let Some(found) = found else {
+        return vec![SdlIssue::Mismatch {
+            path: name.to_string(),
+            expected: Some(expected_kind.to_string()),
+            found: None,
+        }];
+    };
+    let found_kind = kind_name(&found.kind);
+    if expected_kind != found_kind {
+        return vec![SdlIssue::Mismatch {
+            path: name.to_string(),
+            expected: Some(expected_kind.to_string()),
+            found: Some(found_kind.to_string()),
+        }];
+    }
+    let mut expected = type_members(declared);
+    let found = type_members(found);
+    let mut issues = Vec::new();
+    for (member, found) in found {
+        let expected = expected.remove(&member);
+        if expected.as_ref() != Some(&found) {
+            issues.push(SdlIssue::Mismatch {
+                path: format!("{}.{}", name, member),
+                expected,
+                found: Some(found),
+            });
+        }
+    }
+    issues.extend(
+        expected
+            .into_iter()
+            .map(|(member, expected)| SdlIssue::Mismatch {
+                path: format!("{}.{}", name, member),
+                expected: Some(expected),
+                found: None,
+            }),
+    );
+    issues
+}
@@ -0,0 +1,22 @@
+use async_graphql::futures_util::Stream;
+use async_graphql::futures_util::StreamExt;
+
+use crate::resolve::Resolve;
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+
+/// resolve each item of a subscription stream, `None` items are resolved to `null`
+pub fn resolve_stream<'a, S>(
+    stream: S,
+    ctx: &'a Context<'a>,
+) -> impl Stream<Item = Result<FieldValue<'a>>> + Send + 'a
+where
+    S: Stream + Send + 'a,
+    S::Item: Resolve<'a>,
+{
+    stream.map(move |item| {
+        item.resolve(ctx)
+            .map(|value| value.unwrap_or(FieldValue::NULL))
+    })
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use crate::dynamic;
+use crate::errors::RegistryIssue;
+
+/// Select the types and fields of a schema variant by their tags
+///
+/// - an excluded tag removes the types and fields tagged with it
+/// - when tags are included, the objects and interfaces that are not tagged with one of them
+///   are removed, unless one of their fields is, and the fields tagged with other tags are
+///   removed. The query root is always kept, the other types are kept while they are reachable
+///
+/// Untagged fields follow their type. The types that are only reachable through removed
+/// elements are removed too.
+///
+/// ```
+/// use dynamic_graphql::TagFilter;
+///
+/// let public = TagFilter::new().exclude("internal");
+/// let admin = TagFilter::new().include("public").include("admin");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl TagFilter {
+    /// a filter that keeps everything
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn include(mut self, tag: impl Into<String>) -> Self {
+        self.include.push(tag.into());
+        self
+    }
+    pub fn exclude(mut self, tag: impl Into<String>) -> Self {
+        self.exclude.push(tag.into());
+        self
+    }
+
+    fn is_excluded(&self, tags: &[String]) -> bool {
+        tags.iter().any(|tag| self.exclude.contains(tag))
+    }
+
+    /// the tagged element is kept by the filter
+    fn keeps(&self, tags: &[String]) -> bool {
+        !self.is_excluded(tags)
+            && (self.include.is_empty() || tags.iter().any(|tag| self.include.contains(tag)))
+    }
+}
+
+pub(crate) enum TaggedFieldValue {
+    Object(dynamic::Field),
+    Interface(dynamic::InterfaceField),
+}
+
+/// a field added to its type when the schema is created, if its tags are kept
+pub(crate) struct TaggedField {
+    pub(crate) type_name: String,
+    pub(crate) field_name: String,
+    pub(crate) tags: Vec<String>,
+    pub(crate) value: TaggedFieldValue,
+}
+
+/// a reference from a type, or a field of the type, to another type
+pub(crate) enum Reference<'a> {
+    Field(&'a str, &'a str, &'a str),
+    Implement(&'a str, &'a str),
+    // the implementations of an interface don't depend on it
+    Implementation(&'a str, &'a str),
+    PossibleType(&'a str, &'a str),
+}
+
+impl<'a> Reference<'a> {
+    fn owner(&self) -> &'a str {
+        match self {
+            Reference::Field(owner, ..)
+            | Reference::Implement(owner, _)
+            | Reference::Implementation(owner, _)
+            | Reference::PossibleType(owner, _) => owner,
+        }
+    }
+
+    fn target(&self) -> &'a str {
+        match self {
+            Reference::Field(.., target)
+            | Reference::Implement(_, target)
+            | Reference::Implementation(_, target)
+            | Reference::PossibleType(_, target) => target,
+        }
+    }
+
+    fn dangling(&self) -> Option<RegistryIssue> {
+        let (type_name, field_name, reference) = match self {
+            Reference::Field(type_name, field_name, target) => {
+                (type_name, Some(field_name.to_string()), target)
+            }
+            Reference::Implement(type_name, target)
+            | Reference::PossibleType(type_name, target) => (type_name, None, target),
+            Reference::Implementation(..) => return None,
+        };
+        Some(RegistryIssue::DanglingReference {
+            type_name: type_name.to_string(),
+            field_name,
+            reference: reference.to_string(),
+        })
+    }
+}
+
+/// the registered types and their references, as seen by the filter
+pub(crate) struct TagGraph<'a> {
+    /// the registered types, `true` for objects and interfaces
+    pub(crate) types: Vec<(&'a str, bool)>,
+    pub(crate) type_tags: &'a HashMap<String, Vec<String>>,
+    pub(crate) fields: &'a [TaggedField],
+    pub(crate) references: Vec<Reference<'a>>,
+    pub(crate) query: &'a str,
+    /// mutation, subscription and the entities
+    pub(crate) roots: Vec<&'a str>,
+}
+
+/// what the filter removes from the registered types
+pub(crate) struct Pruned {
+    /// the removed types
+    pub(crate) types: HashSet<String>,
+    /// the kept tagged fields, by index
+    pub(crate) fields: HashSet<usize>,
+    pub(crate) issues: Vec<RegistryIssue>,
+}
+
+impl TagGraph<'_> {
+    pub(crate) fn prune(&self, filter: &TagFilter) -> Pruned {
+        let kept_fields: HashSet<usize> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| filter.keeps(&field.tags))
+            .map(|(index, _)| index)
+            .collect();
+        let pruned_fields: HashSet<(&str, &str)> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !kept_fields.contains(index))
+            .map(|(_, field)| (field.type_name.as_str(), field.field_name.as_str()))
+            .collect();
+
+        let excluded: HashSet<&str> = self
+            .types
+            .iter()
+            .filter(|(name, is_object)| match self.type_tags.get(*name) {
+                Some(tags) if !tags.is_empty() => !filter.keeps(tags),
+                _ if *is_object && !filter.include.is_empty() && *name != self.query => {
+                    !kept_fields
+                        .iter()
+                        .any(|index| self.fields[*index].type_name == *name)
+                }
+                _ => false,
+            })
+            .map(|(name, _)| *name)
+            .collect();
+
+        let is_pruned = |reference: &Reference<'_>| matches!(reference, Reference::Field(owner, field, _) if pruned_fields.contains(&(*owner, *field)));
+        let all = self.reachable(&HashSet::new(), |_| true);
+        let kept = self.reachable(&excluded, |reference| !is_pruned(reference));
+        let removed: HashSet<&str> = excluded
+            .iter()
+            .copied()
+            .chain(all.difference(&kept).copied())
+            .collect();
+
+        let mut issues = Vec::new();
+        if excluded.contains(self.query) {
+            issues.push(RegistryIssue::ExcludedRoot {
+                name: self.query.to_string(),
+            });
+        }
+        issues.extend(
+            self.references
+                .iter()
+                .filter(|reference| {
+                    !removed.contains(reference.owner()) && excluded.contains(reference.target())
+                })
+                .filter(|reference| !is_pruned(reference))
+                .filter_map(Reference::dangling),
+        );
+
+        Pruned {
+            types: removed.into_iter().map(str::to_string).collect(),
+            fields: kept_fields,
+            issues,
+        }
+    }
+
+    /// the types reachable from the roots through the followed references, without going
+    /// through the excluded types
+    fn reachable<'a>(
+        &'a self,
+        excluded: &HashSet<&str>,
+        follow: impl Fn(&Reference<'_>) -> bool,
+    ) -> HashSet<&'a str> {
+        let mut reachable = HashSet::new();
+        let mut queue: VecDeque<&str> = std::iter::once(self.query)
+            .chain(self.roots.iter().copied())
+            .collect();
+        while let Some(name) = queue.pop_front() {
+            if excluded.contains(name) || !reachable.insert(name) {
+                continue;
+            }
+            queue.extend(
+                self.references
+                    .iter()
+                    .filter(|reference| reference.owner() == name && follow(reference))
+                    .map(Reference::target),
+            );
+        }
+        reachable
+    }
+}
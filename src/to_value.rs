@@ -0,0 +1,208 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroI8;
+use std::num::NonZeroIsize;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroU8;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use crate::MaybeUndefined;
+use crate::Name;
+use crate::Upload;
+use crate::Value;
+
+/// convert a rust value to a graphql value, used to define default values of arguments and input fields
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+/// the value of an input object with the given fields
+pub fn object_value<'a>(fields: impl IntoIterator<Item = (&'a str, Value)>) -> Value {
+    Value::Object(
+        fields
+            .into_iter()
+            .map(|(name, value)| (Name::new(name), value))
+            .collect(),
+    )
+}
+
+impl<T: ToValue + ?Sized> ToValue for &T {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl ToValue for str {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToValue for async_graphql::ID {
+    fn to_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+
+impl ToValue for bool {
+    fn to_value(&self) -> Value {
+        Value::Boolean(*self)
+    }
+}
+
+impl ToValue for f32 {
+    fn to_value(&self) -> Value {
+        Value::from(*self)
+    }
+}
+
+impl ToValue for f64 {
+    fn to_value(&self) -> Value {
+        Value::from(*self)
+    }
+}
+
+macro_rules! int_to_value {
+    ($($t:ty),*) => {
+        $(
+            impl ToValue for $t {
+                fn to_value(&self) -> Value {
+                    Value::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+int_to_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! non_zero_to_value {
+    ($($t:ty),*) => {
+        $(
+            impl ToValue for $t {
+                fn to_value(&self) -> Value {
+                    Value::from(self.get())
+                }
+            }
+        )*
+    };
+}
+
+non_zero_to_value!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize
+);
+
+impl ToValue for char {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl<T: ToValue> ToValue for Option<T> {
+    fn to_value(&self) -> Value {
+        match self {
+            None => Value::Null,
+            Some(value) => value.to_value(),
+        }
+    }
+}
+
+impl<T: ToValue> ToValue for MaybeUndefined<T> {
+    fn to_value(&self) -> Value {
+        match self {
+            MaybeUndefined::Value(value) => value.to_value(),
+            MaybeUndefined::Null | MaybeUndefined::Undefined => Value::Null,
+        }
+    }
+}
+
+impl<T: ToValue> ToValue for crate::Result<T> {
+    fn to_value(&self) -> Value {
+        match self {
+            Ok(value) => value.to_value(),
+            Err(_) => Value::Null,
+        }
+    }
+}
+
+impl<T: ToValue + ?Sized> ToValue for Box<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: ToValue + ?Sized> ToValue for Arc<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: ToValue> ToValue for Vec<T> {
+    fn to_value(&self) -> Value {
+        self.as_slice().to_value()
+    }
+}
+
+impl<T: ToValue> ToValue for [T] {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+impl<T: ToValue, const N: usize> ToValue for [T; N] {
+    fn to_value(&self) -> Value {
+        self.as_slice().to_value()
+    }
+}
+
+macro_rules! list_to_value {
+    ($(impl<$($generic:ident),*> for $ty:ty;)*) => {
+        $(
+            impl<T: ToValue, $($generic),*> ToValue for $ty {
+                fn to_value(&self) -> Value {
+                    Value::List(self.iter().map(ToValue::to_value).collect())
+                }
+            }
+        )*
+    };
+}
+
+list_to_value! {
+    impl<> for VecDeque<T>;
+    impl<S> for HashSet<T, S>;
+    impl<> for BTreeSet<T>;
+}
+
+#[cfg(feature = "indexmap")]
+list_to_value! {
+    impl<S> for indexmap::IndexSet<T, S>;
+}
+
+/// the files are not values, like in the variables of the request
+impl ToValue for Upload {
+    fn to_value(&self) -> Value {
+        Value::Null
+    }
+}
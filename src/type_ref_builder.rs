@@ -10,6 +10,26 @@ pub enum TypeRefBuilder {
 }
 
 impl TypeRefBuilder {
+    /// the name of the named type, without the list and non-null wrappers
+    pub fn type_name(&self) -> &str {
+        match self {
+            TypeRefBuilder::Named(name)
+            | TypeRefBuilder::NamedNN(name)
+            | TypeRefBuilder::List(name)
+            | TypeRefBuilder::ListNN(name)
+            | TypeRefBuilder::NNList(name)
+            | TypeRefBuilder::NNListNN(name) => name,
+        }
+    }
+
+    /// the value of the type can be null
+    pub fn is_nullable(&self) -> bool {
+        matches!(
+            self,
+            TypeRefBuilder::Named(_) | TypeRefBuilder::List(_) | TypeRefBuilder::NNList(_)
+        )
+    }
+
     pub fn optional(self) -> Self {
         match self {
             TypeRefBuilder::Named(name) => TypeRefBuilder::Named(name),
@@ -4,6 +4,7 @@ use crate::registry::Registry;
 use crate::type_ref_builder::TypeRefBuilder;
 
 mod common;
+mod external;
 
 pub trait Register {
     #[inline]
@@ -83,10 +84,16 @@ pub trait InputObject: InputTypeName {
 
 pub trait Mutation: ExpandObject {}
 
+pub trait Subscription: ExpandSubscription {}
+
 pub trait ExpandObject: ParentType {
     fn get_expand_object_name() -> Cow<'static, str>;
 }
 
+pub trait ExpandSubscription: ParentType {
+    fn get_expand_subscription_name() -> Cow<'static, str>;
+}
+
 pub trait GetOutputTypeRef {
     fn get_output_type_ref() -> TypeRefBuilder;
 }
@@ -1,4 +1,18 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroI8;
+use std::num::NonZeroIsize;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroU8;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
 
 use async_graphql::dynamic;
 use async_graphql::MaybeUndefined;
@@ -32,6 +46,62 @@ where
 
 impl<T: OutputTypeName + 'static> OutputTypeName for &T {}
 
+impl<T> Register for Box<T>
+where
+    T: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<T>()
+    }
+}
+
+impl<T> TypeName for Box<T>
+where
+    T: TypeName + 'static,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        <T as TypeName>::get_type_name()
+    }
+}
+
+impl<T: OutputTypeName + 'static> OutputTypeName for Box<T> {}
+
+impl<T: InputTypeName + 'static> InputTypeName for Box<T> {}
+
+impl<T> Register for Arc<T>
+where
+    T: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<T>()
+    }
+}
+
+impl<T> TypeName for Arc<T>
+where
+    T: TypeName + 'static,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        <T as TypeName>::get_type_name()
+    }
+}
+
+impl<T: GetOutputTypeRef> GetOutputTypeRef for Arc<T> {
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        T::get_output_type_ref()
+    }
+}
+
+impl<T: GetOutputTypeRef> GetOutputTypeRef for &Arc<T> {
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        T::get_output_type_ref()
+    }
+}
+
+impl<T: InputTypeName + 'static> InputTypeName for Arc<T> {}
+
 impl<T: Register + Clone + 'static> Register for Cow<'_, T> {
     fn register(registry: Registry) -> Registry {
         registry.register::<T>()
@@ -138,6 +208,29 @@ macro_rules! int_output_value {
 }
 
 int_output_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+int_output_value!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize
+);
+
+impl Register for char {}
+impl TypeName for char {
+    fn get_type_name() -> Cow<'static, str> {
+        dynamic::TypeRef::STRING.into()
+    }
+}
+
+impl InputTypeName for char {}
+
+impl OutputTypeName for char {}
 
 impl<T> Register for Option<T>
 where
@@ -179,6 +272,14 @@ where
         registry.register::<T>()
     }
 }
+impl<T> Register for Box<[T]>
+where
+    T: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<T>()
+    }
+}
 
 impl<T, E> GetOutputTypeRef for Result<T, E>
 where
@@ -219,6 +320,13 @@ impl<T: GetOutputTypeRef> GetOutputTypeRef for &[T] {
     }
 }
 
+impl<T: GetOutputTypeRef> GetOutputTypeRef for Box<[T]> {
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        T::get_output_type_ref().list()
+    }
+}
+
 impl<T: InputTypeName> GetInputTypeRef for T {
     #[inline]
     fn get_input_type_ref() -> TypeRefBuilder {
@@ -259,6 +367,73 @@ impl<T: GetInputTypeRef> GetInputTypeRef for &[T] {
         T::get_input_type_ref().list()
     }
 }
+impl<T: GetInputTypeRef> GetInputTypeRef for Box<[T]> {
+    #[inline]
+    fn get_input_type_ref() -> TypeRefBuilder {
+        T::get_input_type_ref().list()
+    }
+}
+
+macro_rules! list_type {
+    ($(impl<$($generic:ident),*> for $ty:ty;)*) => {
+        $(
+            impl<T, $($generic),*> Register for $ty
+            where
+                T: Register + 'static,
+            {
+                fn register(registry: Registry) -> Registry {
+                    registry.register::<T>()
+                }
+            }
+            impl<T: GetOutputTypeRef, $($generic),*> GetOutputTypeRef for $ty {
+                #[inline]
+                fn get_output_type_ref() -> TypeRefBuilder {
+                    T::get_output_type_ref().list()
+                }
+            }
+            impl<T: GetInputTypeRef, $($generic),*> GetInputTypeRef for $ty {
+                #[inline]
+                fn get_input_type_ref() -> TypeRefBuilder {
+                    T::get_input_type_ref().list()
+                }
+            }
+        )*
+    };
+}
+
+list_type! {
+    impl<> for VecDeque<T>;
+    impl<S> for HashSet<T, S>;
+    impl<> for BTreeSet<T>;
+}
+
+#[cfg(feature = "indexmap")]
+list_type! {
+    impl<S> for indexmap::IndexSet<T, S>;
+}
+
+impl<T, const N: usize> Register for [T; N]
+where
+    T: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<T>()
+    }
+}
+
+impl<T: GetOutputTypeRef, const N: usize> GetOutputTypeRef for [T; N] {
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        T::get_output_type_ref().list()
+    }
+}
+
+impl<T: GetInputTypeRef, const N: usize> GetInputTypeRef for [T; N] {
+    #[inline]
+    fn get_input_type_ref() -> TypeRefBuilder {
+        T::get_input_type_ref().list()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -295,6 +470,15 @@ mod tests {
         let type_ref: dynamic::TypeRef =
             <Option<&[Option<String>]> as GetOutputTypeRef>::get_output_type_ref().into();
         assert_eq!(type_ref.to_string(), "[String]");
+        let type_ref: dynamic::TypeRef =
+            <Box<String> as GetOutputTypeRef>::get_output_type_ref().into();
+        assert_eq!(type_ref.to_string(), "String!");
+        let type_ref: dynamic::TypeRef =
+            <Option<Arc<String>> as GetOutputTypeRef>::get_output_type_ref().into();
+        assert_eq!(type_ref.to_string(), "String");
+        let type_ref: dynamic::TypeRef =
+            <Box<[Option<String>]> as GetOutputTypeRef>::get_output_type_ref().into();
+        assert_eq!(type_ref.to_string(), "[String]!");
     }
 
     #[test]
@@ -328,5 +512,14 @@ mod tests {
         let type_ref: dynamic::TypeRef =
             <Option<&[Option<String>]> as GetInputTypeRef>::get_input_type_ref().into();
         assert_eq!(type_ref.to_string(), "[String]");
+        let type_ref: dynamic::TypeRef =
+            <Box<String> as GetInputTypeRef>::get_input_type_ref().into();
+        assert_eq!(type_ref.to_string(), "String!");
+        let type_ref: dynamic::TypeRef =
+            <Option<Arc<String>> as GetInputTypeRef>::get_input_type_ref().into();
+        assert_eq!(type_ref.to_string(), "String");
+        let type_ref: dynamic::TypeRef =
+            <Box<[Option<String>]> as GetInputTypeRef>::get_input_type_ref().into();
+        assert_eq!(type_ref.to_string(), "[String]!");
     }
 }
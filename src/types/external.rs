@@ -0,0 +1,145 @@
+//! Scalars of external crates, enabled by the feature of the same name.
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+/// parse a string value, the other values are rejected
+#[allow(dead_code)]
+fn parse_str<T, E>(
+    value: &crate::Value,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, String>
+where
+    E: std::fmt::Display,
+{
+    match value {
+        crate::Value::String(s) => parse(s).map_err(|err| err.to_string()),
+        value => Err(format!("expected a string, found {}", value)),
+    }
+}
+
+/// registers the `DateTime` scalar once for the date time types of chrono and time
+#[cfg(any(feature = "chrono", feature = "time"))]
+struct DateTimeScalar;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl crate::internal::Register for DateTimeScalar {
+    fn register(registry: crate::internal::Registry) -> crate::internal::Registry {
+        registry.register_type(
+            crate::dynamic::Scalar::new("DateTime")
+                .specified_by_url("https://datatracker.ietf.org/doc/html/rfc3339"),
+        )
+    }
+}
+
+/// implement the traits of a scalar for an external type
+///
+/// `to_value` converts `&$ty` to `Result<Value, String>`, `from_value` converts a graphql value to
+/// `Result<$ty, String>`, the scalar is registered by `shared` when several types have the
+/// same scalar
+#[allow(unused_macros)]
+macro_rules! external_scalar {
+    (
+        $ty:ty,
+        $name:literal,
+        shared: $shared:ty,
+        to_value: $to_value:expr,
+        from_value: $from_value:expr $(,)?
+    ) => {
+        impl $crate::internal::Register for $ty {
+            fn register(
+                registry: $crate::internal::Registry,
+            ) -> $crate::internal::Registry {
+                registry.register::<$shared>()
+            }
+        }
+        external_scalar!(@impls $ty, $name, $to_value, $from_value);
+    };
+    (
+        $ty:ty,
+        $name:literal,
+        $(specified_by_url: $url:expr,)?
+        to_value: $to_value:expr,
+        from_value: $from_value:expr $(,)?
+    ) => {
+        impl $crate::internal::Register for $ty {
+            fn register(
+                registry: $crate::internal::Registry,
+            ) -> $crate::internal::Registry {
+                let scalar = $crate::dynamic::Scalar::new($name);
+                $(let scalar = scalar.specified_by_url($url);)?
+                registry.register_type(scalar)
+            }
+        }
+        external_scalar!(@impls $ty, $name, $to_value, $from_value);
+    };
+    (@impls $ty:ty, $name:literal, $to_value:expr, $from_value:expr) => {
+        impl $crate::internal::TypeName for $ty {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                $name.into()
+            }
+        }
+        impl $crate::internal::InputTypeName for $ty {}
+        impl $crate::internal::OutputTypeName for $ty {}
+        impl $crate::internal::Scalar for $ty {}
+
+        impl<'a> $crate::internal::ResolveOwned<'a> for $ty {
+            fn resolve_owned(
+                self,
+                _ctx: &$crate::Context,
+            ) -> $crate::Result<Option<$crate::FieldValue<'a>>> {
+                let to_value: fn(&$ty) -> Result<$crate::Value, String> = $to_value;
+                let value = to_value(&self).map_err($crate::Error::new)?;
+                Ok(Some($crate::FieldValue::value(value)))
+            }
+        }
+        impl<'a> $crate::internal::ResolveRef<'a> for $ty {
+            fn resolve_ref(
+                &'a self,
+                _ctx: &$crate::Context,
+            ) -> $crate::Result<Option<$crate::FieldValue<'a>>> {
+                let to_value: fn(&$ty) -> Result<$crate::Value, String> = $to_value;
+                let value = to_value(self).map_err($crate::Error::new)?;
+                Ok(Some($crate::FieldValue::value(value)))
+            }
+        }
+        impl<'a> $crate::internal::ResolveShared<'a> for $ty {
+            fn resolve_shared(
+                shared: $crate::internal::Shared<Self>,
+                ctx: &$crate::Context,
+            ) -> $crate::Result<Option<$crate::FieldValue<'a>>> {
+                $crate::internal::resolve_shared_value(&shared, ctx)
+            }
+        }
+        impl $crate::internal::ToValue for $ty {
+            fn to_value(&self) -> $crate::Value {
+                // a value that can't be formatted has no default value
+                let to_value: fn(&$ty) -> Result<$crate::Value, String> = $to_value;
+                to_value(self).unwrap_or($crate::Value::Null)
+            }
+        }
+        impl $crate::internal::FromValue for $ty {
+            fn from_value(
+                value: $crate::Result<$crate::dynamic::ValueAccessor>,
+            ) -> $crate::internal::InputValueResult<Self> {
+                let from_value: fn(&$crate::Value) -> Result<$ty, String> = $from_value;
+                from_value(value?.as_value()).map_err($crate::internal::InputValueError::custom)
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+use external_scalar;
@@ -0,0 +1,14 @@
+use std::str::FromStr;
+
+use ::bigdecimal::BigDecimal;
+
+use super::external_scalar;
+use super::parse_str;
+use crate::Value;
+
+external_scalar!(
+    BigDecimal,
+    "BigDecimal",
+    to_value: |value| Ok(Value::String(value.to_string())),
+    from_value: |value| parse_str(value, BigDecimal::from_str),
+);
@@ -0,0 +1,12 @@
+use ::bson::oid::ObjectId;
+
+use super::external_scalar;
+use super::parse_str;
+use crate::Value;
+
+external_scalar!(
+    ObjectId,
+    "ObjectId",
+    to_value: |value| Ok(Value::String(value.to_hex())),
+    from_value: |value| parse_str(value, |s| ObjectId::parse_str(s)),
+);
@@ -0,0 +1,53 @@
+use ::chrono::DateTime;
+use ::chrono::FixedOffset;
+use ::chrono::NaiveDate;
+use ::chrono::NaiveDateTime;
+use ::chrono::NaiveTime;
+use ::chrono::SecondsFormat;
+use ::chrono::Utc;
+
+use super::external_scalar;
+use super::parse_str;
+use super::DateTimeScalar;
+use crate::Value;
+
+external_scalar!(
+    DateTime<Utc>,
+    "DateTime",
+    shared: DateTimeScalar,
+    to_value: |value| Ok(Value::String(value.to_rfc3339_opts(SecondsFormat::AutoSi, true))),
+    from_value: |value| parse_str(value, |s| {
+        DateTime::parse_from_rfc3339(s).map(|value| value.with_timezone(&Utc))
+    }),
+);
+
+external_scalar!(
+    DateTime<FixedOffset>,
+    "DateTime",
+    shared: DateTimeScalar,
+    to_value: |value| Ok(Value::String(value.to_rfc3339_opts(SecondsFormat::AutoSi, true))),
+    from_value: |value| parse_str(value, DateTime::parse_from_rfc3339),
+);
+
+external_scalar!(
+    NaiveDate,
+    "NaiveDate",
+    to_value: |value| Ok(Value::String(value.format("%Y-%m-%d").to_string())),
+    from_value: |value| parse_str(value, |s| NaiveDate::parse_from_str(s, "%Y-%m-%d")),
+);
+
+external_scalar!(
+    NaiveTime,
+    "NaiveTime",
+    to_value: |value| Ok(Value::String(value.format("%H:%M:%S%.f").to_string())),
+    from_value: |value| parse_str(value, |s| NaiveTime::parse_from_str(s, "%H:%M:%S%.f")),
+);
+
+external_scalar!(
+    NaiveDateTime,
+    "NaiveDateTime",
+    to_value: |value| Ok(Value::String(value.format("%Y-%m-%dT%H:%M:%S%.f").to_string())),
+    from_value: |value| parse_str(value, |s| {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+    }),
+);
@@ -0,0 +1,15 @@
+use std::str::FromStr;
+
+use ::rust_decimal::Decimal;
+
+use super::external_scalar;
+use super::parse_str;
+use crate::Value;
+
+// decimals are strings, to keep their precision
+external_scalar!(
+    Decimal,
+    "Decimal",
+    to_value: |value| Ok(Value::String(value.to_string())),
+    from_value: |value| parse_str(value, Decimal::from_str),
+);
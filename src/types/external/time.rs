@@ -0,0 +1,42 @@
+use ::time::format_description::well_known::Rfc3339;
+use ::time::macros::format_description;
+use ::time::Date;
+use ::time::OffsetDateTime;
+use ::time::PrimitiveDateTime;
+
+use super::external_scalar;
+use super::parse_str;
+use super::DateTimeScalar;
+use crate::Value;
+
+/// the formatted value as a string value, a value that can't be formatted is an error
+fn format(formatted: Result<String, ::time::error::Format>) -> Result<Value, String> {
+    formatted.map(Value::String).map_err(|err| err.to_string())
+}
+
+external_scalar!(
+    OffsetDateTime,
+    "DateTime",
+    shared: DateTimeScalar,
+    to_value: |value| format(value.format(&Rfc3339)),
+    from_value: |value| parse_str(value, |s| OffsetDateTime::parse(s, &Rfc3339)),
+);
+
+external_scalar!(
+    Date,
+    "Date",
+    to_value: |value| format(value.format(format_description!("[year]-[month]-[day]"))),
+    from_value: |value| parse_str(value, |s| Date::parse(s, format_description!("[year]-[month]-[day]"))),
+);
+
+external_scalar!(
+    PrimitiveDateTime,
+    "PrimitiveDateTime",
+    to_value: |value| {
+        format(value.format(format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]")))
+    },
+    from_value: |value| parse_str(value, |s| {
+        let format = format_description!("[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]");
+        PrimitiveDateTime::parse(s, format)
+    }),
+);
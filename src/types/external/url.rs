@@ -0,0 +1,13 @@
+use ::url::Url;
+
+use super::external_scalar;
+use super::parse_str;
+use crate::Value;
+
+external_scalar!(
+    Url,
+    "Url",
+    specified_by_url: "https://url.spec.whatwg.org/",
+    to_value: |value| Ok(Value::String(value.to_string())),
+    from_value: |value| parse_str(value, Url::parse),
+);
@@ -0,0 +1,13 @@
+use ::uuid::Uuid;
+
+use super::external_scalar;
+use super::parse_str;
+use crate::Value;
+
+external_scalar!(
+    Uuid,
+    "UUID",
+    specified_by_url: "http://tools.ietf.org/html/rfc4122",
+    to_value: |value| Ok(Value::String(value.to_string())),
+    from_value: |value| parse_str(value, Uuid::parse_str),
+);
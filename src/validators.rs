@@ -0,0 +1,157 @@
+//! Validators used by `#[graphql(validator(...))]` on arguments and input object fields.
+
+use std::fmt::Display;
+
+use crate::dynamic;
+use crate::Error;
+use crate::Result;
+use crate::Value;
+
+#[cfg(feature = "validators-regex")]
+#[doc(no_inline)]
+pub use regex::Regex;
+
+/// Values that have a length, used by the `min_length` and `max_length` validators
+pub trait Length {
+    fn length(&self) -> usize;
+}
+
+impl<T: Length + ?Sized> Length for &T {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+impl Length for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl Length for async_graphql::ID {
+    fn length(&self) -> usize {
+        self.0.length()
+    }
+}
+
+impl<T> Length for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+pub fn min_length<T: Length + ?Sized>(value: &T, min_length: usize) -> Result<()> {
+    let length = value.length();
+    if length < min_length {
+        return Err(Error::new(format!(
+            "the length is {}, must be greater than or equal to {}",
+            length, min_length
+        )));
+    }
+    Ok(())
+}
+
+pub fn max_length<T: Length + ?Sized>(value: &T, max_length: usize) -> Result<()> {
+    let length = value.length();
+    if length > max_length {
+        return Err(Error::new(format!(
+            "the length is {}, must be less than or equal to {}",
+            length, max_length
+        )));
+    }
+    Ok(())
+}
+
+pub fn minimum<T: PartialOrd + Display>(value: &T, min: T) -> Result<()> {
+    if *value < min {
+        return Err(Error::new(format!(
+            "the value is {}, must be greater than or equal to {}",
+            value, min
+        )));
+    }
+    Ok(())
+}
+
+pub fn maximum<T: PartialOrd + Display>(value: &T, max: T) -> Result<()> {
+    if *value > max {
+        return Err(Error::new(format!(
+            "the value is {}, must be less than or equal to {}",
+            value, max
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "validators-regex")]
+pub fn regex<T: AsRef<str> + ?Sized>(value: &T, regex: &Regex) -> Result<()> {
+    if !regex.is_match(value.as_ref()) {
+        return Err(Error::new(format!(
+            "the value does not match the pattern \"{}\"",
+            regex.as_str()
+        )));
+    }
+    Ok(())
+}
+
+/// compile the pattern of a `regex` validator, the pattern is already validated by the derive
+#[cfg(feature = "validators-regex")]
+pub fn compile_regex(pattern: &str) -> Regex {
+    Regex::new(pattern).expect("the regex pattern is already validated by the derive")
+}
+
+/// Result of a scalar validator, `#[graphql(validator(path))]` accepts functions that return
+/// `bool` or `Result<()>`
+pub trait ScalarValidatorResult: Sized {
+    fn into_result(self) -> Result<()>;
+
+    /// register the validator in the schema, so the value is checked while validating the query
+    fn register(scalar: dynamic::Scalar, _validator: fn(&Value) -> Self) -> dynamic::Scalar {
+        scalar
+    }
+}
+
+impl ScalarValidatorResult for bool {
+    fn into_result(self) -> Result<()> {
+        if self {
+            Ok(())
+        } else {
+            Err(Error::new("invalid value"))
+        }
+    }
+
+    fn register(scalar: dynamic::Scalar, validator: fn(&Value) -> Self) -> dynamic::Scalar {
+        scalar.validator(validator)
+    }
+}
+
+impl ScalarValidatorResult for Result<()> {
+    fn into_result(self) -> Result<()> {
+        self
+    }
+}
+
+pub fn register_scalar_validator<R: ScalarValidatorResult>(
+    scalar: dynamic::Scalar,
+    validator: fn(&Value) -> R,
+) -> dynamic::Scalar {
+    R::register(scalar, validator)
+}
+
+pub fn validate_scalar<R: ScalarValidatorResult>(
+    value: &Value,
+    validator: fn(&Value) -> R,
+) -> Result<()> {
+    validator(value).into_result()
+}
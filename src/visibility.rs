@@ -0,0 +1,733 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_graphql::extensions::Extension;
+use async_graphql::extensions::ExtensionContext;
+use async_graphql::extensions::ExtensionFactory;
+use async_graphql::extensions::NextExecute;
+use async_graphql::extensions::NextParseQuery;
+use async_graphql::parser::types::DocumentOperations;
+use async_graphql::parser::types::ExecutableDocument;
+use async_graphql::parser::types::Field;
+use async_graphql::parser::types::OperationDefinition;
+use async_graphql::parser::types::OperationType;
+use async_graphql::parser::types::Selection;
+use async_graphql::parser::types::SelectionSet;
+use async_graphql::parser::Pos;
+use async_graphql::parser::Positioned;
+use async_graphql::registry::MetaType;
+use async_graphql::registry::MetaTypeName;
+use async_graphql::registry::Registry;
+use async_graphql::Response;
+use async_graphql::Result;
+use async_graphql::ServerError;
+use async_graphql::ServerResult;
+
+use crate::Name;
+use crate::Value;
+use crate::Variables;
+
+/// Decides if a type, a field, an argument, an enum item or an input field is visible in a
+/// request, hidden elements are absent from the introspection and rejected as unknown.
+pub type VisibleFn = fn(&VisibleContext<'_>) -> bool;
+
+/// The context of the visibility functions, it gives the same `data` as the resolvers `Context`.
+///
+/// The dynamic types of async-graphql 7.2.1 are always registered without a visibility
+/// function, so the schema visibility hook can't be used. The visibility is decided by an
+/// extension before the execution, when there is no resolver `Context` yet.
+pub struct VisibleContext<'a> {
+    ctx: &'a ExtensionContext<'a>,
+}
+
+impl<'a> VisibleContext<'a> {
+    /// Gets the global data defined in the `Context` or `Schema`, or an error if the data
+    /// does not exist.
+    pub fn data<D: Any + Send + Sync>(&self) -> Result<&'a D> {
+        self.ctx.data::<D>()
+    }
+
+    /// Gets the global data defined in the `Context` or `Schema`, panics if the data does
+    /// not exist.
+    pub fn data_unchecked<D: Any + Send + Sync>(&self) -> &'a D {
+        self.ctx.data_unchecked::<D>()
+    }
+
+    /// Gets the global data defined in the `Context` or `Schema`, or `None` if the data does
+    /// not exist.
+    pub fn data_opt<D: Any + Send + Sync>(&self) -> Option<&'a D> {
+        self.ctx.data_opt::<D>()
+    }
+}
+
+/// the visibility of `#[graphql(visible = false)]`
+pub fn hidden(_ctx: &VisibleContext<'_>) -> bool {
+    false
+}
+
+/// the alias of the name added to the introspection selections, to know what to filter, a
+/// number is appended when the document already uses it
+const NAME_ALIAS: &str = "_visibilityName";
+
+const INTROSPECTION_ITEMS: [&str; 4] = ["__Type", "__Field", "__InputValue", "__EnumValue"];
+
+/// The visibility functions of the registered types, by `Type`, `Type.field` or
+/// `Type.field.argument` path, enum items and input fields are fields of their type
+#[derive(Default)]
+pub(crate) struct Visibilities {
+    types: HashMap<String, VisibleFn>,
+    fields: HashMap<String, VisibleFn>,
+    arguments: HashMap<String, VisibleFn>,
+}
+
+impl Visibilities {
+    pub(crate) fn set_type(&mut self, type_name: &str, visible: VisibleFn) {
+        self.types.insert(type_name.to_string(), visible);
+    }
+
+    pub(crate) fn set_field(&mut self, type_name: &str, field_name: &str, visible: VisibleFn) {
+        self.fields
+            .insert(format!("{}.{}", type_name, field_name), visible);
+    }
+
+    pub(crate) fn set_argument(
+        &mut self,
+        type_name: &str,
+        field_name: &str,
+        argument_name: &str,
+        visible: VisibleFn,
+    ) {
+        self.arguments.insert(
+            format!("{}.{}.{}", type_name, field_name, argument_name),
+            visible,
+        );
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.types.is_empty() && self.fields.is_empty() && self.arguments.is_empty()
+    }
+}
+
+/// Hides the elements of the schema that are not visible in the request.
+pub(crate) struct VisibilityFilter {
+    pub(crate) visibilities: Arc<Visibilities>,
+}
+
+impl ExtensionFactory for VisibilityFilter {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(VisibilityFilterExtension {
+            visibilities: self.visibilities.clone(),
+            document: Mutex::new(None),
+        })
+    }
+}
+
+struct VisibilityFilterExtension {
+    visibilities: Arc<Visibilities>,
+    // the document of the request, with the names added to the introspection selections, and
+    // the alias of the names
+    document: Mutex<Option<(ExecutableDocument, String)>>,
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for VisibilityFilterExtension {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let mut document = next.run(ctx, query, variables).await?;
+        let name_alias = name_alias(&document);
+        let visibility = Visibility {
+            visibilities: &self.visibilities,
+            ctx: VisibleContext { ctx },
+            registry: &ctx.schema_env.registry,
+            name_alias: &name_alias,
+        };
+        visibility.check_document(&document, variables)?;
+        visibility.add_names(&mut document);
+        *self.document.lock().unwrap() = Some((document.clone(), name_alias));
+        Ok(document)
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let mut response = next.run(ctx, operation_name).await;
+        let document = self.document.lock().unwrap().take();
+        if let Some((document, name_alias)) = document {
+            let visibility = Visibility {
+                visibilities: &self.visibilities,
+                ctx: VisibleContext { ctx },
+                registry: &ctx.schema_env.registry,
+                name_alias: &name_alias,
+            };
+            if let Some(operation) = find_operation(&document, operation_name) {
+                visibility.filter_response(&document, operation, &mut response.data);
+            }
+        }
+        response
+    }
+}
+
+fn find_operation<'a>(
+    document: &'a ExecutableDocument,
+    operation_name: Option<&str>,
+) -> Option<&'a OperationDefinition> {
+    match (&document.operations, operation_name) {
+        (DocumentOperations::Single(operation), _) => Some(&operation.node),
+        (DocumentOperations::Multiple(operations), Some(name)) => {
+            operations.get(name).map(|operation| &operation.node)
+        }
+        (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+            operations.values().next().map(|operation| &operation.node)
+        }
+        _ => None,
+    }
+}
+
+fn root_type<'a>(registry: &'a Registry, operation: &OperationDefinition) -> Option<&'a MetaType> {
+    let name = match operation.ty {
+        OperationType::Query => Some(registry.query_type.as_str()),
+        OperationType::Mutation => registry.mutation_type.as_deref(),
+        OperationType::Subscription => registry.subscription_type.as_deref(),
+    };
+    name.and_then(|name| registry.types.get(name))
+}
+
+/// where an introspection item is listed, to know which element it describes
+#[derive(Clone, Copy)]
+enum Owner<'a> {
+    None,
+    Type(&'a str),
+    Field(&'a str, &'a str),
+}
+
+struct Visibility<'a> {
+    visibilities: &'a Visibilities,
+    ctx: VisibleContext<'a>,
+    registry: &'a Registry,
+    name_alias: &'a str,
+}
+
+impl<'a> Visibility<'a> {
+    fn is_type_visible(&self, type_name: &str) -> bool {
+        let type_name = MetaTypeName::concrete_typename(type_name);
+        self.visibilities
+            .types
+            .get(type_name)
+            .is_none_or(|visible| visible(&self.ctx))
+    }
+
+    /// a field, an input field or an enum item, the fields of hidden types are hidden
+    fn is_field_visible(&self, type_name: &str, field_name: &str) -> bool {
+        let visible = self
+            .visibilities
+            .fields
+            .get(&format!("{}.{}", type_name, field_name))
+            .is_none_or(|visible| visible(&self.ctx));
+        if !visible {
+            return false;
+        }
+        let field_type = match self.registry.types.get(type_name) {
+            Some(MetaType::InputObject { input_fields, .. }) => {
+                input_fields.get(field_name).map(|field| field.ty.as_str())
+            }
+            Some(ty) => ty.field_by_name(field_name).map(|field| field.ty.as_str()),
+            None => None,
+        };
+        field_type.is_none_or(|ty| self.is_type_visible(ty))
+    }
+
+    /// the arguments of hidden types are hidden
+    fn is_argument_visible(&self, type_name: &str, field_name: &str, argument_name: &str) -> bool {
+        let visible = self
+            .visibilities
+            .arguments
+            .get(&format!("{}.{}.{}", type_name, field_name, argument_name))
+            .is_none_or(|visible| visible(&self.ctx));
+        if !visible {
+            return false;
+        }
+        self.registry
+            .types
+            .get(type_name)
+            .and_then(|ty| ty.field_by_name(field_name))
+            .and_then(|field| field.args.get(argument_name))
+            .is_none_or(|argument| self.is_type_visible(&argument.ty))
+    }
+
+    /// reject the hidden elements of the query like the unknown ones
+    fn check_document(
+        &self,
+        document: &'a ExecutableDocument,
+        variables: &Variables,
+    ) -> ServerResult<()> {
+        for fragment in document.fragments.values() {
+            let on = &fragment.node.type_condition.node.on;
+            if !self.is_type_visible(&on.node) {
+                return Err(unknown_type(&on.node, on.pos));
+            }
+        }
+        for (_, operation) in document.operations.iter() {
+            let operation = &operation.node;
+            let mut variables: BTreeMap<Name, Value> = (**variables).clone();
+            for definition in &operation.variable_definitions {
+                let definition = &definition.node;
+                let var_type = definition.var_type.node.to_string();
+                if !self.is_type_visible(&var_type) {
+                    return Err(unknown_type(
+                        MetaTypeName::concrete_typename(&var_type),
+                        definition.var_type.pos,
+                    ));
+                }
+                if let Some(default_value) = &definition.default_value {
+                    variables
+                        .entry(definition.name.node.clone())
+                        .or_insert_with(|| default_value.node.clone());
+                }
+            }
+            let mut checker = QueryChecker {
+                visibility: self,
+                document,
+                variables,
+                fragments: HashSet::new(),
+            };
+            let root = root_type(self.registry, operation);
+            checker.selection_set(root, &operation.selection_set.node)?;
+        }
+        Ok(())
+    }
+
+    /// add the names to the selections of the introspection items
+    fn add_names(&self, document: &mut ExecutableDocument) {
+        for fragment in document.fragments.values_mut() {
+            let fragment = &mut fragment.node;
+            let ty = self
+                .registry
+                .types
+                .get(fragment.type_condition.node.on.node.as_str());
+            self.add_names_to_selection_set(ty, &mut fragment.selection_set.node);
+        }
+        let operations: Vec<&mut Positioned<OperationDefinition>> = match &mut document.operations {
+            DocumentOperations::Single(operation) => vec![operation],
+            DocumentOperations::Multiple(operations) => operations.values_mut().collect(),
+        };
+        for operation in operations {
+            let operation = &mut operation.node;
+            let root = root_type(self.registry, operation);
+            self.add_names_to_selection_set(root, &mut operation.selection_set.node);
+        }
+    }
+
+    fn add_names_to_selection_set(
+        &self,
+        parent: Option<&MetaType>,
+        selection_set: &mut SelectionSet,
+    ) {
+        for selection in &mut selection_set.items {
+            match &mut selection.node {
+                Selection::Field(field) => {
+                    let field = &mut field.node;
+                    let ty = parent
+                        .and_then(|parent| parent.field_by_name(&field.name.node))
+                        .and_then(|field| {
+                            self.registry
+                                .types
+                                .get(MetaTypeName::concrete_typename(&field.ty))
+                        });
+                    self.add_names_to_selection_set(ty, &mut field.selection_set.node);
+                }
+                Selection::FragmentSpread(_) => {}
+                Selection::InlineFragment(fragment) => {
+                    let fragment = &mut fragment.node;
+                    let ty = match &fragment.type_condition {
+                        Some(condition) => self.registry.types.get(condition.node.on.node.as_str()),
+                        None => parent,
+                    };
+                    self.add_names_to_selection_set(ty, &mut fragment.selection_set.node);
+                }
+            }
+        }
+        if parent.is_some_and(|parent| INTROSPECTION_ITEMS.contains(&parent.name())) {
+            let pos = Pos::default();
+            selection_set.items.push(Positioned::new(
+                Selection::Field(Positioned::new(
+                    Field {
+                        alias: Some(Positioned::new(Name::new(self.name_alias), pos)),
+                        name: Positioned::new(Name::new("name"), pos),
+                        arguments: Vec::new(),
+                        directives: Vec::new(),
+                        selection_set: Positioned::new(SelectionSet::default(), pos),
+                    },
+                    pos,
+                )),
+                pos,
+            ));
+        }
+    }
+
+    /// remove the hidden elements from the introspection fields of the response
+    fn filter_response(
+        &self,
+        document: &ExecutableDocument,
+        operation: &OperationDefinition,
+        data: &mut Value,
+    ) {
+        let Some(root) = root_type(self.registry, operation) else {
+            return;
+        };
+        let filter = ResponseFilter {
+            visibility: self,
+            document,
+        };
+        let Value::Object(data) = data else {
+            return;
+        };
+        for (key, name, selection_sets) in filter.collect_fields(&[&operation.selection_set.node]) {
+            if name != "__schema" && name != "__type" {
+                continue;
+            }
+            let Some(field) = root.field_by_name(name) else {
+                continue;
+            };
+            if let Some(value) = data.get_mut(key) {
+                filter.value(&field.ty, value, &selection_sets, Owner::None);
+            }
+        }
+    }
+}
+
+fn unknown_type(name: &str, pos: Pos) -> ServerError {
+    ServerError::new(format!(r#"Unknown type "{}""#, name), Some(pos))
+}
+
+struct QueryChecker<'a, 'v> {
+    visibility: &'v Visibility<'a>,
+    document: &'a ExecutableDocument,
+    variables: BTreeMap<Name, Value>,
+    // fragments in the current path, to stop on cycles (they are reported by the validation)
+    fragments: HashSet<&'a str>,
+}
+
+impl<'a> QueryChecker<'a, '_> {
+    fn selection_set(
+        &mut self,
+        parent: Option<&'a MetaType>,
+        selection_set: &'a SelectionSet,
+    ) -> ServerResult<()> {
+        let registry = self.visibility.registry;
+        for selection in &selection_set.items {
+            match &selection.node {
+                Selection::Field(field) => self.field(parent, field)?,
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.node.fragment_name.node.as_str();
+                    let Some(fragment) = self.document.fragments.get(name) else {
+                        continue;
+                    };
+                    if !self.fragments.insert(name) {
+                        continue;
+                    }
+                    let ty = registry
+                        .types
+                        .get(fragment.node.type_condition.node.on.node.as_str());
+                    self.selection_set(ty, &fragment.node.selection_set.node)?;
+                    self.fragments.remove(name);
+                }
+                Selection::InlineFragment(fragment) => {
+                    let ty = match &fragment.node.type_condition {
+                        Some(condition) => {
+                            let on = &condition.node.on;
+                            if !self.visibility.is_type_visible(&on.node) {
+                                return Err(unknown_type(&on.node, on.pos));
+                            }
+                            registry.types.get(on.node.as_str())
+                        }
+                        None => parent,
+                    };
+                    self.selection_set(ty, &fragment.node.selection_set.node)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn field(
+        &mut self,
+        parent: Option<&'a MetaType>,
+        field: &'a Positioned<Field>,
+    ) -> ServerResult<()> {
+        let Some(parent) = parent else {
+            return Ok(());
+        };
+        let type_name = parent.name();
+        let field_name = field.node.name.node.as_str();
+        let Some(meta_field) = parent.field_by_name(field_name) else {
+            return Ok(());
+        };
+        if !self.visibility.is_field_visible(type_name, field_name) {
+            return Err(ServerError::new(
+                format!(r#"Unknown field "{}" on type "{}"."#, field_name, type_name),
+                Some(field.pos),
+            ));
+        }
+        for (name, value) in &field.node.arguments {
+            let Some(argument) = meta_field.args.get(name.node.as_str()) else {
+                continue;
+            };
+            if !self
+                .visibility
+                .is_argument_visible(type_name, field_name, &name.node)
+            {
+                return Err(ServerError::new(
+                    format!(
+                        r#"Unknown argument "{}" on field "{}" of type "{}"."#,
+                        name.node, field_name, type_name
+                    ),
+                    Some(name.pos),
+                ));
+            }
+            let value = value.node.clone().into_const_with(|name| {
+                Ok::<_, std::convert::Infallible>(
+                    self.variables.get(&name).cloned().unwrap_or_default(),
+                )
+            });
+            let Ok(value) = value;
+            if let Some(reason) = self.input_value(&argument.ty, &value) {
+                return Err(ServerError::new(
+                    format!(r#"Invalid value for argument "{}", {}"#, name.node, reason),
+                    Some(name.pos),
+                ));
+            }
+        }
+        let ty = self
+            .visibility
+            .registry
+            .types
+            .get(MetaTypeName::concrete_typename(&meta_field.ty));
+        self.selection_set(ty, &field.node.selection_set.node)
+    }
+
+    /// the reason the value uses a hidden enum item or input field
+    fn input_value(&self, type_name: &str, value: &Value) -> Option<String> {
+        match MetaTypeName::create(type_name) {
+            MetaTypeName::NonNull(type_name) => return self.input_value(type_name, value),
+            MetaTypeName::List(type_name) => {
+                return match value {
+                    Value::List(items) => items
+                        .iter()
+                        .find_map(|item| self.input_value(type_name, item)),
+                    value => self.input_value(type_name, value),
+                };
+            }
+            MetaTypeName::Named(_) => {}
+        }
+        match (self.visibility.registry.types.get(type_name)?, value) {
+            (MetaType::Enum { name, .. }, Value::Enum(item)) => self.enum_item(name, item.as_str()),
+            (MetaType::Enum { name, .. }, Value::String(item)) => self.enum_item(name, item),
+            (
+                MetaType::InputObject {
+                    name, input_fields, ..
+                },
+                Value::Object(fields),
+            ) => fields.iter().find_map(|(field_name, value)| {
+                let field = input_fields.get(field_name.as_str())?;
+                if !self.visibility.is_field_visible(name, field_name) {
+                    return Some(format!(
+                        r#"unknown field "{}" of type "{}""#,
+                        field_name, name
+                    ));
+                }
+                self.input_value(&field.ty, value)
+            }),
+            _ => None,
+        }
+    }
+
+    fn enum_item(&self, enum_name: &str, item: &str) -> Option<String> {
+        (!self.visibility.is_field_visible(enum_name, item)).then(|| {
+            format!(
+                r#"enumeration type "{}" does not contain the value "{}""#,
+                enum_name, item
+            )
+        })
+    }
+}
+
+struct ResponseFilter<'a, 'v> {
+    visibility: &'v Visibility<'a>,
+    document: &'v ExecutableDocument,
+}
+
+impl<'v> ResponseFilter<'_, 'v> {
+    /// the selected fields by response key, with all their selection sets
+    fn collect_fields(
+        &self,
+        selection_sets: &[&'v SelectionSet],
+    ) -> Vec<(&'v str, &'v str, Vec<&'v SelectionSet>)> {
+        let mut fields: Vec<(&'v str, &'v str, Vec<&'v SelectionSet>)> = Vec::new();
+        let mut pending: Vec<&'v SelectionSet> = selection_sets.iter().rev().copied().collect();
+        let mut fragments = HashSet::new();
+        while let Some(selection_set) = pending.pop() {
+            let mut nested = Vec::new();
+            for selection in &selection_set.items {
+                match &selection.node {
+                    Selection::Field(field) => {
+                        let field = &field.node;
+                        let key = field.response_key().node.as_str();
+                        let selection_set = &field.selection_set.node;
+                        match fields.iter_mut().find(|(k, _, _)| *k == key) {
+                            Some((_, _, selection_sets)) => selection_sets.push(selection_set),
+                            None => {
+                                fields.push((key, field.name.node.as_str(), vec![selection_set]))
+                            }
+                        }
+                    }
+                    Selection::FragmentSpread(spread) => {
+                        let name = spread.node.fragment_name.node.as_str();
+                        if !fragments.insert(name) {
+                            continue;
+                        }
+                        if let Some(fragment) = self.document.fragments.get(name) {
+                            nested.push(&fragment.node.selection_set.node);
+                        }
+                    }
+                    Selection::InlineFragment(fragment) => {
+                        nested.push(&fragment.node.selection_set.node)
+                    }
+                }
+            }
+            pending.extend(nested.into_iter().rev());
+        }
+        fields
+    }
+
+    fn item_name<'b>(&self, value: &'b Value) -> Option<&'b str> {
+        match value {
+            Value::Object(object) => match object.get(self.visibility.name_alias) {
+                Some(Value::String(name)) => Some(name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn is_item_visible(&self, type_name: &str, name: Option<&str>, owner: Owner<'_>) -> bool {
+        let Some(name) = name else {
+            return true;
+        };
+        let visibility = self.visibility;
+        match (type_name, owner) {
+            ("__Type", _) => visibility.is_type_visible(name),
+            ("__Field" | "__EnumValue" | "__InputValue", Owner::Type(type_name)) => {
+                visibility.is_field_visible(type_name, name)
+            }
+            ("__InputValue", Owner::Field(type_name, field_name)) => {
+                visibility.is_argument_visible(type_name, field_name, name)
+            }
+            _ => true,
+        }
+    }
+
+    fn value(
+        &self,
+        type_name: &str,
+        value: &mut Value,
+        selection_sets: &[&'v SelectionSet],
+        owner: Owner<'_>,
+    ) {
+        let concrete_type = MetaTypeName::concrete_typename(type_name);
+        let Some(ty) = self.visibility.registry.types.get(concrete_type) else {
+            return;
+        };
+        match value {
+            Value::List(items) => {
+                items.retain(|item| {
+                    self.is_item_visible(concrete_type, self.item_name(item), owner)
+                });
+                for item in items {
+                    self.object(ty, item, selection_sets, owner);
+                }
+            }
+            Value::Object(_) => {
+                if self.is_item_visible(concrete_type, self.item_name(value), owner) {
+                    self.object(ty, value, selection_sets, owner);
+                } else {
+                    *value = Value::Null;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn object(
+        &self,
+        ty: &MetaType,
+        value: &mut Value,
+        selection_sets: &[&'v SelectionSet],
+        owner: Owner<'_>,
+    ) {
+        let Value::Object(object) = value else {
+            return;
+        };
+        let name = match object.shift_remove(self.visibility.name_alias) {
+            Some(Value::String(name)) => Some(name),
+            _ => None,
+        };
+        let children_owner = match (ty.name(), owner, name.as_deref()) {
+            ("__Type", _, Some(name)) => Owner::Type(name),
+            ("__Field", Owner::Type(type_name), Some(name)) => Owner::Field(type_name, name),
+            _ => Owner::None,
+        };
+        for (key, field_name, selection_sets) in self.collect_fields(selection_sets) {
+            let Some(field) = ty.field_by_name(field_name) else {
+                continue;
+            };
+            if let Some(value) = object.get_mut(key) {
+                self.value(&field.ty, value, &selection_sets, children_owner);
+            }
+        }
+    }
+}
+
+/// the alias of the added names, one that is not a response key of the document
+fn name_alias(document: &ExecutableDocument) -> String {
+    let mut keys = HashSet::new();
+    for fragment in document.fragments.values() {
+        response_keys(&fragment.node.selection_set.node, &mut keys);
+    }
+    for (_, operation) in document.operations.iter() {
+        response_keys(&operation.node.selection_set.node, &mut keys);
+    }
+    let mut alias = NAME_ALIAS.to_string();
+    let mut index = 0;
+    while keys.contains(alias.as_str()) {
+        index += 1;
+        alias = format!("{}{}", NAME_ALIAS, index);
+    }
+    alias
+}
+
+fn response_keys<'a>(selection_set: &'a SelectionSet, keys: &mut HashSet<&'a str>) {
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => {
+                keys.insert(field.node.response_key().node.as_str());
+                response_keys(&field.node.selection_set.node, keys);
+            }
+            Selection::FragmentSpread(_) => {}
+            Selection::InlineFragment(fragment) => {
+                response_keys(&fragment.node.selection_set.node, keys)
+            }
+        }
+    }
+}